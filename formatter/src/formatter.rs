@@ -1,11 +1,13 @@
 use crate::comments::CommentMapper;
 use lexer::Span;
 use parser::{
-    ast::{Comment, DataType, Expression, SelectItem, TableSource},
+    ast::{Comment, DataType, DataTypeLength, Expression, SelectItem, TableSource},
     visitor::Visitor,
 };
 
-use crate::settings::{FormatterSettings, IndentCommaLists, KeywordCase};
+use crate::settings::{
+    BooleanOperatorPosition, FormatterSettings, IdentifierCase, IndentCommaLists, KeywordCase,
+};
 
 pub struct Formatter {
     settings: FormatterSettings,
@@ -13,6 +15,8 @@ pub struct Formatter {
     formatted_query: String,
     comment_map_before_line: Vec<(Span, Comment)>,
     comment_map_same_line: Vec<(Span, Comment)>,
+    // where the last same line comment and the line after it start
+    comment_line_break: Option<(usize, usize)>,
 }
 
 impl Formatter {
@@ -24,10 +28,14 @@ impl Formatter {
             formatted_query,
             comment_map_before_line: vec![],
             comment_map_same_line: vec![],
+            comment_line_break: None,
         }
     }
 
     pub fn format(&mut self, input: &str) -> Result<(), String> {
+        // start from a clean slate so the formatter can be reused
+        *self = Self::new(self.settings);
+
         let lexer = lexer::Lexer::new(input);
         let mut parser = parser::Parser::new(lexer);
         let query = parser.parse();
@@ -81,6 +89,19 @@ impl Formatter {
         match self.settings.keyword_case {
             KeywordCase::Upper => self.formatted_query.push_str(&keyword.to_uppercase()),
             KeywordCase::Lower => self.formatted_query.push_str(&keyword.to_lowercase()),
+            KeywordCase::Capitalize => self.formatted_query.push_str(&capitalize_words(keyword)),
+        }
+    }
+
+    fn print_identifier(&mut self, identifier: &str) {
+        match self.settings.identifier_case {
+            Some(IdentifierCase::Upper) => {
+                self.formatted_query.push_str(&identifier.to_uppercase())
+            }
+            Some(IdentifierCase::Lower) => {
+                self.formatted_query.push_str(&identifier.to_lowercase())
+            }
+            Some(IdentifierCase::Preserve) | None => self.formatted_query.push_str(identifier),
         }
     }
 
@@ -91,11 +112,35 @@ impl Formatter {
         self.formatted_query.push_str(&indent_string);
     }
 
+    fn print_statement_terminator(&mut self) {
+        // the terminator goes before a same line comment that ended the statement
+        if let Some((comment_start, line_start)) = self.comment_line_break {
+            if self.formatted_query.len() >= line_start
+                && self.formatted_query[line_start..].trim().is_empty()
+            {
+                self.formatted_query.insert(comment_start, ';');
+                self.comment_line_break = Some((comment_start + 1, line_start + 1));
+                return;
+            }
+        }
+        self.formatted_query.push(';');
+    }
+
     fn print_space(&mut self) {
         self.formatted_query.push_str(" ");
     }
 
     fn print_new_line(&mut self) {
+        // a same line comment already ended the line so only the indent is redone
+        if let Some((_, line_start)) = self.comment_line_break.take() {
+            if self.formatted_query.len() >= line_start
+                && self.formatted_query[line_start..].trim().is_empty()
+            {
+                self.formatted_query.truncate(line_start);
+                self.print_indent();
+                return;
+            }
+        }
         if self
             .formatted_query
             .lines()
@@ -134,16 +179,88 @@ impl Formatter {
         }
     }
 
-    fn print_in_list_comma(&mut self) {
-        if self.settings.indent_in_lists {
-            self.increase_indent();
-            self.print_select_column_comma();
-            self.decrease_indent();
-        } else {
-            self.formatted_query.push_str(", ");
+    fn print_in_list<T>(&mut self, items: &[T], print_item: impl Fn(&mut Self, &T)) {
+        if !self.settings.indent_in_lists {
+            self.print_inline_list(items, print_item);
+            return;
+        }
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                self.increase_indent();
+                self.print_select_column_comma();
+                self.decrease_indent();
+            }
+            print_item(self, item);
+        }
+    }
+
+    fn current_line_width(&self) -> usize {
+        self.formatted_query
+            .rsplit('\n')
+            .next()
+            .map_or(0, |line| line.chars().count())
+    }
+
+    // inline lists stay on one line, an item that would take the line past the
+    // max line width starts the next line instead
+    fn print_inline_list<T>(&mut self, items: &[T], print_item: impl Fn(&mut Self, &T)) {
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                self.formatted_query.push(',');
+                // every item but the last is followed by a comma on the same line
+                let comma_width = usize::from(i + 1 < items.len());
+                let wraps = match self.settings.max_line_width {
+                    Some(max_line_width) => {
+                        let item_width = self.measure_on_one_line(item, &print_item);
+                        self.current_line_width() + 1 + item_width + comma_width > max_line_width
+                    }
+                    None => false,
+                };
+                if wraps {
+                    self.increase_indent();
+                    self.print_new_line();
+                    self.decrease_indent();
+                } else {
+                    self.print_space();
+                }
+            }
+            print_item(self, item);
         }
     }
 
+    // how wide the first line of an item is when it is printed without any
+    // wrapping, the item is printed into a scratch buffer with the comments set
+    // aside so nothing is printed twice and nested lists are measured only once
+    fn measure_on_one_line<T>(&mut self, item: &T, print_item: &impl Fn(&mut Self, &T)) -> usize {
+        let formatted_query = std::mem::take(&mut self.formatted_query);
+        let comment_map_before_line = std::mem::take(&mut self.comment_map_before_line);
+        let comment_map_same_line = std::mem::take(&mut self.comment_map_same_line);
+        let comment_line_break = self.comment_line_break.take();
+        let max_line_width = self.settings.max_line_width.take();
+
+        print_item(self, item);
+        let width = self
+            .formatted_query
+            .lines()
+            .next()
+            .map_or(0, |line| line.chars().count());
+
+        self.formatted_query = formatted_query;
+        self.comment_map_before_line = comment_map_before_line;
+        self.comment_map_same_line = comment_map_same_line;
+        self.comment_line_break = comment_line_break;
+        self.settings.max_line_width = max_line_width;
+        width
+    }
+
+    // how wide a line may get before a case is broken over several lines, the
+    // max line width when one is set and the max width otherwise
+    fn line_width_limit(&self) -> usize {
+        self.settings
+            .max_line_width
+            .unwrap_or(self.settings.max_width as usize)
+    }
+
     fn print_column_list_open_paren_symbol(&mut self, symbol: &parser::ast::Symbol) {
         self.increase_indent();
         self.visit_symbol(symbol);
@@ -160,6 +277,117 @@ impl Formatter {
         self.visit_symbol(symbol);
     }
 
+    // a case is tried on a single line first and only expanded to one line per
+    // condition when expanding is enabled and the case does not fit the width
+    fn print_case_expression(
+        &mut self,
+        case_kw: &parser::ast::Keyword,
+        input_expression: Option<&Expression>,
+        conditions: &[parser::ast::CaseCondition],
+        end_kw: &parser::ast::Keyword,
+    ) {
+        let case_start = self.formatted_query.len();
+        self.visit_keyword(case_kw);
+        if let Some(input_expression) = input_expression {
+            self.print_space();
+            self.visit_expression(input_expression);
+        }
+        for condition in conditions.iter() {
+            self.print_space();
+            match condition {
+                parser::ast::CaseCondition::WhenCondition {
+                    when_kw,
+                    when_expression,
+                    then_kw,
+                    result_expression,
+                } => {
+                    self.visit_keyword(when_kw);
+                    self.print_space();
+                    self.visit_expression(when_expression);
+                    self.print_space();
+                    self.visit_keyword(then_kw);
+                    self.print_space();
+                    self.visit_expression(result_expression);
+                }
+                parser::ast::CaseCondition::ElseCondition { .. } => {
+                    self.visit_case_condition(condition)
+                }
+            }
+        }
+        self.print_space();
+        self.visit_keyword(end_kw);
+
+        let fits_on_line = !self.formatted_query[case_start..].contains('\n')
+            && self.current_line_width() <= self.line_width_limit();
+        if !self.settings.case_on_new_line || fits_on_line {
+            return;
+        }
+
+        self.formatted_query.truncate(case_start);
+        self.visit_keyword(case_kw);
+        if let Some(input_expression) = input_expression {
+            self.print_space();
+            self.visit_expression(input_expression);
+        }
+        self.increase_indent();
+        for condition in conditions.iter() {
+            self.print_new_line();
+            self.print_case_condition_line(condition);
+        }
+        self.decrease_indent();
+        self.print_new_line();
+        self.visit_keyword(end_kw);
+    }
+
+    // a when condition keeps its then on the same line unless the line gets
+    // too wide, then the then moves to its own line
+    fn print_case_condition_line(&mut self, condition: &parser::ast::CaseCondition) {
+        let parser::ast::CaseCondition::WhenCondition {
+            when_kw,
+            when_expression,
+            then_kw,
+            result_expression,
+        } = condition
+        else {
+            self.visit_case_condition(condition);
+            return;
+        };
+        let condition_start = self.formatted_query.len();
+        self.visit_keyword(when_kw);
+        self.print_space();
+        self.visit_expression(when_expression);
+        self.print_space();
+        self.visit_keyword(then_kw);
+        self.print_space();
+        self.visit_expression(result_expression);
+
+        let fits_on_line = !self.formatted_query[condition_start..].contains('\n')
+            && self.current_line_width() <= self.line_width_limit();
+        if !fits_on_line {
+            self.formatted_query.truncate(condition_start);
+            self.visit_case_condition(condition);
+        }
+    }
+
+    // the assignments after SET, on the same line when there is only one
+    fn print_update_columns(&mut self, update_columns: &[parser::ast::Expression]) {
+        if update_columns.len() == 1 {
+            self.print_space();
+        } else {
+            self.increase_indent();
+            self.print_new_line();
+        }
+        for (i, column) in update_columns.iter().enumerate() {
+            if i > 0 {
+                self.print_select_column_comma();
+            }
+            self.visit_expression(column);
+        }
+        if update_columns.len() > 1 {
+            self.decrease_indent();
+        }
+    }
+
     fn print_comments_before(&mut self, location: Span) {
         let mut comment_present = false;
         let comma_char = if self
@@ -198,23 +426,38 @@ impl Formatter {
     }
 
     fn print_comments_same_line(&mut self, location: Span) {
+        let mut comment_present = false;
+        let comment_start = self.formatted_query.len();
         for (span, comment) in self.comment_map_same_line.iter() {
             if *span == location {
                 self.formatted_query += " -- ";
                 self.formatted_query += &comment.content;
-                self.formatted_query += self.get_new_line_str().as_str();
+                comment_present = true;
             }
         }
+        if comment_present {
+            self.formatted_query.push('\n');
+            self.comment_line_break = Some((comment_start, self.formatted_query.len()));
+            self.print_indent();
+        }
     }
 }
 
-macro_rules! walk_list_two {
-    ($visitor: expr, $method: ident, $list: expr, $before_visit: stmt) => {
-        for element in $list.iter() {
-            $before_visit
-            $visitor.$method(element);
+// uppercases the first letter of every word, words being split by anything
+// that is not alphanumeric like the spaces in `order by` or the underscore in
+// `row_number`
+fn capitalize_words(keyword: &str) -> String {
+    let mut capitalized = String::with_capacity(keyword.len());
+    let mut word_start = true;
+    for ch in keyword.chars() {
+        if word_start {
+            capitalized.extend(ch.to_uppercase());
+        } else {
+            capitalized.extend(ch.to_lowercase());
         }
-    };
+        word_start = !ch.is_alphanumeric();
+    }
+    capitalized
 }
 
 macro_rules! walk_opt_two {
@@ -273,14 +516,30 @@ impl Visitor for Formatter {
     }
 
     fn visit_query(&mut self, query: &parser::ast::Query) -> Self::Result {
+        let is_batch_separator =
+            |s: &parser::ast::Statement| matches!(s, parser::ast::Statement::BatchSeparator { .. });
         for (i, s) in query.statements.iter().enumerate() {
             if i > 0 {
-                self.formatted_query.push(';');
-                self.print_new_line();
+                // GO is never terminated and sits on the line right under the
+                // batch it ends
+                if !is_batch_separator(&query.statements[i - 1]) {
+                    self.print_statement_terminator();
+                }
                 self.print_new_line();
+                if !is_batch_separator(s) {
+                    self.print_new_line();
+                }
             }
             self.visit_statement(s);
         }
+        if self.settings.terminate_statements
+            && query
+                .statements
+                .last()
+                .is_some_and(|s| !is_batch_separator(s))
+        {
+            self.print_statement_terminator();
+        }
     }
 
     fn visit_data_type_numeric_size(&mut self, ns: &parser::ast::NumericSize) -> Self::Result {
@@ -295,7 +554,10 @@ impl Visitor for Formatter {
 
     fn visit_data_type_size(&mut self, data_type_size: &parser::ast::DataTypeSize) -> Self::Result {
         self.visit_symbol(&data_type_size.left_paren);
-        self.formatted_query += data_type_size.size.to_string().as_str();
+        match &data_type_size.size {
+            DataTypeLength::Number(n) => self.formatted_query += n.to_string().as_str(),
+            DataTypeLength::Max(k) => self.visit_keyword(k),
+        }
         self.visit_symbol(&data_type_size.right_paren);
     }
 
@@ -316,7 +578,11 @@ impl Visitor for Formatter {
                     self.visit_data_type_numeric_size(ns);
                 }
             }
-            DataType::Float(k, n) | DataType::Varchar(k, n) => {
+            DataType::Float(k, n)
+            | DataType::Varchar(k, n)
+            | DataType::Nvarchar(k, n)
+            | DataType::Char(k, n)
+            | DataType::Nchar(k, n) => {
                 self.visit_keyword(&k);
                 if let Some(n) = n {
                     self.visit_data_type_size(n);
@@ -329,8 +595,9 @@ impl Visitor for Formatter {
         match stmt {
             parser::ast::Statement::Select(s) => self.visit_select_statement(s),
             parser::ast::Statement::Insert(i) => self.visit_insert_statement(i),
-            parser::ast::Statement::Update(_) => unimplemented!(),
-            parser::ast::Statement::Delete(_) => unimplemented!(),
+            parser::ast::Statement::Update(u) => self.visit_update_statement(u),
+            parser::ast::Statement::Delete(d) => self.visit_delete_statement(d),
+            parser::ast::Statement::Merge(m) => self.visit_merge_statement(m),
             parser::ast::Statement::CTE {
                 with_kw,
                 ctes,
@@ -338,15 +605,12 @@ impl Visitor for Formatter {
             } => {
                 self.visit_keyword(with_kw);
                 self.print_space();
-                self.increase_indent();
                 for (i, cte) in ctes.iter().enumerate() {
                     if i > 0 {
                         self.print_select_column_comma();
                     }
                     self.visit_common_table_expression(cte);
                 }
-                self.decrease_indent();
-                self.print_new_line();
                 self.print_new_line();
                 self.visit_common_table_expression_statement(statement);
             }
@@ -379,6 +643,18 @@ impl Visitor for Formatter {
                 self.print_space();
                 self.visit_expression(value);
             }
+            parser::ast::Statement::SetIsolationLevel {
+                set_kw,
+                transaction_isolation_level_kws,
+                level_kws,
+                ..
+            } => {
+                self.visit_keyword(set_kw);
+                for kw in transaction_isolation_level_kws.iter().chain(level_kws) {
+                    self.print_space();
+                    self.visit_keyword(kw);
+                }
+            }
             parser::ast::Statement::Execute {
                 exec_kw,
                 procedure_name,
@@ -388,33 +664,94 @@ impl Visitor for Formatter {
                 self.print_space();
                 self.visit_expression(procedure_name);
                 self.print_space();
-                for (i, p) in parameters.iter().enumerate() {
-                    if i > 0 {
-                        self.formatted_query += ", ";
-                    }
-                    self.visit_execute_statement_procedure_parameter(p);
-                }
+                self.print_inline_list(parameters, |f, p| {
+                    f.visit_execute_statement_procedure_parameter(p)
+                });
             }
-            parser::ast::Statement::Union { select, unions } => {
-                self.visit_select_statement(select);
-                for union in unions.iter() {
-                    self.print_new_line();
-                    self.print_new_line();
-                    self.visit_union(union);
-                }
+            parser::ast::Statement::SetOperation {
+                left,
+                op,
+                all,
+                right,
+            } => self.visit_set_operation(left, op, all, right),
+            parser::ast::Statement::CreateTable {
+                create_kw,
+                table_kw,
+                name,
+                left_paren,
+                columns,
+                right_paren,
+            } => {
+                self.visit_keyword(create_kw);
+                self.print_space();
+                self.visit_keyword(table_kw);
+                self.print_space();
+                self.visit_expression(name);
+                self.print_space();
+                self.print_column_list_open_paren_symbol(left_paren);
+                self.print_in_list(columns, |f, column| f.visit_column_def(column));
+                self.print_column_list_close_paren_symbol(right_paren);
+            }
+            parser::ast::Statement::Truncate {
+                truncate_kw,
+                table_kw,
+                table,
+                partitions,
+            } => {
+                self.visit_keyword(truncate_kw);
+                self.print_space();
+                self.visit_keyword(table_kw);
+                self.print_space();
+                self.visit_expression(table);
+                walk_opt_two!(
+                    self,
+                    visit_truncate_partitions,
+                    partitions,
+                    self.print_space()
+                );
+            }
+            parser::ast::Statement::AlterIndex {
+                alter_kw,
+                index_kw,
+                name,
+                on_kw,
+                table,
+                action,
+            } => {
+                self.visit_keyword(alter_kw);
+                self.print_space();
+                self.visit_keyword(index_kw);
+                self.print_space();
+                self.visit_expression(name);
+                self.print_space();
+                self.visit_keyword(on_kw);
+                self.print_space();
+                self.visit_expression(table);
+                self.print_space();
+                self.visit_alter_index_action(action);
+            }
+            parser::ast::Statement::BatchSeparator { go_kw, count } => {
+                self.visit_keyword(go_kw);
+                walk_opt_two!(self, visit_expression, count, self.print_space());
             }
         }
     }
 
-    fn visit_union(&mut self, union: &parser::ast::Union) -> Self::Result {
-        self.visit_keyword(&union.union_kw);
-        if let Some(kw) = union.all_kw {
-            self.print_space();
-            self.visit_keyword(&kw);
-        }
+    fn visit_set_operation(
+        &mut self,
+        left: &parser::ast::Statement,
+        op: &parser::ast::Keyword,
+        all: &Option<parser::ast::Keyword>,
+        right: &parser::ast::Statement,
+    ) -> Self::Result {
+        self.visit_statement(left);
+        self.print_new_line();
         self.print_new_line();
+        self.visit_keyword(op);
+        walk_opt_two!(self, visit_keyword, all, self.print_space());
         self.print_new_line();
-        self.visit_select_statement(&union.select);
+        self.print_new_line();
+        self.visit_statement(right);
     }
 
     fn visit_common_table_expression(
@@ -424,16 +761,11 @@ impl Visitor for Formatter {
         self.visit_expression(&cte.name);
         self.print_space();
         if let Some(columns) = &cte.columns {
-            self.print_column_list_open_paren_symbol(&columns.left_paren);
-            for (i, column) in columns.items.iter().enumerate() {
-                if i > 0 {
-                    self.print_in_list_comma();
-                }
-                self.visit_expression(column);
-            }
-            self.print_column_list_close_paren_symbol(&columns.right_paren);
+            self.visit_expression_list(columns);
+            self.print_space();
         }
         self.visit_keyword(&cte.as_kw);
+        self.print_space();
         self.visit_symbol(&cte.left_paren);
         self.increase_indent();
         self.print_new_line();
@@ -458,6 +790,53 @@ impl Visitor for Formatter {
         }
     }
 
+    fn visit_column_def(&mut self, column_def: &parser::ast::ColumnDef) -> Self::Result {
+        self.visit_expression(&column_def.name);
+        self.print_space();
+        self.visit_data_type(&column_def.data_type);
+        for constraint in column_def.constraints.iter() {
+            self.print_space();
+            self.visit_column_constraint(constraint);
+        }
+    }
+
+    fn visit_column_constraint(
+        &mut self,
+        constraint: &parser::ast::ColumnConstraint,
+    ) -> Self::Result {
+        match constraint {
+            parser::ast::ColumnConstraint::Null(null_kw) => self.visit_keyword(null_kw),
+            parser::ast::ColumnConstraint::NotNull { not_kw, null_kw } => {
+                self.visit_keyword(not_kw);
+                self.print_space();
+                self.visit_keyword(null_kw);
+            }
+            parser::ast::ColumnConstraint::PrimaryKey { primary_kw, key_kw } => {
+                self.visit_keyword(primary_kw);
+                self.print_space();
+                self.visit_keyword(key_kw);
+            }
+            parser::ast::ColumnConstraint::Identity {
+                identity_kw,
+                seed_increment,
+            } => {
+                self.visit_keyword(identity_kw);
+                if let Some(seed_increment) = seed_increment {
+                    self.visit_symbol(&seed_increment.left_paren);
+                    self.visit_expression(&seed_increment.seed);
+                    self.formatted_query += ", ";
+                    self.visit_expression(&seed_increment.increment);
+                    self.visit_symbol(&seed_increment.right_paren);
+                }
+            }
+            parser::ast::ColumnConstraint::Default { default_kw, value } => {
+                self.visit_keyword(default_kw);
+                self.print_space();
+                self.visit_expression(value);
+            }
+        }
+    }
+
     fn visit_execute_statement_procedure_parameter_name(
         &mut self,
         name: &parser::ast::ProcedureParameterName,
@@ -493,9 +872,238 @@ impl Visitor for Formatter {
         }
     }
 
+    fn visit_update_statement(&mut self, stmt: &parser::ast::UpdateStatement) -> Self::Result {
+        self.visit_keyword(&stmt.update_kw);
+        walk_opt_two!(self, visit_top_clause, &stmt.top, self.print_space());
+        self.print_space();
+        self.visit_expression(&stmt.table);
+        self.print_new_line();
+        self.visit_keyword(&stmt.set_kw);
+        self.print_update_columns(&stmt.update_columns);
+        walk_opt_two!(
+            self,
+            visit_output_clause,
+            &stmt.output,
+            self.print_new_line()
+        );
+        walk_opt_two!(self, visit_table_clause, &stmt.from, self.print_new_line());
+        walk_opt_two!(
+            self,
+            visit_where_clause,
+            &stmt.where_clause,
+            self.print_new_line()
+        );
+    }
+
+    fn visit_insert_statement(&mut self, stmt: &parser::ast::InsertStatement) -> Self::Result {
+        match stmt {
+            parser::ast::InsertStatement::Values {
+                insert_kw,
+                insert_top,
+                into_kw,
+                object,
+                columns,
+                values_kw,
+                values,
+            } => {
+                self.visit_keyword(insert_kw);
+                walk_opt_two!(self, visit_top_clause, insert_top, self.print_space());
+                walk_opt_two!(self, visit_keyword, into_kw, self.print_space());
+                self.print_space();
+                self.visit_expression(object);
+                walk_opt_two!(self, visit_expression_list, columns, self.print_space());
+                self.print_new_line();
+                self.visit_keyword(values_kw);
+                self.print_space();
+                self.visit_expression_list(values);
+            }
+            parser::ast::InsertStatement::Table {
+                insert_kw,
+                insert_top,
+                into_kw,
+                object,
+                select_kw,
+                top,
+                columns,
+                table,
+                where_clause,
+            } => {
+                self.visit_keyword(insert_kw);
+                walk_opt_two!(self, visit_top_clause, insert_top, self.print_space());
+                walk_opt_two!(self, visit_keyword, into_kw, self.print_space());
+                self.print_space();
+                self.visit_expression(object);
+                self.print_new_line();
+                self.visit_keyword(select_kw);
+                walk_opt_two!(self, visit_top_clause, top, self.print_space());
+                if columns.len() == 1 {
+                    self.print_space();
+                } else {
+                    self.increase_indent();
+                    self.print_new_line();
+                }
+                for (i, column) in columns.iter().enumerate() {
+                    if i > 0 {
+                        self.print_select_column_comma();
+                    }
+                    self.visit_expression(column);
+                }
+                if columns.len() > 1 {
+                    self.decrease_indent();
+                }
+                self.print_new_line();
+                self.visit_table_clause(table);
+                walk_opt_two!(
+                    self,
+                    visit_where_clause,
+                    where_clause,
+                    self.print_new_line()
+                );
+            }
+        }
+    }
+
+    fn visit_delete_statement(&mut self, stmt: &parser::ast::DeleteStatement) -> Self::Result {
+        self.visit_keyword(&stmt.delete_kw);
+        walk_opt_two!(self, visit_top_clause, &stmt.top, self.print_space());
+        self.print_new_line();
+        self.visit_table_clause(&stmt.table);
+        walk_opt_two!(
+            self,
+            visit_output_clause,
+            &stmt.output,
+            self.print_new_line()
+        );
+        walk_opt_two!(
+            self,
+            visit_where_clause,
+            &stmt.where_clause,
+            self.print_new_line()
+        );
+    }
+
+    fn visit_merge_statement(&mut self, stmt: &parser::ast::MergeStatement) -> Self::Result {
+        self.visit_keyword(&stmt.merge_kw);
+        walk_opt_two!(self, visit_top_clause, &stmt.top, self.print_space());
+        walk_opt_two!(self, visit_keyword, &stmt.into_kw, self.print_space());
+        self.print_space();
+        self.visit_table_source(&stmt.target);
+        self.print_new_line();
+        self.visit_keyword(&stmt.using_kw);
+        self.print_space();
+        self.visit_table_source(&stmt.source);
+        self.print_new_line();
+        self.increase_indent();
+        self.visit_keyword(&stmt.on_kw);
+        self.print_space();
+        self.visit_expression(&stmt.condition);
+        self.decrease_indent();
+        for clause in stmt.clauses.iter() {
+            self.print_new_line();
+            self.visit_merge_clause(clause);
+        }
+        walk_opt_two!(
+            self,
+            visit_output_clause,
+            &stmt.output,
+            self.print_new_line()
+        );
+    }
+
+    fn visit_merge_clause(&mut self, clause: &parser::ast::MergeClause) -> Self::Result {
+        for (i, kw) in clause.when_kws.iter().enumerate() {
+            if i > 0 {
+                self.print_space();
+            }
+            self.visit_keyword(kw);
+        }
+        if let Some((and_kw, condition)) = &clause.condition {
+            self.print_space();
+            self.visit_keyword(and_kw);
+            self.print_space();
+            self.visit_expression(condition);
+        }
+        self.print_space();
+        self.visit_keyword(&clause.then_kw);
+        // the action goes under the WHEN it belongs to
+        self.increase_indent();
+        self.print_new_line();
+        match &clause.action {
+            parser::ast::MergeAction::Update {
+                update_kw,
+                set_kw,
+                update_columns,
+            } => {
+                self.visit_keyword(update_kw);
+                self.print_space();
+                self.visit_keyword(set_kw);
+                self.print_update_columns(update_columns);
+            }
+            parser::ast::MergeAction::Delete(delete_kw) => self.visit_keyword(delete_kw),
+            parser::ast::MergeAction::Insert {
+                insert_kw,
+                columns,
+                values_kw,
+                values,
+            } => {
+                self.visit_keyword(insert_kw);
+                walk_opt_two!(self, visit_expression_list, columns, self.print_space());
+                self.print_new_line();
+                self.visit_keyword(values_kw);
+                self.print_space();
+                self.visit_expression_list(values);
+            }
+        }
+        self.decrease_indent();
+    }
+
+    fn visit_output_clause(&mut self, output: &parser::ast::OutputClause) -> Self::Result {
+        self.visit_keyword(&output.output_kw);
+        if output.columns.len() == 1 {
+            self.print_space();
+        } else {
+            self.increase_indent();
+            self.print_new_line();
+        }
+        for (i, select_item) in output.columns.iter().enumerate() {
+            if i > 0 {
+                self.print_select_column_comma();
+            }
+            self.visit_select_item(select_item);
+        }
+        if output.columns.len() > 1 {
+            self.decrease_indent();
+        }
+        if let Some(into_table) = &output.into_table {
+            self.print_new_line();
+            self.visit_keyword(&into_table.into_kw);
+            self.print_space();
+            self.visit_expression(&into_table.table);
+            walk_opt_two!(
+                self,
+                visit_expression_list,
+                &into_table.columns,
+                self.print_space()
+            );
+        }
+    }
+
     fn visit_select_statement(&mut self, stmt: &parser::ast::SelectStatement) -> Self::Result {
         self.visit_keyword(&stmt.select);
         walk_opt_two!(self, visit_keyword, &stmt.distinct, self.print_space());
+        if let Some(distinct_on) = &stmt.distinct_on {
+            self.print_space();
+            self.visit_keyword(&distinct_on.0);
+            self.print_space();
+            self.visit_symbol(&distinct_on.1.left_paren);
+            for (i, item) in distinct_on.1.items.iter().enumerate() {
+                if i > 0 {
+                    self.formatted_query += ", ";
+                }
+                self.visit_expression(item);
+            }
+            self.visit_symbol(&distinct_on.1.right_paren);
+        }
         walk_opt_two!(self, visit_keyword, &stmt.all, self.print_space());
         walk_opt_two!(self, visit_top_clause, &stmt.top, self.print_space());
         if stmt.columns.len() == 1 {
@@ -513,6 +1121,12 @@ impl Visitor for Formatter {
         if stmt.columns.len() > 1 {
             self.decrease_indent();
         }
+        walk_opt_two!(
+            self,
+            visit_into_arg,
+            &stmt.into_table,
+            self.print_new_line()
+        );
         walk_opt_two!(self, visit_table_clause, &stmt.table, self.print_new_line());
         walk_opt_two!(
             self,
@@ -538,12 +1152,13 @@ impl Visitor for Formatter {
             &stmt.order_by,
             self.print_new_line()
         );
+        walk_opt_two!(self, visit_limit_clause, &stmt.limit, self.print_new_line());
     }
 
     fn visit_select_item(&mut self, select_item: &parser::ast::SelectItem) -> Self::Result {
         match select_item {
             SelectItem::Wildcard(s) => self.visit_symbol(s),
-            SelectItem::Unnamed(e) => self.visit_expression(e),
+            SelectItem::QualifiedWildcard(e) | SelectItem::Unnamed(e) => self.visit_expression(e),
             SelectItem::WithAlias {
                 expression,
                 as_kw,
@@ -579,7 +1194,10 @@ impl Visitor for Formatter {
             Expression::Asterisk(s) => self.visit_symbol(s),
             Expression::Identifier(l) => {
                 self.print_comments_before(l.location);
-                self.visit_literal(l);
+                // quoted identifiers and strings keep their case, only plain
+                // identifiers follow the identifier case setting
+                self.print_identifier(&l.content);
+                self.visit_span(&l.location);
                 self.print_comments_same_line(l.location);
             }
             Expression::QuotedIdentifier(l) => {
@@ -633,9 +1251,18 @@ impl Visitor for Formatter {
                 right,
             } => {
                 self.visit_expression(left);
-                self.print_new_line();
-                self.visit_keyword(and_kw);
-                self.print_space();
+                match self.settings.boolean_operator_position {
+                    BooleanOperatorPosition::Leading => {
+                        self.print_new_line();
+                        self.visit_keyword(and_kw);
+                        self.print_space();
+                    }
+                    BooleanOperatorPosition::Trailing => {
+                        self.print_space();
+                        self.visit_keyword(and_kw);
+                        self.print_new_line();
+                    }
+                }
                 self.visit_expression(right);
             }
             Expression::Or { or_kw, left, right } => {
@@ -663,29 +1290,35 @@ impl Visitor for Formatter {
             Expression::Function {
                 name,
                 left_paren,
+                set_quantifier,
                 args,
                 right_paren,
                 over,
             } => {
                 self.visit_function_name(name);
                 self.visit_symbol(left_paren);
+                if let Some(set_quantifier) = set_quantifier {
+                    self.visit_keyword(set_quantifier);
+                    self.print_space();
+                }
                 if let Some(args) = args {
-                    for (i, arg) in args.iter().enumerate() {
-                        if i > 0 {
-                            self.formatted_query += ", ";
-                        }
-                        self.visit_expression(arg);
-                    }
+                    self.print_inline_list(args, |f, arg| f.visit_expression(arg));
                 }
                 self.visit_symbol(right_paren);
-                self.increase_indent();
-                walk_opt_two!(
-                    self,
-                    visit_function_over_clause,
-                    over,
-                    self.print_new_line()
-                );
-                self.decrease_indent();
+                if let Some(over) = over {
+                    // an empty window stays on the same line as the function
+                    let is_empty_window = over.partition_by_kws.is_none()
+                        && over.order_by_kws.is_none()
+                        && over.window_frame.is_none();
+                    self.increase_indent();
+                    if is_empty_window {
+                        self.print_space();
+                    } else {
+                        self.print_new_line();
+                    }
+                    self.visit_function_over_clause(over);
+                    self.decrease_indent();
+                }
             }
             Expression::Cast {
                 cast_kw,
@@ -718,12 +1351,7 @@ impl Visitor for Formatter {
                 walk_opt_two!(self, visit_keyword, not_kw, self.print_space());
                 self.print_space();
                 self.print_column_list_open_paren_symbol(left_paren);
-                for (i, item) in list.iter().enumerate() {
-                    if i > 0 {
-                        self.print_in_list_comma();
-                    }
-                    self.visit_expression(item);
-                }
+                self.print_in_list(list, |f, item| f.visit_expression(item));
                 self.print_column_list_close_paren_symbol(right_paren);
             }
             Expression::InSubquery {
@@ -741,17 +1369,37 @@ impl Visitor for Formatter {
             }
             Expression::Subquery {
                 left_paren,
-                select_statement,
+                statement,
                 right_paren,
             } => {
                 self.visit_symbol(left_paren);
                 self.increase_indent();
                 self.print_new_line();
-                self.visit_select_statement(select_statement);
+                self.visit_statement(statement);
                 self.decrease_indent();
                 self.print_new_line();
                 self.visit_symbol(right_paren);
             }
+            Expression::Grouping {
+                left_paren,
+                expression,
+                right_paren,
+            } => {
+                self.visit_symbol(left_paren);
+                self.visit_expression(expression);
+                self.visit_symbol(right_paren);
+            }
+            Expression::Subscript {
+                base,
+                left_bracket,
+                index,
+                right_bracket,
+            } => {
+                self.visit_expression(base);
+                self.visit_symbol(left_bracket);
+                self.visit_expression(index);
+                self.visit_symbol(right_bracket);
+            }
             Expression::Between {
                 test_expression,
                 not_kw,
@@ -833,6 +1481,7 @@ impl Visitor for Formatter {
                 not_kw,
                 like_kw,
                 pattern,
+                ..
             } => {
                 self.visit_expression(match_expression);
                 walk_opt_two!(self, visit_keyword, not_kw, self.print_space());
@@ -846,45 +1495,12 @@ impl Visitor for Formatter {
                 input_expression,
                 conditions,
                 end_kw,
-            } => {
-                self.visit_keyword(case_kw);
-                self.print_space();
-                self.visit_expression(input_expression);
-                // for (i, c) in conditions.iter().enumerate() {
-                //    self.visit_case_condition(c);
-                // }
-                self.increase_indent();
-                walk_list_two!(
-                    self,
-                    visit_case_condition,
-                    conditions,
-                    self.print_new_line()
-                );
-                self.print_new_line();
-                self.visit_keyword(end_kw);
-                self.decrease_indent();
-            }
+            } => self.print_case_expression(case_kw, Some(input_expression), conditions, end_kw),
             Expression::SearchedCase {
                 case_kw,
                 conditions,
                 end_kw,
-            } => {
-                self.visit_keyword(case_kw);
-                self.print_space();
-                // for (i, c) in conditions.iter().enumerate() {
-                //    self.visit_case_condition(c);
-                // }
-                self.increase_indent();
-                walk_list_two!(
-                    self,
-                    visit_case_condition,
-                    conditions,
-                    self.print_new_line()
-                );
-                self.print_new_line();
-                self.visit_keyword(end_kw);
-                self.decrease_indent();
-            }
+            } => self.print_case_expression(case_kw, None, conditions, end_kw),
         }
     }
 
@@ -978,6 +1594,30 @@ impl Visitor for Formatter {
         self.decrease_indent();
     }
 
+    fn visit_into_arg(&mut self, into_arg: &parser::ast::IntoArg) -> Self::Result {
+        self.visit_keyword(&into_arg.into_kw);
+        self.print_space();
+        self.visit_expression(&into_arg.table);
+        if let Some((on_kw, file_group)) = &into_arg.file_group {
+            self.print_space();
+            self.visit_keyword(on_kw);
+            self.print_space();
+            self.visit_expression(file_group);
+        }
+    }
+
+    fn visit_limit_clause(&mut self, limit_clause: &parser::ast::LimitClause) -> Self::Result {
+        self.visit_keyword(&limit_clause.limit_kw);
+        self.print_space();
+        self.visit_expression(&limit_clause.value);
+        if let Some(offset) = &limit_clause.offset {
+            self.print_space();
+            self.visit_keyword(&offset.0);
+            self.print_space();
+            self.visit_expression(&offset.1);
+        }
+    }
+
     fn visit_order_by_arg(&mut self, order_by_arg: &parser::ast::OrderByArg) -> Self::Result {
         self.visit_expression(&order_by_arg.column);
         walk_opt_two!(
@@ -986,6 +1626,12 @@ impl Visitor for Formatter {
             &order_by_arg.order_kw,
             self.print_space()
         );
+        if let Some(kws) = &order_by_arg.nulls_kws {
+            for kw in kws.iter() {
+                self.print_space();
+                self.visit_keyword(kw);
+            }
+        }
     }
 
     fn visit_table_clause(&mut self, table_clause: &parser::ast::TableArg) -> Self::Result {
@@ -1004,8 +1650,13 @@ impl Visitor for Formatter {
                 self.visit_expression(name);
                 walk_opt_two!(self, visit_expression, alias, self.print_space());
             }
-            TableSource::Derived { query, alias } => {
+            TableSource::Derived {
+                query,
+                as_kw,
+                alias,
+            } => {
                 self.visit_expression(query);
+                walk_opt_two!(self, visit_keyword, as_kw, self.print_space());
                 self.print_space();
                 self.visit_expression(alias);
             }
@@ -1013,6 +1664,20 @@ impl Visitor for Formatter {
                 self.visit_expression(function);
                 walk_opt_two!(self, visit_expression, alias, self.print_space());
             }
+            TableSource::JoinGroup {
+                left_paren,
+                table,
+                joins,
+                right_paren,
+            } => {
+                self.visit_symbol(left_paren);
+                self.visit_table_source(table);
+                for join in joins.iter() {
+                    self.print_space();
+                    self.visit_table_join(join);
+                }
+                self.visit_symbol(right_paren);
+            }
         }
     }
 
@@ -1042,6 +1707,42 @@ impl Visitor for Formatter {
         self.visit_expression(&table_join_condition.condition);
     }
 
+    fn visit_expression_list(&mut self, list: &parser::ast::ExpressionList) -> Self::Result {
+        self.visit_symbol(&list.left_paren);
+        self.print_inline_list(&list.items, |f, item| f.visit_expression(item));
+        self.visit_symbol(&list.right_paren);
+    }
+
+    fn visit_truncate_partitions(
+        &mut self,
+        partitions: &parser::ast::TruncatePartitions,
+    ) -> Self::Result {
+        self.visit_keyword(&partitions.with_kw);
+        self.print_space();
+        self.visit_symbol(&partitions.left_paren);
+        self.visit_keyword(&partitions.partitions_kw);
+        self.print_space();
+        self.visit_symbol(&partitions.partitions_left_paren);
+        self.print_inline_list(&partitions.partitions, |f, partition| {
+            f.visit_partition_range(partition)
+        });
+        self.visit_symbol(&partitions.partitions_right_paren);
+        self.visit_symbol(&partitions.right_paren);
+    }
+
+    fn visit_partition_range(&mut self, partition: &parser::ast::PartitionRange) -> Self::Result {
+        match partition {
+            parser::ast::PartitionRange::Single(partition) => self.visit_expression(partition),
+            parser::ast::PartitionRange::Range { start, to_kw, end } => {
+                self.visit_expression(start);
+                self.print_space();
+                self.visit_keyword(to_kw);
+                self.print_space();
+                self.visit_expression(end);
+            }
+        }
+    }
+
     fn visit_top_clause(&mut self, top_clause: &parser::ast::Top) -> Self::Result {
         self.visit_keyword(&top_clause.top);
         self.print_space();
@@ -1,5 +1,5 @@
 use clap::Parser;
-use settings::{IndentCommaLists, KeywordCase};
+use settings::{BooleanOperatorPosition, IdentifierCase, IndentCommaLists, KeywordCase};
 
 pub mod comments;
 pub mod formatter;
@@ -15,14 +15,24 @@ struct Cli {
     indent_in_lists: bool,
     #[arg(short = 'b', long, default_value_t = false)]
     indent_between_conditions: bool,
+    #[arg(long, default_value_t = BooleanOperatorPosition::Leading)]
+    boolean_operator_position: BooleanOperatorPosition,
+    #[arg(long, default_value_t = false)]
+    case_on_new_line: bool,
     #[arg(short, long, default_value_t = KeywordCase::Upper)]
     keyword_case: KeywordCase,
+    #[arg(long)]
+    identifier_case: Option<IdentifierCase>,
     #[arg(short, long, default_value_t = 80)]
     max_width: u32,
+    #[arg(long)]
+    max_line_width: Option<usize>,
     #[arg(short = 'w', long, default_value_t = 4)]
     indent_width: u32,
     #[arg(short, long, default_value_t = false)]
     use_tab: bool,
+    #[arg(long, default_value_t = false)]
+    terminate_statements: bool,
 }
 
 fn main() {
@@ -31,10 +41,15 @@ fn main() {
         indent_comma_lists: cli.indent_comma_lists,
         indent_in_lists: cli.indent_in_lists,
         indent_between_conditions: cli.indent_between_conditions,
+        boolean_operator_position: cli.boolean_operator_position,
+        case_on_new_line: cli.case_on_new_line,
         keyword_case: cli.keyword_case,
+        identifier_case: cli.identifier_case,
         max_width: cli.max_width,
+        max_line_width: cli.max_line_width,
         indent_width: cli.indent_width,
         use_tab: cli.use_tab,
+        terminate_statements: cli.terminate_statements,
     };
     let mut formatter = formatter::Formatter::new(formatter_settings);
     if let Err(e) = formatter.format(&cli.input) {
@@ -12,6 +12,20 @@ pub enum IndentCommaLists {
 pub enum KeywordCase {
     Upper,
     Lower,
+    Capitalize,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum IdentifierCase {
+    Upper,
+    Lower,
+    Preserve,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum BooleanOperatorPosition {
+    Leading,
+    Trailing,
 }
 
 impl fmt::Display for KeywordCase {
@@ -19,6 +33,7 @@ impl fmt::Display for KeywordCase {
         match self {
             KeywordCase::Upper => f.write_str("upper"),
             KeywordCase::Lower => f.write_str("lower"),
+            KeywordCase::Capitalize => f.write_str("capitalize"),
         }
     }
 }
@@ -28,8 +43,22 @@ pub struct FormatterSettings {
     pub indent_comma_lists: Option<IndentCommaLists>,
     pub indent_in_lists: bool,
     pub indent_between_conditions: bool,
+    pub boolean_operator_position: BooleanOperatorPosition,
+    pub case_on_new_line: bool,
     pub keyword_case: KeywordCase,
+    pub identifier_case: Option<IdentifierCase>,
     pub max_width: u32,
+    pub max_line_width: Option<usize>,
     pub indent_width: u32,
     pub use_tab: bool,
+    pub terminate_statements: bool,
+}
+
+impl fmt::Display for BooleanOperatorPosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BooleanOperatorPosition::Leading => f.write_str("leading"),
+            BooleanOperatorPosition::Trailing => f.write_str("trailing"),
+        }
+    }
 }
@@ -0,0 +1,30 @@
+-- create the archive table
+create table MarketArchive (
+    Id int identity(1, 1) primary key,
+    Symbol varchar(10) not null,
+    LastPrice decimal(10, 2) null,
+    Volume bigint default 0
+);
+
+insert into MarketArchive (Symbol, LastPrice, Volume)
+values ('MSFT', 412.5, 1000);
+
+insert into MarketArchive
+select Symbol, LastPrice, Volume from MarketData where Volume > 0;
+
+update MarketArchive set LastPrice = LastPrice * 1.1, Volume = 0
+output inserted.Symbol, deleted.LastPrice as OldPrice, inserted.LastPrice
+where Symbol = 'MSFT';
+
+delete top (100) from MarketArchive output deleted.* into @removed
+where Volume = 0 and LastPrice < 1;
+
+-- keep the archive in step with the live prices
+merge into MarketArchive t
+using MarketData s on t.Symbol = s.Symbol
+when matched and s.Volume > 0 then
+    update set LastPrice = s.LastPrice, Volume = t.Volume + s.Volume
+when not matched by target then
+    insert (Symbol, LastPrice, Volume) values (s.Symbol, s.LastPrice, s.Volume)
+when not matched by source then delete
+output deleted.Symbol, inserted.Symbol;
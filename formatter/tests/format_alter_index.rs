@@ -0,0 +1,34 @@
+use formatter::formatter::Formatter;
+use formatter::settings::{BooleanOperatorPosition, FormatterSettings, KeywordCase};
+
+#[test]
+fn alter_index() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+    let mut formatter = Formatter::new(formatter_settings);
+
+    let input = r"alter index IX_MarketData_Symbol on dbo.MarketData rebuild";
+    let expected = r"ALTER INDEX IX_MarketData_Symbol ON dbo.MarketData REBUILD";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    let input = r"alter  index all
+on MarketData   reorganize";
+    let expected = r"ALTER INDEX ALL ON MarketData REORGANIZE";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    Ok(())
+}
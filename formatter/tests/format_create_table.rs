@@ -0,0 +1,35 @@
+use formatter::formatter::Formatter;
+use formatter::settings::{BooleanOperatorPosition, FormatterSettings, KeywordCase};
+
+#[test]
+fn basic_create_table() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+    let mut formatter = Formatter::new(formatter_settings);
+
+    let input = r"create table dbo.Customers (Id int identity(1,1) primary key,
+    Name varchar(50) not null, Balance decimal(10,2) default 0)";
+    let expected = r"CREATE TABLE dbo.Customers (
+    Id INT IDENTITY(1, 1) PRIMARY KEY
+    ,Name VARCHAR(50) NOT NULL
+    ,Balance DECIMAL(10, 2) DEFAULT 0
+)";
+    formatter.format(input)?;
+
+    let formatted_query = formatter.formatted_query();
+    assert_eq!(expected, formatted_query);
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use formatter::formatter::Formatter;
+use formatter::settings::{BooleanOperatorPosition, FormatterSettings, KeywordCase};
+
+#[test]
+fn insert_values() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+    let mut formatter = Formatter::new(formatter_settings);
+
+    let input = r"insert into MarketData (Symbol, LastPrice) values ('MSFT', 412.5)";
+    let expected = r"INSERT INTO MarketData (Symbol, LastPrice)
+VALUES ('MSFT', 412.5)";
+    formatter.format(input)?;
+
+    let formatted_query = formatter.formatted_query();
+    assert_eq!(expected, formatted_query);
+
+    Ok(())
+}
+
+#[test]
+fn insert_select() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+    let mut formatter = Formatter::new(formatter_settings);
+
+    let input = r"insert top (10) into MarketArchive select Symbol, LastPrice from MarketData where Volume = 0";
+    let expected = r"INSERT TOP (10) INTO MarketArchive
+SELECT
+    Symbol
+    ,LastPrice
+FROM MarketData
+WHERE Volume = 0";
+    formatter.format(input)?;
+
+    let formatted_query = formatter.formatted_query();
+    assert_eq!(expected, formatted_query);
+
+    Ok(())
+}
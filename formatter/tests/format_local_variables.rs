@@ -1,5 +1,5 @@
 use formatter::formatter::Formatter;
-use formatter::settings::{FormatterSettings, KeywordCase};
+use formatter::settings::{BooleanOperatorPosition, FormatterSettings, KeywordCase};
 
 
 #[test]
@@ -8,10 +8,15 @@ fn basic_local_variables() -> Result<(), String> {
         indent_comma_lists: None,
         indent_in_lists: true,
         indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
         keyword_case: KeywordCase::Lower,
+        identifier_case: None,
         max_width: 80,
+        max_line_width: None,
         indent_width: 4,
         use_tab: false,
+        terminate_statements: false,
     };
     let mut formatter = Formatter::new(formatter_settings);
 
@@ -34,3 +39,62 @@ set @TestDos = cast(getdate() as date)";
 
     Ok(())
 }
+
+#[test]
+fn set_local_variable_compound_assignment() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+    let mut formatter = Formatter::new(formatter_settings);
+
+    let input = r"set @counter = @counter + 1; set @total -= @fee";
+    let expected = r"SET @counter = @counter + 1;
+
+SET @total -= @fee";
+    formatter.format(input)?;
+
+    let formatted_query = formatter.formatted_query();
+    assert_eq!(expected, formatted_query);
+
+    Ok(())
+}
+
+#[test]
+fn declare_local_variables_with_sized_data_types() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+    let mut formatter = Formatter::new(formatter_settings);
+
+    let input = r"declare @name nvarchar(max), @code char(3)";
+    let expected = r"DECLARE @name NVARCHAR(MAX)
+    ,@code CHAR(3)";
+    formatter.format(input)?;
+
+    let formatted_query = formatter.formatted_query();
+    assert_eq!(expected, formatted_query);
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use formatter::formatter::Formatter;
+use formatter::settings::{BooleanOperatorPosition, FormatterSettings, KeywordCase};
+
+#[test]
+fn merge_with_output() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+    let mut formatter = Formatter::new(formatter_settings);
+
+    let input = r"merge into MarketArchive t using MarketData s on t.Symbol = s.Symbol
+when matched then update set LastPrice = s.LastPrice, Volume = s.Volume
+when not matched by target then insert (Symbol) values (s.Symbol)
+when not matched by source then delete output deleted.Symbol";
+    let expected = r"MERGE INTO MarketArchive t
+USING MarketData s
+ON t.Symbol = s.Symbol
+WHEN MATCHED THEN
+    UPDATE SET
+        LastPrice = s.LastPrice
+        ,Volume = s.Volume
+WHEN NOT MATCHED BY TARGET THEN
+    INSERT (Symbol)
+    VALUES (s.Symbol)
+WHEN NOT MATCHED BY SOURCE THEN
+    DELETE
+OUTPUT deleted.Symbol";
+    formatter.format(input)?;
+
+    let formatted_query = formatter.formatted_query();
+    assert_eq!(expected, formatted_query);
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use formatter::formatter::Formatter;
+use formatter::settings::{BooleanOperatorPosition, FormatterSettings, KeywordCase};
+
+const MIGRATION_SCRIPT: &str = include_str!("fixtures/migration.sql");
+
+#[test]
+fn migration_script() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: true,
+    };
+    let mut parser = parser::Parser::new(lexer::Lexer::new(MIGRATION_SCRIPT));
+    let query = parser.parse();
+    assert!(parser.errors().is_empty());
+    assert_eq!(6, query.statements.len());
+
+    let mut formatter = Formatter::new(formatter_settings);
+    formatter.format(MIGRATION_SCRIPT)?;
+    let formatted_query = formatter.formatted_query().to_string();
+
+    let mut formatter = Formatter::new(formatter_settings);
+    formatter.format(&formatted_query)?;
+    assert_eq!(formatted_query, formatter.formatted_query());
+
+    Ok(())
+}
@@ -1,5 +1,7 @@
 use formatter::formatter::Formatter;
-use formatter::settings::{FormatterSettings, IndentCommaLists, KeywordCase};
+use formatter::settings::{
+    BooleanOperatorPosition, FormatterSettings, IdentifierCase, IndentCommaLists, KeywordCase,
+};
 
 #[test]
 fn basic_select_statement() -> Result<(), String> {
@@ -7,10 +9,15 @@ fn basic_select_statement() -> Result<(), String> {
         indent_comma_lists: None,
         indent_in_lists: true,
         indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
         keyword_case: KeywordCase::Upper,
+        identifier_case: None,
         max_width: 80,
+        max_line_width: None,
         indent_width: 4,
         use_tab: false,
+        terminate_statements: false,
     };
     let mut formatter = Formatter::new(formatter_settings);
 
@@ -48,10 +55,15 @@ fn basic_select_statement_two() -> Result<(), String> {
         indent_comma_lists: None,
         indent_in_lists: true,
         indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
         keyword_case: KeywordCase::Lower,
+        identifier_case: None,
         max_width: 80,
+        max_line_width: None,
         indent_width: 4,
         use_tab: false,
+        terminate_statements: false,
     };
     let mut formatter = Formatter::new(formatter_settings);
 
@@ -89,10 +101,15 @@ fn basic_select_statement_three() -> Result<(), String> {
         indent_comma_lists: None,
         indent_in_lists: true,
         indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
         keyword_case: KeywordCase::Lower,
+        identifier_case: None,
         max_width: 80,
+        max_line_width: None,
         indent_width: 4,
         use_tab: false,
+        terminate_statements: false,
     };
     let mut formatter = Formatter::new(formatter_settings);
 
@@ -127,10 +144,15 @@ fn basic_select_statement_four() -> Result<(), String> {
         indent_comma_lists: None,
         indent_in_lists: true,
         indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
         keyword_case: KeywordCase::Lower,
+        identifier_case: None,
         max_width: 80,
+        max_line_width: None,
         indent_width: 4,
         use_tab: false,
+        terminate_statements: false,
     };
     let mut formatter = Formatter::new(formatter_settings);
 
@@ -143,18 +165,12 @@ fn basic_select_statement_four() -> Result<(), String> {
     let expected = r"select top 30 percent with ties
     LastPrice
     ,PC as 'PercentChange'
-    ,case LastPrice
-        when 02
-            then 'blah'
+    ,case LastPrice when 02 then 'blah' else 'no' end
+    ,case
+        when LastPrice > 7 then 'blah'
+        when LastPrice > 55 then 'yo'
         else 'no'
-        end
-    ,case 
-        when LastPrice > 7
-            then 'blah'
-        when LastPrice > 55
-            then 'yo'
-        else 'no'
-        end
+    end
 from MarketTable mkt
 where QuoteTime between '6:30'
         and '13:00'
@@ -179,10 +195,15 @@ fn basic_select_statement_five() -> Result<(), String> {
         indent_comma_lists: None,
         indent_in_lists: true,
         indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
         keyword_case: KeywordCase::Lower,
+        identifier_case: None,
         max_width: 80,
+        max_line_width: None,
         indent_width: 4,
         use_tab: false,
+        terminate_statements: false,
     };
     let mut formatter = Formatter::new(formatter_settings);
 
@@ -209,10 +230,15 @@ fn basic_select_statement_with_unions() -> Result<(), String> {
         indent_comma_lists: None,
         indent_in_lists: true,
         indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
         keyword_case: KeywordCase::Lower,
+        identifier_case: None,
         max_width: 80,
+        max_line_width: None,
         indent_width: 4,
         use_tab: false,
+        terminate_statements: false,
     };
     let mut formatter = Formatter::new(formatter_settings);
 
@@ -240,3 +266,1015 @@ from PotatoTable";
 
     Ok(())
 }
+
+#[test]
+fn basic_select_statement_with_except_intersect() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+    let mut formatter = Formatter::new(formatter_settings);
+
+    let input = r"select Symbol from MarketTable except select Symbol from Delisted
+    intersect select Symbol from Watchlist";
+    let expected = r"SELECT Symbol
+FROM MarketTable
+
+EXCEPT
+
+SELECT Symbol
+FROM Delisted
+
+INTERSECT
+
+SELECT Symbol
+FROM Watchlist";
+    formatter.format(input)?;
+
+    let formatted_query = formatter.formatted_query();
+    assert_eq!(expected, formatted_query);
+
+    Ok(())
+}
+
+#[test]
+fn basic_select_statement_with_derived_table() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+    let mut formatter = Formatter::new(formatter_settings);
+
+    let input = r"select m.Symbol, t.MaxPrice from Market m inner join (select Symbol,
+    max(LastPrice) MaxPrice from MarketData group by Symbol) as t on m.Symbol = t.Symbol";
+    let expected = r"SELECT
+    m.Symbol
+    ,t.MaxPrice
+FROM Market m
+INNER JOIN (
+    SELECT
+        Symbol
+        ,MAX(LastPrice) MaxPrice
+    FROM MarketData
+    GROUP BY Symbol
+) AS t ON m.Symbol = t.Symbol";
+    formatter.format(input)?;
+
+    let formatted_query = formatter.formatted_query();
+    assert_eq!(expected, formatted_query);
+
+    Ok(())
+}
+
+#[test]
+fn basic_select_statement_with_cross_apply() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+    let mut formatter = Formatter::new(formatter_settings);
+
+    let input = r"select m.Symbol, q.LastPrice from Market m cross apply
+    dbo.LatestQuotes(m.Symbol) q outer apply dbo.Dividends(m.Symbol, 5) d";
+    let expected = r"SELECT
+    m.Symbol
+    ,q.LastPrice
+FROM Market m
+CROSS APPLY dbo.LatestQuotes(m.Symbol) q
+OUTER APPLY dbo.Dividends(m.Symbol, 5) d";
+    formatter.format(input)?;
+
+    let formatted_query = formatter.formatted_query();
+    assert_eq!(expected, formatted_query);
+
+    Ok(())
+}
+
+#[test]
+fn basic_select_statement_with_into() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+    let mut formatter = Formatter::new(formatter_settings);
+
+    let input = r"select Symbol, LastPrice into PriceSnapshot from MarketData";
+    let expected = r"SELECT
+    Symbol
+    ,LastPrice
+INTO PriceSnapshot
+FROM MarketData";
+    formatter.format(input)?;
+
+    let formatted_query = formatter.formatted_query();
+    assert_eq!(expected, formatted_query);
+
+    Ok(())
+}
+
+#[test]
+fn basic_select_statement_with_qualified_wildcard() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+    let mut formatter = Formatter::new(formatter_settings);
+
+    let input = r"select t.*, u.name from t inner join users u on t.id = u.id";
+    let expected = r"SELECT
+    t.*
+    ,u.name
+FROM t
+INNER JOIN users u ON t.id = u.id";
+    formatter.format(input)?;
+
+    let formatted_query = formatter.formatted_query();
+    assert_eq!(expected, formatted_query);
+
+    Ok(())
+}
+
+#[test]
+fn basic_select_statement_with_join_group() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+    let mut formatter = Formatter::new(formatter_settings);
+
+    let input = r"select a.id from a left join (b join c on b.id=c.id) on a.id=b.id";
+    let expected = r"SELECT a.id
+FROM a
+LEFT JOIN (b JOIN c ON b.id = c.id) ON a.id = b.id";
+    formatter.format(input)?;
+
+    let formatted_query = formatter.formatted_query();
+    assert_eq!(expected, formatted_query);
+
+    Ok(())
+}
+
+#[test]
+fn basic_select_statement_with_empty_over_clause() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+    let mut formatter = Formatter::new(formatter_settings);
+
+    let input = r"select sum(salary) over() from emp";
+    let expected = r"SELECT SUM(salary) OVER ()
+FROM emp";
+    formatter.format(input)?;
+
+    let formatted_query = formatter.formatted_query();
+    assert_eq!(expected, formatted_query);
+
+    Ok(())
+}
+
+#[test]
+fn basic_select_statement_with_count_distinct() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+    let mut formatter = Formatter::new(formatter_settings);
+
+    let input = r"select count(distinct symbol) from market";
+    let expected = r"SELECT COUNT(DISTINCT symbol)
+FROM market";
+    formatter.format(input)?;
+
+    let formatted_query = formatter.formatted_query();
+    assert_eq!(expected, formatted_query);
+
+    Ok(())
+}
+
+#[test]
+fn basic_select_statement_with_count_star() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+    let mut formatter = Formatter::new(formatter_settings);
+
+    let input = r"select count(*) from market";
+    let expected = r"SELECT COUNT(*)
+FROM market";
+    formatter.format(input)?;
+
+    let formatted_query = formatter.formatted_query();
+    assert_eq!(expected, formatted_query);
+
+    Ok(())
+}
+
+#[test]
+fn basic_select_statement_with_unicode_string_literal() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+    let mut formatter = Formatter::new(formatter_settings);
+
+    let input = r"select '🎉 party' as msg, naïve from [fête] where 名前 = 'ünïcode'";
+    let expected = r"SELECT
+    '🎉 party' AS msg
+    ,naïve
+FROM [fête]
+WHERE 名前 = 'ünïcode'";
+    formatter.format(input)?;
+
+    let formatted_query = formatter.formatted_query();
+    assert_eq!(expected, formatted_query);
+
+    Ok(())
+}
+
+#[test]
+fn basic_select_statement_with_max_line_width() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: Some(IndentCommaLists::TrailingComma),
+        indent_in_lists: false,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+    let input = r"select coalesce(first_name, middle_name, last_name, nickname, 'unknown')
+    from users where id in (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16)";
+
+    let mut formatter = Formatter::new(formatter_settings);
+    let expected = r"SELECT coalesce(first_name, middle_name, last_name, nickname, 'unknown')
+FROM users
+WHERE id IN (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16)";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    let mut formatter = Formatter::new(FormatterSettings {
+        max_line_width: Some(40),
+        ..formatter_settings
+    });
+    let expected = r"SELECT coalesce(first_name, middle_name,
+    last_name, nickname, 'unknown')
+FROM users
+WHERE id IN (1, 2, 3, 4, 5, 6, 7, 8, 9,
+        10, 11, 12, 13, 14, 15, 16)";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+    assert!(formatter
+        .formatted_query()
+        .lines()
+        .all(|line| line.chars().count() <= 40));
+
+    Ok(())
+}
+
+#[test]
+fn nested_calls_with_narrow_max_line_width() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: Some(5),
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+    // each list is measured once, printing it again for every level of
+    // nesting would take minutes here
+    let depth = 20;
+    let input = format!(
+        "select {}1{} from t",
+        "f(a, ".repeat(depth),
+        ")".repeat(depth)
+    );
+
+    let mut formatter = Formatter::new(formatter_settings);
+    formatter.format(&input)?;
+    let formatted_query = formatter.formatted_query().to_string();
+    assert_eq!(depth + 2, formatted_query.lines().count());
+    assert!(formatted_query.starts_with("SELECT f(a,\n    f(a,\n"));
+
+    let mut formatter = Formatter::new(formatter_settings);
+    formatter.format(&formatted_query)?;
+    assert_eq!(formatted_query, formatter.formatted_query());
+
+    Ok(())
+}
+
+#[test]
+fn basic_select_statement_with_capitalized_keywords() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Capitalize,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+    let mut formatter = Formatter::new(formatter_settings);
+
+    let input = r"select top 10 with ties name, row_number() over (order by hired desc) as rn
+    from employees where not dept = 'hr' and salary between 10 and 20
+    group by name order by name";
+    let expected = r"Select Top 10 With Ties
+    name
+    ,Row_Number()
+        Over (
+            Order By hired Desc
+        ) As rn
+From employees
+Where Not dept = 'hr'
+    And salary Between 10
+        And 20
+Group By name
+Order By name";
+    formatter.format(input)?;
+
+    let formatted_query = formatter.formatted_query();
+    assert_eq!(expected, formatted_query);
+
+    Ok(())
+}
+
+#[test]
+fn basic_select_statement_with_identifier_case() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: Some(IdentifierCase::Lower),
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+    let input = r"select FirstName, [My Table].LastName as FullName, @MyVar
+    from dbo.MyTable inner join [My Table] on MyTable.Id = [My Table].Id where Kind = 'Literal'";
+
+    let mut formatter = Formatter::new(formatter_settings);
+    let expected = r"SELECT
+    firstname
+    ,[My Table].lastname AS fullname
+    ,@MyVar
+FROM dbo.mytable
+INNER JOIN [My Table] ON mytable.id = [My Table].id
+WHERE kind = 'Literal'";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    let mut formatter = Formatter::new(FormatterSettings {
+        identifier_case: Some(IdentifierCase::Preserve),
+        ..formatter_settings
+    });
+    let expected = r"SELECT
+    FirstName
+    ,[My Table].LastName AS FullName
+    ,@MyVar
+FROM dbo.MyTable
+INNER JOIN [My Table] ON MyTable.Id = [My Table].Id
+WHERE Kind = 'Literal'";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    Ok(())
+}
+
+#[test]
+fn basic_select_statement_with_case_layouts() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: false,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+    let input = r"select case when LastPrice > 7 then 'high' when LastPrice > 3 then 'medium'
+    else 'low' end as Bucket from Market";
+
+    let mut formatter = Formatter::new(formatter_settings);
+    let expected = r"SELECT CASE WHEN LastPrice > 7 THEN 'high' WHEN LastPrice > 3 THEN 'medium' ELSE 'low' END AS Bucket
+FROM Market";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    let mut formatter = Formatter::new(FormatterSettings {
+        case_on_new_line: true,
+        ..formatter_settings
+    });
+    let expected = r"SELECT CASE
+    WHEN LastPrice > 7 THEN 'high'
+    WHEN LastPrice > 3 THEN 'medium'
+    ELSE 'low'
+END AS Bucket
+FROM Market";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    let input = r"select case Kind when 1 then 'one' else 'other' end as Bucket from Market";
+    let mut formatter = Formatter::new(FormatterSettings {
+        case_on_new_line: true,
+        ..formatter_settings
+    });
+    let expected = r"SELECT CASE Kind WHEN 1 THEN 'one' ELSE 'other' END AS Bucket
+FROM Market";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    // a max line width narrower than the case expands it too
+    let mut formatter = Formatter::new(FormatterSettings {
+        case_on_new_line: true,
+        max_line_width: Some(40),
+        ..formatter_settings
+    });
+    let expected = r"SELECT CASE Kind
+    WHEN 1 THEN 'one'
+    ELSE 'other'
+END AS Bucket
+FROM Market";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    Ok(())
+}
+
+#[test]
+fn basic_select_statement_with_comments() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+
+    let input = "-- get the users\nselect Name from Users";
+    let mut formatter = Formatter::new(formatter_settings);
+    let expected = "-- get the users
+SELECT Name
+FROM Users";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    let input = "select Name -- the name\nfrom Users where Id = 1 -- the id\nand Active = 1";
+    let mut formatter = Formatter::new(formatter_settings);
+    let expected = "SELECT Name -- the name
+FROM Users
+WHERE Id = 1 -- the id
+    AND Active = 1";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    let input = "-- first\nselect 1;\n-- second\nselect 2";
+    let mut formatter = Formatter::new(formatter_settings);
+    let expected = "-- first
+SELECT 1;
+
+-- second
+SELECT 2";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    Ok(())
+}
+
+#[test]
+fn basic_select_statement_with_terminated_statements() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: true,
+    };
+
+    let input = "select Name from Users";
+    let mut formatter = Formatter::new(formatter_settings);
+    let expected = "SELECT Name
+FROM Users;";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    // formatting the output again does not add another terminator
+    let mut formatter = Formatter::new(formatter_settings);
+    formatter.format(expected)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    let input = "select Name from Users; select Id from Orders";
+    let mut formatter = Formatter::new(formatter_settings);
+    let expected = "SELECT Name
+FROM Users;
+
+SELECT Id
+FROM Orders;";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    let mut formatter = Formatter::new(formatter_settings);
+    formatter.format(expected)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    let input = "select Name from Users -- all users";
+    let mut formatter = Formatter::new(formatter_settings);
+    let expected = "SELECT Name
+FROM Users; -- all users";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    let input = "select Name from Users; select Id from Orders;";
+    let mut formatter = Formatter::new(FormatterSettings {
+        terminate_statements: false,
+        ..formatter_settings
+    });
+    let expected = "SELECT Name
+FROM Users;
+
+SELECT Id
+FROM Orders";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    let input = "select Name from Users;";
+    let mut formatter = Formatter::new(FormatterSettings {
+        terminate_statements: false,
+        ..formatter_settings
+    });
+    let expected = "SELECT Name
+FROM Users";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    Ok(())
+}
+
+#[test]
+fn basic_select_statement_with_common_table_expressions() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+
+    let input = r"with Recent as (select Id, Name from Users where Created > '2024-01-01'),
+    Named (Id, Label) as (select Id, Name from Recent) select Label from Named";
+    let mut formatter = Formatter::new(formatter_settings);
+    let expected = r"WITH Recent AS (
+    SELECT
+        Id
+        ,Name
+    FROM Users
+    WHERE Created > '2024-01-01'
+)
+,Named (Id, Label) AS (
+    SELECT
+        Id
+        ,Name
+    FROM Recent
+)
+SELECT Label
+FROM Named";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    // formatting the output again gives the same output
+    let mut formatter = Formatter::new(formatter_settings);
+    formatter.format(expected)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    let mut formatter = Formatter::new(FormatterSettings {
+        indent_comma_lists: Some(IndentCommaLists::TrailingComma),
+        indent_width: 2,
+        ..formatter_settings
+    });
+    let expected = r"WITH Recent AS (
+  SELECT
+    Id,
+    Name
+  FROM Users
+  WHERE Created > '2024-01-01'
+),
+Named (Id, Label) AS (
+  SELECT
+    Id,
+    Name
+  FROM Recent
+)
+SELECT Label
+FROM Named";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    let mut formatter = Formatter::new(FormatterSettings {
+        indent_comma_lists: Some(IndentCommaLists::TrailingComma),
+        indent_width: 2,
+        ..formatter_settings
+    });
+    formatter.format(expected)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    Ok(())
+}
+
+#[test]
+fn basic_select_statement_with_three_branch_case() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 60,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+    let mut formatter = Formatter::new(formatter_settings);
+
+    let input = r"select Symbol, case when Volume > 1000000 then 'heavy' when Volume > 1000 then 'normal'
+    when Volume * LastPrice > 100000 then 'light trading on a valuable symbol' else 'none' end
+    as Activity from MarketData";
+    let expected = r"SELECT
+    Symbol
+    ,CASE
+        WHEN Volume > 1000000 THEN 'heavy'
+        WHEN Volume > 1000 THEN 'normal'
+        WHEN Volume * LastPrice > 100000
+            THEN 'light trading on a valuable symbol'
+        ELSE 'none'
+    END AS Activity
+FROM MarketData";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    Ok(())
+}
+
+#[test]
+fn basic_select_statement_with_string_concatenation() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+    let mut formatter = Formatter::new(formatter_settings);
+
+    let input = r"select FirstName+' '+LastName as FullName from Users where 'x'  +Name='xy'";
+    let expected = r"SELECT FirstName + ' ' + LastName AS FullName
+FROM Users
+WHERE 'x' + Name = 'xy'";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    Ok(())
+}
+
+#[test]
+fn basic_select_statement_with_boolean_operator_position() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+
+    let input =
+        r"select Symbol from MarketData where Volume > 0 and LastPrice > 1 and Symbol = 'MSFT'";
+    let mut formatter = Formatter::new(formatter_settings);
+    let expected = r"SELECT Symbol
+FROM MarketData
+WHERE Volume > 0
+    AND LastPrice > 1
+    AND Symbol = 'MSFT'";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    let mut formatter = Formatter::new(FormatterSettings {
+        boolean_operator_position: BooleanOperatorPosition::Trailing,
+        ..formatter_settings
+    });
+    let expected = r"SELECT Symbol
+FROM MarketData
+WHERE Volume > 0 AND
+    LastPrice > 1 AND
+    Symbol = 'MSFT'";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    Ok(())
+}
+
+#[test]
+fn basic_select_statement_with_having_clause() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+
+    let input = r"select Symbol, count(*) from MarketData group by Symbol having count(*) > 1";
+    let mut formatter = Formatter::new(formatter_settings);
+    let expected = r"SELECT
+    Symbol
+    ,COUNT(*)
+FROM MarketData
+GROUP BY Symbol
+HAVING COUNT(*) > 1";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    Ok(())
+}
+
+#[test]
+fn basic_select_statement_with_null_and_boolean_literals() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+
+    let input = r"select null, Symbol from MarketData where IsActive = true and IsDeleted = false";
+    let mut formatter = Formatter::new(formatter_settings);
+    let expected = r"SELECT
+    NULL
+    ,Symbol
+FROM MarketData
+WHERE IsActive = TRUE
+    AND IsDeleted = FALSE";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    Ok(())
+}
+
+#[test]
+fn basic_select_statement_keeps_number_formatting() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+
+    let input = r"select 1.50, 100., .5 from MarketData where Price > .25";
+    let mut formatter = Formatter::new(formatter_settings);
+    let expected = r"SELECT
+    1.50
+    ,100.
+    ,.5
+FROM MarketData
+WHERE Price > .25";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    formatter.format(expected)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    Ok(())
+}
+
+#[test]
+fn basic_select_statement_with_distinct() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+    let mut formatter = Formatter::new(formatter_settings);
+
+    let input = r"select distinct a from t";
+    let expected = r"SELECT DISTINCT a
+FROM t";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    let input = r"select all a from t";
+    let expected = r"SELECT ALL a
+FROM t";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    // the set quantifier comes before TOP
+    let input = r"select distinct top 10 a, b from t";
+    formatter.format(input)?;
+    assert!(formatter
+        .formatted_query()
+        .starts_with("SELECT DISTINCT TOP 10\n"));
+
+    let input = r"select all top (5) percent a from t";
+    formatter.format(input)?;
+    assert!(formatter
+        .formatted_query()
+        .starts_with("SELECT ALL TOP (5) PERCENT a\n"));
+
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use formatter::formatter::Formatter;
+use formatter::settings::{BooleanOperatorPosition, FormatterSettings, KeywordCase};
+
+#[test]
+fn select_statements() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+    let mut formatter = Formatter::new(formatter_settings);
+
+    let input = r"SELECT 1; SELECT 2;";
+    let expected = r"SELECT 1;
+
+SELECT 2";
+    formatter.format(input)?;
+
+    let formatted_query = formatter.formatted_query();
+    assert_eq!(expected, formatted_query);
+
+    Ok(())
+}
+
+#[test]
+fn mixed_statements() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+    let mut formatter = Formatter::new(formatter_settings);
+
+    let input = r"select Symbol from MarketData; insert into MarketData (Symbol) values ('MSFT');
+    delete from MarketData where Volume = 0";
+    let expected = r"SELECT Symbol
+FROM MarketData;
+
+INSERT INTO MarketData (Symbol)
+VALUES ('MSFT');
+
+DELETE
+FROM MarketData
+WHERE Volume = 0";
+    formatter.format(input)?;
+
+    let formatted_query = formatter.formatted_query();
+    assert_eq!(expected, formatted_query);
+
+    Ok(())
+}
+
+#[test]
+fn reused_formatter() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+    let mut formatter = Formatter::new(formatter_settings);
+
+    formatter.format(r"select Symbol from MarketData")?;
+    assert_eq!(
+        "SELECT Symbol\nFROM MarketData",
+        formatter.formatted_query()
+    );
+
+    let input = r"delete from MarketArchive where Volume = 0";
+    let expected = r"DELETE
+FROM MarketArchive
+WHERE Volume = 0";
+    formatter.format(input)?;
+
+    let formatted_query = formatter.formatted_query();
+    assert!(!formatted_query.contains("MarketData"));
+    assert_eq!(expected, formatted_query);
+
+    assert!(formatter.format(r"select from").is_err());
+    assert_eq!("", formatter.formatted_query());
+
+    Ok(())
+}
+
+#[test]
+fn statements_separated_by_go() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: true,
+    };
+    let mut formatter = Formatter::new(formatter_settings);
+
+    let input = "select 1\ngo\nselect 2; select 3\n go 3";
+    let expected = r"SELECT 1;
+GO
+
+SELECT 2;
+
+SELECT 3;
+GO 3";
+    formatter.format(input)?;
+
+    let formatted_query = formatter.formatted_query();
+    assert_eq!(expected, formatted_query);
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use formatter::formatter::Formatter;
+use formatter::settings::{BooleanOperatorPosition, FormatterSettings, KeywordCase};
+
+#[test]
+fn truncate_table() -> Result<(), String> {
+    let formatter_settings = FormatterSettings {
+        indent_comma_lists: None,
+        indent_in_lists: true,
+        indent_between_conditions: true,
+        boolean_operator_position: BooleanOperatorPosition::Leading,
+        case_on_new_line: true,
+        keyword_case: KeywordCase::Upper,
+        identifier_case: None,
+        max_width: 80,
+        max_line_width: None,
+        indent_width: 4,
+        use_tab: false,
+        terminate_statements: false,
+    };
+    let mut formatter = Formatter::new(formatter_settings);
+
+    let input = r"truncate table dbo.MarketData";
+    let expected = r"TRUNCATE TABLE dbo.MarketData";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    let input = r"truncate   table MarketData with(partitions(1,2,4 to 6))";
+    let expected = r"TRUNCATE TABLE MarketData WITH (PARTITIONS (1, 2, 4 TO 6))";
+    formatter.format(input)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    formatter.format(expected)?;
+    assert_eq!(expected, formatter.formatted_query());
+
+    Ok(())
+}
@@ -1,5 +1,7 @@
 use clap::{Parser, Subcommand};
-use formatter::settings::{FormatterSettings, IndentCommaLists, KeywordCase};
+use formatter::settings::{
+    BooleanOperatorPosition, FormatterSettings, IdentifierCase, IndentCommaLists, KeywordCase,
+};
 
 #[derive(Parser, Debug, Clone)]
 pub struct Format {
@@ -10,14 +12,24 @@ pub struct Format {
     pub indent_in_lists: bool,
     #[arg(short = 'b', long, default_value_t = false)]
     pub indent_between_conditions: bool,
+    #[arg(long, default_value_t = BooleanOperatorPosition::Leading)]
+    pub boolean_operator_position: BooleanOperatorPosition,
+    #[arg(long, default_value_t = false)]
+    pub case_on_new_line: bool,
     #[arg(short, long, default_value_t = KeywordCase::Upper)]
     pub keyword_case: KeywordCase,
+    #[arg(long)]
+    pub identifier_case: Option<IdentifierCase>,
     #[arg(short, long, default_value_t = 80)]
     pub max_width: u32,
+    #[arg(long)]
+    pub max_line_width: Option<usize>,
     #[arg(short = 'w', long, default_value_t = 4)]
     pub indent_width: u32,
     #[arg(short, long, default_value_t = false)]
     pub use_tab: bool,
+    #[arg(long, default_value_t = false)]
+    pub terminate_statements: bool,
 }
 
 #[derive(Subcommand, Debug, Clone)]
@@ -44,10 +56,15 @@ impl From<Format> for FormatterSettings {
             indent_comma_lists: value.indent_comma_lists,
             indent_in_lists: value.indent_in_lists,
             indent_between_conditions: value.indent_between_conditions,
+            boolean_operator_position: value.boolean_operator_position,
+            case_on_new_line: value.case_on_new_line,
             keyword_case: value.keyword_case,
+            identifier_case: value.identifier_case,
             max_width: value.max_width,
+            max_line_width: value.max_line_width,
             indent_width: value.indent_width,
             use_tab: value.use_tab,
+            terminate_statements: value.terminate_statements,
         }
     }
 }
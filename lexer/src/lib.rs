@@ -1,6 +1,8 @@
 mod token;
 
-pub use token::{Span, Token, TokenKind};
+use std::collections::VecDeque;
+
+pub use token::{lookup_keyword, Span, Token, TokenKind};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct LexicalError {
@@ -36,6 +38,11 @@ pub struct Lexer<'a> {
     current_position: usize, // current position in input (points to current char)
     read_position: usize,    // current reading position in input (after current char)
     ch: Option<char>,        // current char under examination
+    // tokens lexed ahead by peek_n, handed out before lexing any further
+    peeked: VecDeque<LexerResult<'a>>,
+    // whether a bracket written right against a name opens a subscript like
+    // arr[i] instead of a quoted identifier
+    bracket_subscripts: bool,
 }
 
 impl<'a> Lexer<'a> {
@@ -46,11 +53,59 @@ impl<'a> Lexer<'a> {
             current_position: 0,
             read_position: 0,
             ch: None,
+            peeked: VecDeque::new(),
+            bracket_subscripts: false,
         };
         lexer.read_char();
         lexer
     }
 
+    /// Lexes a `[` written right after a name or a closing paren or bracket
+    /// as the start of a subscript, so `arr[i]` is `arr` indexed by `i`
+    /// rather than `arr` followed by the quoted identifier `[i]`.
+    pub fn with_bracket_subscripts(mut self, bracket_subscripts: bool) -> Self {
+        self.bracket_subscripts = bracket_subscripts;
+        self
+    }
+
+    /// Lexes the whole input up to and including the eof token, stopping at
+    /// the first lexical error.
+    pub fn tokenize(self) -> Result<Vec<Token<'a>>, LexicalError> {
+        let mut tokens = vec![];
+        for result in self {
+            let token = result?;
+            tokens.push(token);
+            if token.shallow_eq_token_kind(&TokenKind::Eof) {
+                break;
+            }
+        }
+        Ok(tokens)
+    }
+
+    /// Lexes the whole input up to and including the eof token, keeping every
+    /// lexical error in place of the token it failed on.
+    pub fn tokenize_lossy(self) -> Vec<LexerResult<'a>> {
+        let mut results = vec![];
+        for result in self {
+            results.push(result);
+            if result.is_ok_and(|t| t.shallow_eq_token_kind(&TokenKind::Eof)) {
+                break;
+            }
+        }
+        results
+    }
+
+    /// Looks `n` tokens ahead without consuming anything, `peek_n(0)` is the
+    /// token the next call to `next` returns. Gives `None` when the token at
+    /// that position fails to lex.
+    pub fn peek_n(&mut self, n: usize) -> Option<&Token<'a>> {
+        while self.peeked.len() <= n {
+            let result = self.next_lex();
+            self.peeked.push_back(result);
+        }
+        self.peeked[n].as_ref().ok()
+    }
+
     fn has_more_tokens(&self) -> bool {
         self.read_position < self.input.len()
     }
@@ -62,8 +117,9 @@ impl<'a> Lexer<'a> {
             self.ch = None;
         }
 
+        // positions are byte offsets so step over the whole utf-8 encoding
         self.current_position = self.read_position;
-        self.read_position += 1;
+        self.read_position += self.ch.map_or(1, char::len_utf8);
     }
 
     fn skip_whitespace(&mut self) {
@@ -81,7 +137,7 @@ impl<'a> Lexer<'a> {
         {
             self.read_char();
         }
-        &self.input[start..self.current_position + 1]
+        &self.input[start..self.read_position]
     }
 
     fn read_quoted_identifier(&mut self) -> Result<&'a str, LexicalError> {
@@ -143,40 +199,73 @@ impl<'a> Lexer<'a> {
         let mut start = self.current_position;
         let mut end = self.current_position;
         let mut start_found = false;
-        while self.chars.peek().is_some_and(|ch| ch != &'\n') {
+        // stop on the new line so it becomes the end of the comment token
+        while self.ch.is_some_and(|ch| ch != '\n') {
             if self.ch.is_some_and(|ch| !ch.is_whitespace()) {
                 if !start_found {
                     start = self.current_position;
                     start_found = true
                 }
-                end = self.current_position;
+                end = self.read_position;
             }
             self.read_char();
         }
-        // read the closing quote
-        self.read_char();
-        &self.input[start..end + 1]
+        &self.input[start..end]
     }
 
     fn read_number_literal(&mut self) -> &'a str {
         let start = self.current_position;
+        // a number like .5 starts on its period and has no whole part
+        let leading_period = self.ch == Some('.');
         // read all the digits
-        while self.chars.peek().is_some_and(|ch| ch.is_numeric()) {
+        while !leading_period && self.chars.peek().is_some_and(|ch| ch.is_numeric()) {
             self.read_char();
         }
 
         // check if the number has a period
         // aka number is a float
-        if self.chars.peek() == Some(&'.') {
+        if leading_period || self.chars.peek() == Some(&'.') {
             // go to the period
-            self.read_char();
+            if !leading_period {
+                self.read_char();
+            }
 
             while self.chars.peek().is_some_and(|ch| ch.is_numeric()) {
                 self.read_char();
             }
         }
 
-        &self.input[start..self.current_position + 1]
+        &self.input[start..self.read_position]
+    }
+
+    // checks if the word starting at `start` and ending on the current char is
+    // alone on its line, where GO separates batches. a repeat count and a
+    // trailing comment may follow it, anywhere else go is just an identifier
+    fn on_own_line(&self, start: usize) -> bool {
+        let before = self.input[..start].rsplit('\n').next().unwrap_or_default();
+        let after = self.input[self.read_position..]
+            .split('\n')
+            .next()
+            .unwrap_or_default()
+            .trim_start()
+            .trim_start_matches(|ch: char| ch.is_ascii_digit())
+            .trim();
+
+        before.trim().is_empty() && (after.is_empty() || after.starts_with("--"))
+    }
+
+    // checks if the current char comes right after an identifier, where a
+    // period separates the parts of a compound name instead of starting a number
+    fn follows_name(&self) -> bool {
+        self.input[..self.current_position]
+            .chars()
+            .next_back()
+            .is_some_and(|ch| ch.is_alphanumeric() || ch == '_' || ch == ']')
+    }
+
+    // checks if the current char is right after something that can be indexed
+    fn follows_subscriptable(&self) -> bool {
+        self.follows_name() || self.input[..self.current_position].ends_with(')')
     }
 
     fn next_lex(&mut self) -> LexerResult<'a> {
@@ -207,19 +296,47 @@ impl<'a> Lexer<'a> {
                 }
                 '<' => TokenKind::LessThan,
                 '>' => TokenKind::GreaterThan,
+                '+' if self.chars.peek().is_some_and(|c| c == &'=') => {
+                    self.read_char();
+                    TokenKind::PlusEqual
+                }
                 '+' => TokenKind::Plus,
                 '-' if self.chars.peek().is_some_and(|c| c == &'-') => {
                     self.read_char();
                     let comment = self.read_comment();
                     TokenKind::Comment(comment)
                 }
+                '-' if self.chars.peek().is_some_and(|c| c == &'=') => {
+                    self.read_char();
+                    TokenKind::MinusEqual
+                }
                 '-' => TokenKind::Minus,
+                '/' if self.chars.peek().is_some_and(|c| c == &'=') => {
+                    self.read_char();
+                    TokenKind::DivideEqual
+                }
                 '/' => TokenKind::ForwardSlash,
+                '*' if self.chars.peek().is_some_and(|c| c == &'=') => {
+                    self.read_char();
+                    TokenKind::MultiplyEqual
+                }
                 '*' => TokenKind::Asterisk,
-                '%' => TokenKind::Percent,
+                '%' if self.chars.peek().is_some_and(|c| c == &'=') => {
+                    self.read_char();
+                    TokenKind::PercentEqual
+                }
+                '%' => TokenKind::PercentSign,
+                '.' if !self.follows_name()
+                    && self.chars.peek().is_some_and(|c| c.is_numeric()) =>
+                {
+                    let number_literal = self.read_number_literal();
+                    TokenKind::NumberLiteral(number_literal)
+                }
                 '.' => TokenKind::Period,
                 ';' => TokenKind::SemiColon,
-                '[' if self.chars.peek().is_some_and(|c| c.is_alphabetic()) => {
+                '[' if !(self.bracket_subscripts && self.follows_subscriptable())
+                    && self.chars.peek().is_some_and(|c| c.is_alphabetic()) =>
+                {
                     match self.read_quoted_identifier() {
                         Ok(ident) => TokenKind::QuotedIdentifier(ident),
                         Err(error) => {
@@ -228,6 +345,10 @@ impl<'a> Lexer<'a> {
                         }
                     }
                 }
+                '[' => TokenKind::LeftBracket,
+                ']' => TokenKind::RightBracket,
+                '{' => TokenKind::LeftBrace,
+                '}' => TokenKind::RightBrace,
                 '\'' => match self.read_string_literal() {
                     Ok(string_literal) => TokenKind::StringLiteral(string_literal),
                     Err(error) => {
@@ -245,6 +366,10 @@ impl<'a> Lexer<'a> {
                     let identifier = self.read_identifier();
                     if let Some(keyword) = token::lookup_keyword(identifier) {
                         keyword
+                    } else if identifier.eq_ignore_ascii_case("go")
+                        && self.on_own_line(start as usize)
+                    {
+                        TokenKind::Go
                     } else {
                         TokenKind::Identifier(identifier)
                     }
@@ -261,10 +386,7 @@ impl<'a> Lexer<'a> {
                     self.read_char();
                     return Err(LexicalError {
                         error: LexicalErrorType::UnrecognizedToken { ch },
-                        span: Span {
-                            start: start as u32,
-                            end: self.current_position as u32,
-                        },
+                        span: Span::new(start, start),
                     });
                 }
             },
@@ -281,6 +403,6 @@ impl<'a> Iterator for Lexer<'a> {
     type Item = LexerResult<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        Some(self.next_lex())
+        Some(self.peeked.pop_front().unwrap_or_else(|| self.next_lex()))
     }
 }
@@ -1,6 +1,7 @@
 use core::fmt;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Span {
     pub start: u32,
     pub end: u32,
@@ -65,12 +66,16 @@ pub enum TokenKind<'a> {
     PercentSign,
     Period,
     SemiColon,
+    LeftBracket,
+    RightBracket,
+    LeftBrace,
+    RightBrace,
     Eof,
-    // PlusEqual,
-    // MinusEqual,
-    // DivideEqual,
-    // MultiplyEqual,
-    // PercentEqual,
+    PlusEqual,
+    MinusEqual,
+    DivideEqual,
+    MultiplyEqual,
+    PercentEqual,
     // AndEqual,
     // OrEqual,
     // CaretEqual,
@@ -81,6 +86,7 @@ pub enum TokenKind<'a> {
     Alter,
     And,
     Any,
+    Apply,
     As,
     Asc,
     Asin,
@@ -101,12 +107,13 @@ pub enum TokenKind<'a> {
     Column,
     Columns,
     Commit,
-    Commited,
+    Committed,
     Constraint,
     Cos,
     Cot,
     Count,
     Create,
+    Cross,
     Current,
     Date,
     Datetime,
@@ -121,12 +128,14 @@ pub enum TokenKind<'a> {
     DenseRank,
     Desc,
     Describe,
+    Disable,
     Distinct,
     Do,
     Drop,
     Else,
     End,
     Engine,
+    Except,
     Exec,
     Execute,
     Exists,
@@ -143,11 +152,13 @@ pub enum TokenKind<'a> {
     Full,
     Function,
     Getdate,
+    Go,
     Group,
     Having,
     Hour,
     Hours,
     Identity,
+    Ilike,
     If,
     In,
     Increment,
@@ -159,6 +170,7 @@ pub enum TokenKind<'a> {
     Int,
     Into,
     Is,
+    Isolation,
     Join,
     Key,
     Lag,
@@ -166,11 +178,14 @@ pub enum TokenKind<'a> {
     LastValue,
     Lead,
     Left,
+    Level,
     Like,
     Limit,
     Log,
     Log10,
+    Matched,
     Max,
+    Merge,
     Microsecond,
     Microseconds,
     Millisecond,
@@ -180,11 +195,15 @@ pub enum TokenKind<'a> {
     Month,
     Nanosecond,
     Nanoseconds,
+    Natural,
     Nchar,
+    Newid,
+    Newsequentialid,
     Next,
     Not,
     Null,
     Nullif,
+    Nulls,
     Numeric,
     Nvarchar,
     Offset,
@@ -193,19 +212,26 @@ pub enum TokenKind<'a> {
     Or,
     Order,
     Outer,
+    Output,
     Over,
     Partition,
+    Partitions,
     Password,
     Percent,
     Pi,
     Power,
     Preceding,
+    Primary,
     Procedure,
     Radians,
-    Rands,
+    Rand,
     Range,
     Rank,
+    Read,
     Real,
+    Rebuild,
+    Reorganize,
+    Repeatable,
     Return,
     Returns,
     Revoke,
@@ -219,6 +245,7 @@ pub enum TokenKind<'a> {
     RowNumber,
     Second,
     Select,
+    Serializable,
     Set,
     Sign,
     Sin,
@@ -240,6 +267,7 @@ pub enum TokenKind<'a> {
     Ties,
     Time,
     Tinyint,
+    To,
     Top,
     Transaction,
     Trigger,
@@ -254,6 +282,7 @@ pub enum TokenKind<'a> {
     Upper,
     Use,
     User,
+    Using,
     Uuid,
     Value,
     Values,
@@ -301,6 +330,7 @@ impl<'a> TokenKind<'a> {
             | TokenKind::Cos
             | TokenKind::Cot
             | TokenKind::Count
+            | TokenKind::Day
             | TokenKind::Degrees
             | TokenKind::DenseRank
             | TokenKind::Exp
@@ -310,13 +340,19 @@ impl<'a> TokenKind<'a> {
             | TokenKind::Log10
             | TokenKind::Max
             | TokenKind::Min
+            | TokenKind::Month
+            | TokenKind::Newid
+            | TokenKind::Newsequentialid
             | TokenKind::Nullif
             | TokenKind::Pi
             | TokenKind::Power
             | TokenKind::Radians
+            | TokenKind::Rand
             | TokenKind::Rank
             | TokenKind::Round
             | TokenKind::RowNumber
+            | TokenKind::Sign
+            | TokenKind::Sin
             | TokenKind::Sqrt
             | TokenKind::Square
             | TokenKind::Stage
@@ -324,8 +360,10 @@ impl<'a> TokenKind<'a> {
             | TokenKind::Stdevp
             | TokenKind::Sum
             | TokenKind::Tan
+            | TokenKind::Upper
             | TokenKind::Var
-            | TokenKind::Varp => true,
+            | TokenKind::Varp
+            | TokenKind::Year => true,
             _ => false,
         }
     }
@@ -354,13 +392,23 @@ impl<'a> TokenKind<'a> {
             (&TokenKind::PercentSign, &TokenKind::PercentSign) => true,
             (&TokenKind::Period, &TokenKind::Period) => true,
             (&TokenKind::SemiColon, &TokenKind::SemiColon) => true,
+            (&TokenKind::LeftBracket, &TokenKind::LeftBracket) => true,
+            (&TokenKind::RightBracket, &TokenKind::RightBracket) => true,
+            (&TokenKind::LeftBrace, &TokenKind::LeftBrace) => true,
+            (&TokenKind::RightBrace, &TokenKind::RightBrace) => true,
             (&TokenKind::Eof, &TokenKind::Eof) => true,
+            (&TokenKind::PlusEqual, &TokenKind::PlusEqual) => true,
+            (&TokenKind::MinusEqual, &TokenKind::MinusEqual) => true,
+            (&TokenKind::DivideEqual, &TokenKind::DivideEqual) => true,
+            (&TokenKind::MultiplyEqual, &TokenKind::MultiplyEqual) => true,
+            (&TokenKind::PercentEqual, &TokenKind::PercentEqual) => true,
             (&TokenKind::Abs, &TokenKind::Abs) => true,
             (&TokenKind::Acos, &TokenKind::Acos) => true,
             (&TokenKind::All, &TokenKind::All) => true,
             (&TokenKind::Alter, &TokenKind::Alter) => true,
             (&TokenKind::And, &TokenKind::And) => true,
             (&TokenKind::Any, &TokenKind::Any) => true,
+            (&TokenKind::Apply, &TokenKind::Apply) => true,
             (&TokenKind::As, &TokenKind::As) => true,
             (&TokenKind::Asc, &TokenKind::Asc) => true,
             (&TokenKind::Asin, &TokenKind::Asin) => true,
@@ -381,12 +429,13 @@ impl<'a> TokenKind<'a> {
             (&TokenKind::Column, &TokenKind::Column) => true,
             (&TokenKind::Columns, &TokenKind::Columns) => true,
             (&TokenKind::Commit, &TokenKind::Commit) => true,
-            (&TokenKind::Commited, &TokenKind::Commited) => true,
+            (&TokenKind::Committed, &TokenKind::Committed) => true,
             (&TokenKind::Constraint, &TokenKind::Constraint) => true,
             (&TokenKind::Cos, &TokenKind::Cos) => true,
             (&TokenKind::Cot, &TokenKind::Cot) => true,
             (&TokenKind::Count, &TokenKind::Count) => true,
             (&TokenKind::Create, &TokenKind::Create) => true,
+            (&TokenKind::Cross, &TokenKind::Cross) => true,
             (&TokenKind::Current, &TokenKind::Current) => true,
             (&TokenKind::Date, &TokenKind::Date) => true,
             (&TokenKind::Datetime, &TokenKind::Datetime) => true,
@@ -401,12 +450,14 @@ impl<'a> TokenKind<'a> {
             (&TokenKind::DenseRank, &TokenKind::DenseRank) => true,
             (&TokenKind::Desc, &TokenKind::Desc) => true,
             (&TokenKind::Describe, &TokenKind::Describe) => true,
+            (&TokenKind::Disable, &TokenKind::Disable) => true,
             (&TokenKind::Distinct, &TokenKind::Distinct) => true,
             (&TokenKind::Do, &TokenKind::Do) => true,
             (&TokenKind::Drop, &TokenKind::Drop) => true,
             (&TokenKind::Else, &TokenKind::Else) => true,
             (&TokenKind::End, &TokenKind::End) => true,
             (&TokenKind::Engine, &TokenKind::Engine) => true,
+            (&TokenKind::Except, &TokenKind::Except) => true,
             (&TokenKind::Exec, &TokenKind::Exec) => true,
             (&TokenKind::Execute, &TokenKind::Execute) => true,
             (&TokenKind::Exists, &TokenKind::Exists) => true,
@@ -423,11 +474,13 @@ impl<'a> TokenKind<'a> {
             (&TokenKind::Full, &TokenKind::Full) => true,
             (&TokenKind::Function, &TokenKind::Function) => true,
             (&TokenKind::Getdate, &TokenKind::Getdate) => true,
+            (&TokenKind::Go, &TokenKind::Go) => true,
             (&TokenKind::Group, &TokenKind::Group) => true,
             (&TokenKind::Having, &TokenKind::Having) => true,
             (&TokenKind::Hour, &TokenKind::Hour) => true,
             (&TokenKind::Hours, &TokenKind::Hours) => true,
             (&TokenKind::Identity, &TokenKind::Identity) => true,
+            (&TokenKind::Ilike, &TokenKind::Ilike) => true,
             (&TokenKind::If, &TokenKind::If) => true,
             (&TokenKind::In, &TokenKind::In) => true,
             (&TokenKind::Increment, &TokenKind::Increment) => true,
@@ -439,6 +492,7 @@ impl<'a> TokenKind<'a> {
             (&TokenKind::Int, &TokenKind::Int) => true,
             (&TokenKind::Into, &TokenKind::Into) => true,
             (&TokenKind::Is, &TokenKind::Is) => true,
+            (&TokenKind::Isolation, &TokenKind::Isolation) => true,
             (&TokenKind::Join, &TokenKind::Join) => true,
             (&TokenKind::Key, &TokenKind::Key) => true,
             (&TokenKind::Lag, &TokenKind::Lag) => true,
@@ -446,11 +500,14 @@ impl<'a> TokenKind<'a> {
             (&TokenKind::LastValue, &TokenKind::LastValue) => true,
             (&TokenKind::Lead, &TokenKind::Lead) => true,
             (&TokenKind::Left, &TokenKind::Left) => true,
+            (&TokenKind::Level, &TokenKind::Level) => true,
             (&TokenKind::Like, &TokenKind::Like) => true,
             (&TokenKind::Limit, &TokenKind::Limit) => true,
             (&TokenKind::Log, &TokenKind::Log) => true,
             (&TokenKind::Log10, &TokenKind::Log10) => true,
+            (&TokenKind::Matched, &TokenKind::Matched) => true,
             (&TokenKind::Max, &TokenKind::Max) => true,
+            (&TokenKind::Merge, &TokenKind::Merge) => true,
             (&TokenKind::Microsecond, &TokenKind::Microsecond) => true,
             (&TokenKind::Microseconds, &TokenKind::Microseconds) => true,
             (&TokenKind::Millisecond, &TokenKind::Millisecond) => true,
@@ -460,11 +517,15 @@ impl<'a> TokenKind<'a> {
             (&TokenKind::Month, &TokenKind::Month) => true,
             (&TokenKind::Nanosecond, &TokenKind::Nanosecond) => true,
             (&TokenKind::Nanoseconds, &TokenKind::Nanoseconds) => true,
+            (&TokenKind::Natural, &TokenKind::Natural) => true,
             (&TokenKind::Nchar, &TokenKind::Nchar) => true,
+            (&TokenKind::Newid, &TokenKind::Newid) => true,
+            (&TokenKind::Newsequentialid, &TokenKind::Newsequentialid) => true,
             (&TokenKind::Next, &TokenKind::Next) => true,
             (&TokenKind::Not, &TokenKind::Not) => true,
             (&TokenKind::Null, &TokenKind::Null) => true,
             (&TokenKind::Nullif, &TokenKind::Nullif) => true,
+            (&TokenKind::Nulls, &TokenKind::Nulls) => true,
             (&TokenKind::Numeric, &TokenKind::Numeric) => true,
             (&TokenKind::Nvarchar, &TokenKind::Nvarchar) => true,
             (&TokenKind::Offset, &TokenKind::Offset) => true,
@@ -473,19 +534,26 @@ impl<'a> TokenKind<'a> {
             (&TokenKind::Or, &TokenKind::Or) => true,
             (&TokenKind::Order, &TokenKind::Order) => true,
             (&TokenKind::Outer, &TokenKind::Outer) => true,
+            (&TokenKind::Output, &TokenKind::Output) => true,
             (&TokenKind::Over, &TokenKind::Over) => true,
             (&TokenKind::Partition, &TokenKind::Partition) => true,
+            (&TokenKind::Partitions, &TokenKind::Partitions) => true,
             (&TokenKind::Password, &TokenKind::Password) => true,
             (&TokenKind::Percent, &TokenKind::Percent) => true,
             (&TokenKind::Pi, &TokenKind::Pi) => true,
             (&TokenKind::Power, &TokenKind::Power) => true,
             (&TokenKind::Preceding, &TokenKind::Preceding) => true,
+            (&TokenKind::Primary, &TokenKind::Primary) => true,
             (&TokenKind::Procedure, &TokenKind::Procedure) => true,
             (&TokenKind::Radians, &TokenKind::Radians) => true,
-            (&TokenKind::Rands, &TokenKind::Rands) => true,
+            (&TokenKind::Rand, &TokenKind::Rand) => true,
             (&TokenKind::Range, &TokenKind::Range) => true,
             (&TokenKind::Rank, &TokenKind::Rank) => true,
+            (&TokenKind::Read, &TokenKind::Read) => true,
             (&TokenKind::Real, &TokenKind::Real) => true,
+            (&TokenKind::Rebuild, &TokenKind::Rebuild) => true,
+            (&TokenKind::Reorganize, &TokenKind::Reorganize) => true,
+            (&TokenKind::Repeatable, &TokenKind::Repeatable) => true,
             (&TokenKind::Return, &TokenKind::Return) => true,
             (&TokenKind::Returns, &TokenKind::Returns) => true,
             (&TokenKind::Revoke, &TokenKind::Revoke) => true,
@@ -499,6 +567,7 @@ impl<'a> TokenKind<'a> {
             (&TokenKind::RowNumber, &TokenKind::RowNumber) => true,
             (&TokenKind::Second, &TokenKind::Second) => true,
             (&TokenKind::Select, &TokenKind::Select) => true,
+            (&TokenKind::Serializable, &TokenKind::Serializable) => true,
             (&TokenKind::Set, &TokenKind::Set) => true,
             (&TokenKind::Sign, &TokenKind::Sign) => true,
             (&TokenKind::Sin, &TokenKind::Sin) => true,
@@ -520,6 +589,7 @@ impl<'a> TokenKind<'a> {
             (&TokenKind::Ties, &TokenKind::Ties) => true,
             (&TokenKind::Time, &TokenKind::Time) => true,
             (&TokenKind::Tinyint, &TokenKind::Tinyint) => true,
+            (&TokenKind::To, &TokenKind::To) => true,
             (&TokenKind::Top, &TokenKind::Top) => true,
             (&TokenKind::Transaction, &TokenKind::Transaction) => true,
             (&TokenKind::Trigger, &TokenKind::Trigger) => true,
@@ -534,6 +604,7 @@ impl<'a> TokenKind<'a> {
             (&TokenKind::Upper, &TokenKind::Upper) => true,
             (&TokenKind::Use, &TokenKind::Use) => true,
             (&TokenKind::User, &TokenKind::User) => true,
+            (&TokenKind::Using, &TokenKind::Using) => true,
             (&TokenKind::Uuid, &TokenKind::Uuid) => true,
             (&TokenKind::Value, &TokenKind::Value) => true,
             (&TokenKind::Values, &TokenKind::Values) => true,
@@ -561,6 +632,7 @@ pub fn lookup_keyword(str: &str) -> Option<TokenKind> {
         "alter" => Some(TokenKind::Alter),
         "and" => Some(TokenKind::And),
         "any" => Some(TokenKind::Any),
+        "apply" => Some(TokenKind::Apply),
         "as" => Some(TokenKind::As),
         "asc" => Some(TokenKind::Asc),
         "asin" => Some(TokenKind::Asin),
@@ -581,12 +653,13 @@ pub fn lookup_keyword(str: &str) -> Option<TokenKind> {
         "column" => Some(TokenKind::Column),
         "columns" => Some(TokenKind::Columns),
         "commit" => Some(TokenKind::Commit),
-        "commited" => Some(TokenKind::Commited),
+        "committed" => Some(TokenKind::Committed),
         "constraint" => Some(TokenKind::Constraint),
         "cos" => Some(TokenKind::Cos),
         "cot" => Some(TokenKind::Cot),
         "count" => Some(TokenKind::Count),
         "create" => Some(TokenKind::Create),
+        "cross" => Some(TokenKind::Cross),
         "current" => Some(TokenKind::Current),
         "date" => Some(TokenKind::Date),
         "datetime" => Some(TokenKind::Datetime),
@@ -601,12 +674,14 @@ pub fn lookup_keyword(str: &str) -> Option<TokenKind> {
         "dense_rank" => Some(TokenKind::DenseRank),
         "desc" => Some(TokenKind::Desc),
         "describe" => Some(TokenKind::Describe),
+        "disable" => Some(TokenKind::Disable),
         "distinct" => Some(TokenKind::Distinct),
         "do" => Some(TokenKind::Do),
         "drop" => Some(TokenKind::Drop),
         "else" => Some(TokenKind::Else),
         "end" => Some(TokenKind::End),
         "engine" => Some(TokenKind::Engine),
+        "except" => Some(TokenKind::Except),
         "exec" => Some(TokenKind::Exec),
         "execute" => Some(TokenKind::Execute),
         "exists" => Some(TokenKind::Exists),
@@ -628,6 +703,7 @@ pub fn lookup_keyword(str: &str) -> Option<TokenKind> {
         "hour" => Some(TokenKind::Hour),
         "hours" => Some(TokenKind::Hours),
         "identity" => Some(TokenKind::Identity),
+        "ilike" => Some(TokenKind::Ilike),
         "if" => Some(TokenKind::If),
         "in" => Some(TokenKind::In),
         "increment" => Some(TokenKind::Increment),
@@ -639,6 +715,7 @@ pub fn lookup_keyword(str: &str) -> Option<TokenKind> {
         "int" => Some(TokenKind::Int),
         "into" => Some(TokenKind::Into),
         "is" => Some(TokenKind::Is),
+        "isolation" => Some(TokenKind::Isolation),
         "join" => Some(TokenKind::Join),
         "key" => Some(TokenKind::Key),
         "lag" => Some(TokenKind::Lag),
@@ -646,11 +723,14 @@ pub fn lookup_keyword(str: &str) -> Option<TokenKind> {
         "last_value" => Some(TokenKind::LastValue),
         "lead" => Some(TokenKind::Lead),
         "left" => Some(TokenKind::Left),
+        "level" => Some(TokenKind::Level),
         "like" => Some(TokenKind::Like),
         "limit" => Some(TokenKind::Limit),
         "log" => Some(TokenKind::Log),
         "log10" => Some(TokenKind::Log10),
+        "matched" => Some(TokenKind::Matched),
         "max" => Some(TokenKind::Max),
+        "merge" => Some(TokenKind::Merge),
         "microsecond" => Some(TokenKind::Microsecond),
         "microseconds" => Some(TokenKind::Microseconds),
         "millisecond" => Some(TokenKind::Millisecond),
@@ -660,11 +740,15 @@ pub fn lookup_keyword(str: &str) -> Option<TokenKind> {
         "month" => Some(TokenKind::Month),
         "nanosecond" => Some(TokenKind::Nanosecond),
         "nanoseconds" => Some(TokenKind::Nanoseconds),
+        "natural" => Some(TokenKind::Natural),
         "nchar" => Some(TokenKind::Nchar),
+        "newid" => Some(TokenKind::Newid),
+        "newsequentialid" => Some(TokenKind::Newsequentialid),
         "next" => Some(TokenKind::Next),
         "not" => Some(TokenKind::Not),
         "null" => Some(TokenKind::Null),
         "nullif" => Some(TokenKind::Nullif),
+        "nulls" => Some(TokenKind::Nulls),
         "numeric" => Some(TokenKind::Numeric),
         "nvarchar" => Some(TokenKind::Nvarchar),
         "offset" => Some(TokenKind::Offset),
@@ -673,19 +757,26 @@ pub fn lookup_keyword(str: &str) -> Option<TokenKind> {
         "or" => Some(TokenKind::Or),
         "order" => Some(TokenKind::Order),
         "outer" => Some(TokenKind::Outer),
+        "output" => Some(TokenKind::Output),
         "over" => Some(TokenKind::Over),
         "partition" => Some(TokenKind::Partition),
+        "partitions" => Some(TokenKind::Partitions),
         "password" => Some(TokenKind::Password),
         "percent" => Some(TokenKind::Percent),
         "pi" => Some(TokenKind::Pi),
         "power" => Some(TokenKind::Power),
         "preceding" => Some(TokenKind::Preceding),
+        "primary" => Some(TokenKind::Primary),
         "procedure" => Some(TokenKind::Procedure),
         "radians" => Some(TokenKind::Radians),
-        "rands" => Some(TokenKind::Rands),
+        "rand" => Some(TokenKind::Rand),
         "range" => Some(TokenKind::Range),
         "rank" => Some(TokenKind::Rank),
+        "read" => Some(TokenKind::Read),
         "real" => Some(TokenKind::Real),
+        "rebuild" => Some(TokenKind::Rebuild),
+        "reorganize" => Some(TokenKind::Reorganize),
+        "repeatable" => Some(TokenKind::Repeatable),
         "return" => Some(TokenKind::Return),
         "returns" => Some(TokenKind::Returns),
         "revoke" => Some(TokenKind::Revoke),
@@ -699,6 +790,7 @@ pub fn lookup_keyword(str: &str) -> Option<TokenKind> {
         "row_number" => Some(TokenKind::RowNumber),
         "second" => Some(TokenKind::Second),
         "select" => Some(TokenKind::Select),
+        "serializable" => Some(TokenKind::Serializable),
         "set" => Some(TokenKind::Set),
         "sign" => Some(TokenKind::Sign),
         "sin" => Some(TokenKind::Sin),
@@ -720,6 +812,7 @@ pub fn lookup_keyword(str: &str) -> Option<TokenKind> {
         "ties" => Some(TokenKind::Ties),
         "time" => Some(TokenKind::Time),
         "tinyint" => Some(TokenKind::Tinyint),
+        "to" => Some(TokenKind::To),
         "top" => Some(TokenKind::Top),
         "transaction" => Some(TokenKind::Transaction),
         "trigger" => Some(TokenKind::Trigger),
@@ -734,6 +827,7 @@ pub fn lookup_keyword(str: &str) -> Option<TokenKind> {
         "upper" => Some(TokenKind::Upper),
         "use" => Some(TokenKind::Use),
         "user" => Some(TokenKind::User),
+        "using" => Some(TokenKind::Using),
         "uuid" => Some(TokenKind::Uuid),
         "value" => Some(TokenKind::Value),
         "values" => Some(TokenKind::Values),
@@ -777,16 +871,16 @@ impl<'a> fmt::Display for TokenKind<'a> {
             TokenKind::PercentSign => f.write_str("%"),
             TokenKind::Period => f.write_str("."),
             TokenKind::SemiColon => f.write_str(";"),
-            // Token::LeftBracket => f.write_str("["),
-            // Token::RightBracket => f.write_str("]"),
-            // Token::LeftBrace => f.write_str("{"),
-            // Token::RightBrace => f.write_str("}"),
+            TokenKind::LeftBracket => f.write_str("["),
+            TokenKind::RightBracket => f.write_str("]"),
+            TokenKind::LeftBrace => f.write_str("{"),
+            TokenKind::RightBrace => f.write_str("}"),
             TokenKind::Eof => f.write_str("eof"),
-            // Token::PlusEqual => f.write_str("+="),
-            // Token::MinusEqual => f.write_str("-="),
-            // Token::DivideEqual => f.write_str("/="),
-            // Token::MultiplyEqual => f.write_str("*="),
-            // Token::PercentEqual => f.write_str("%="),
+            TokenKind::PlusEqual => f.write_str("+="),
+            TokenKind::MinusEqual => f.write_str("-="),
+            TokenKind::DivideEqual => f.write_str("/="),
+            TokenKind::MultiplyEqual => f.write_str("*="),
+            TokenKind::PercentEqual => f.write_str("%="),
             // Token::AndEqual => f.write_str("&="),
             // Token::OrEqual => f.write_str("|="),
             // Token::CaretEqual => f.write_str("^="),
@@ -796,6 +890,7 @@ impl<'a> fmt::Display for TokenKind<'a> {
             TokenKind::Alter => f.write_str("alter"),
             TokenKind::And => f.write_str("and"),
             TokenKind::Any => f.write_str("any"),
+            TokenKind::Apply => f.write_str("apply"),
             TokenKind::As => f.write_str("as"),
             TokenKind::Asc => f.write_str("asc"),
             TokenKind::Asin => f.write_str("asin"),
@@ -816,12 +911,13 @@ impl<'a> fmt::Display for TokenKind<'a> {
             TokenKind::Column => f.write_str("column"),
             TokenKind::Columns => f.write_str("columns"),
             TokenKind::Commit => f.write_str("commit"),
-            TokenKind::Commited => f.write_str("commited"),
+            TokenKind::Committed => f.write_str("committed"),
             TokenKind::Constraint => f.write_str("constraint"),
             TokenKind::Cos => f.write_str("cos"),
             TokenKind::Cot => f.write_str("cot"),
             TokenKind::Count => f.write_str("count"),
             TokenKind::Create => f.write_str("create"),
+            TokenKind::Cross => f.write_str("cross"),
             TokenKind::Current => f.write_str("current"),
             TokenKind::Date => f.write_str("date"),
             TokenKind::Datetime => f.write_str("datetime"),
@@ -833,15 +929,17 @@ impl<'a> fmt::Display for TokenKind<'a> {
             TokenKind::Degrees => f.write_str("degrees"),
             TokenKind::Default => f.write_str("default"),
             TokenKind::Delete => f.write_str("delete"),
-            TokenKind::DenseRank => f.write_str("denserank"),
+            TokenKind::DenseRank => f.write_str("dense_rank"),
             TokenKind::Desc => f.write_str("desc"),
             TokenKind::Describe => f.write_str("describe"),
+            TokenKind::Disable => f.write_str("disable"),
             TokenKind::Distinct => f.write_str("distinct"),
             TokenKind::Do => f.write_str("do"),
             TokenKind::Drop => f.write_str("drop"),
             TokenKind::Else => f.write_str("else"),
             TokenKind::End => f.write_str("end"),
             TokenKind::Engine => f.write_str("engine"),
+            TokenKind::Except => f.write_str("except"),
             TokenKind::Exec => f.write_str("exec"),
             TokenKind::Execute => f.write_str("execute"),
             TokenKind::Exists => f.write_str("exists"),
@@ -849,7 +947,7 @@ impl<'a> fmt::Display for TokenKind<'a> {
             TokenKind::False => f.write_str("false"),
             TokenKind::Fetch => f.write_str("fetch"),
             TokenKind::First => f.write_str("first"),
-            TokenKind::FirstValue => f.write_str("firstvalue"),
+            TokenKind::FirstValue => f.write_str("first_value"),
             TokenKind::Float => f.write_str("float"),
             TokenKind::Floor => f.write_str("floor"),
             TokenKind::Following => f.write_str("following"),
@@ -858,11 +956,13 @@ impl<'a> fmt::Display for TokenKind<'a> {
             TokenKind::Full => f.write_str("full"),
             TokenKind::Function => f.write_str("function"),
             TokenKind::Getdate => f.write_str("getdate"),
+            TokenKind::Go => f.write_str("go"),
             TokenKind::Group => f.write_str("group"),
             TokenKind::Having => f.write_str("having"),
             TokenKind::Hour => f.write_str("hour"),
             TokenKind::Hours => f.write_str("hours"),
             TokenKind::Identity => f.write_str("identity"),
+            TokenKind::Ilike => f.write_str("ilike"),
             TokenKind::If => f.write_str("if"),
             TokenKind::In => f.write_str("in"),
             TokenKind::Increment => f.write_str("increment"),
@@ -874,18 +974,22 @@ impl<'a> fmt::Display for TokenKind<'a> {
             TokenKind::Int => f.write_str("int"),
             TokenKind::Into => f.write_str("into"),
             TokenKind::Is => f.write_str("is"),
+            TokenKind::Isolation => f.write_str("isolation"),
             TokenKind::Join => f.write_str("join"),
             TokenKind::Key => f.write_str("key"),
             TokenKind::Lag => f.write_str("lag"),
             TokenKind::Last => f.write_str("last"),
-            TokenKind::LastValue => f.write_str("lastvalue"),
+            TokenKind::LastValue => f.write_str("last_value"),
             TokenKind::Lead => f.write_str("lead"),
             TokenKind::Left => f.write_str("left"),
+            TokenKind::Level => f.write_str("level"),
             TokenKind::Like => f.write_str("like"),
             TokenKind::Limit => f.write_str("limit"),
             TokenKind::Log => f.write_str("log"),
             TokenKind::Log10 => f.write_str("log10"),
+            TokenKind::Matched => f.write_str("matched"),
             TokenKind::Max => f.write_str("max"),
+            TokenKind::Merge => f.write_str("merge"),
             TokenKind::Microsecond => f.write_str("microsecond"),
             TokenKind::Microseconds => f.write_str("microseconds"),
             TokenKind::Millisecond => f.write_str("millisecond"),
@@ -895,11 +999,15 @@ impl<'a> fmt::Display for TokenKind<'a> {
             TokenKind::Month => f.write_str("month"),
             TokenKind::Nanosecond => f.write_str("nanosecond"),
             TokenKind::Nanoseconds => f.write_str("nanoseconds"),
+            TokenKind::Natural => f.write_str("natural"),
             TokenKind::Nchar => f.write_str("nchar"),
+            TokenKind::Newid => f.write_str("newid"),
+            TokenKind::Newsequentialid => f.write_str("newsequentialid"),
             TokenKind::Next => f.write_str("next"),
             TokenKind::Not => f.write_str("not"),
             TokenKind::Null => f.write_str("null"),
             TokenKind::Nullif => f.write_str("nullif"),
+            TokenKind::Nulls => f.write_str("nulls"),
             TokenKind::Numeric => f.write_str("numeric"),
             TokenKind::Nvarchar => f.write_str("nvarchar"),
             TokenKind::Offset => f.write_str("offset"),
@@ -908,19 +1016,26 @@ impl<'a> fmt::Display for TokenKind<'a> {
             TokenKind::Or => f.write_str("or"),
             TokenKind::Order => f.write_str("order"),
             TokenKind::Outer => f.write_str("outer"),
+            TokenKind::Output => f.write_str("output"),
             TokenKind::Over => f.write_str("over"),
             TokenKind::Partition => f.write_str("partition"),
+            TokenKind::Partitions => f.write_str("partitions"),
             TokenKind::Password => f.write_str("password"),
             TokenKind::Percent => f.write_str("percent"),
             TokenKind::Pi => f.write_str("pi"),
             TokenKind::Power => f.write_str("power"),
             TokenKind::Preceding => f.write_str("preceding"),
+            TokenKind::Primary => f.write_str("primary"),
             TokenKind::Procedure => f.write_str("procedure"),
             TokenKind::Radians => f.write_str("radians"),
-            TokenKind::Rands => f.write_str("rands"),
+            TokenKind::Rand => f.write_str("rand"),
             TokenKind::Range => f.write_str("range"),
             TokenKind::Rank => f.write_str("rank"),
+            TokenKind::Read => f.write_str("read"),
             TokenKind::Real => f.write_str("real"),
+            TokenKind::Rebuild => f.write_str("rebuild"),
+            TokenKind::Reorganize => f.write_str("reorganize"),
+            TokenKind::Repeatable => f.write_str("repeatable"),
             TokenKind::Return => f.write_str("return"),
             TokenKind::Returns => f.write_str("returns"),
             TokenKind::Revoke => f.write_str("revoke"),
@@ -931,9 +1046,10 @@ impl<'a> fmt::Display for TokenKind<'a> {
             TokenKind::Row => f.write_str("row"),
             TokenKind::Rowid => f.write_str("rowid"),
             TokenKind::Rows => f.write_str("rows"),
-            TokenKind::RowNumber => f.write_str("rownumber"),
+            TokenKind::RowNumber => f.write_str("row_number"),
             TokenKind::Second => f.write_str("second"),
             TokenKind::Select => f.write_str("select"),
+            TokenKind::Serializable => f.write_str("serializable"),
             TokenKind::Set => f.write_str("set"),
             TokenKind::Sign => f.write_str("sign"),
             TokenKind::Sin => f.write_str("sin"),
@@ -955,6 +1071,7 @@ impl<'a> fmt::Display for TokenKind<'a> {
             TokenKind::Ties => f.write_str("ties"),
             TokenKind::Time => f.write_str("time"),
             TokenKind::Tinyint => f.write_str("tinyint"),
+            TokenKind::To => f.write_str("to"),
             TokenKind::Top => f.write_str("top"),
             TokenKind::Transaction => f.write_str("transaction"),
             TokenKind::Trigger => f.write_str("trigger"),
@@ -969,6 +1086,7 @@ impl<'a> fmt::Display for TokenKind<'a> {
             TokenKind::Upper => f.write_str("upper"),
             TokenKind::Use => f.write_str("use"),
             TokenKind::User => f.write_str("user"),
+            TokenKind::Using => f.write_str("using"),
             TokenKind::Uuid => f.write_str("uuid"),
             TokenKind::Value => f.write_str("value"),
             TokenKind::Values => f.write_str("values"),
@@ -1012,16 +1130,16 @@ impl<'a> TokenKind<'a> {
             TokenKind::PercentSign => "%",
             TokenKind::Period => ".",
             TokenKind::SemiColon => ";",
-            // Token::LeftBracket => "[",
-            // Token::RightBracket => "]",
-            // Token::LeftBrace => "{",
-            // Token::RightBrace => "}",
+            TokenKind::LeftBracket => "[",
+            TokenKind::RightBracket => "]",
+            TokenKind::LeftBrace => "{",
+            TokenKind::RightBrace => "}",
             TokenKind::Eof => "eof",
-            // Token::PlusEqual => "+=",
-            // Token::MinusEqual => "-=",
-            // Token::DivideEqual => "/=",
-            // Token::MultiplyEqual => "*=",
-            // Token::PercentEqual => "%=",
+            TokenKind::PlusEqual => "+=",
+            TokenKind::MinusEqual => "-=",
+            TokenKind::DivideEqual => "/=",
+            TokenKind::MultiplyEqual => "*=",
+            TokenKind::PercentEqual => "%=",
             // Token::AndEqual => "&=",
             // Token::OrEqual => "|=",
             // Token::CaretEqual => "^=",
@@ -1031,6 +1149,7 @@ impl<'a> TokenKind<'a> {
             TokenKind::Alter => "alter",
             TokenKind::And => "and",
             TokenKind::Any => "any",
+            TokenKind::Apply => "apply",
             TokenKind::As => "as",
             TokenKind::Asc => "asc",
             TokenKind::Asin => "asin",
@@ -1051,12 +1170,13 @@ impl<'a> TokenKind<'a> {
             TokenKind::Column => "column",
             TokenKind::Columns => "columns",
             TokenKind::Commit => "commit",
-            TokenKind::Commited => "commited",
+            TokenKind::Committed => "committed",
             TokenKind::Constraint => "constraint",
             TokenKind::Cos => "cos",
             TokenKind::Cot => "cot",
             TokenKind::Count => "count",
             TokenKind::Create => "create",
+            TokenKind::Cross => "cross",
             TokenKind::Current => "current",
             TokenKind::Date => "date",
             TokenKind::Datetime => "datetime",
@@ -1068,15 +1188,17 @@ impl<'a> TokenKind<'a> {
             TokenKind::Degrees => "degrees",
             TokenKind::Default => "default",
             TokenKind::Delete => "delete",
-            TokenKind::DenseRank => "denserank",
+            TokenKind::DenseRank => "dense_rank",
             TokenKind::Desc => "desc",
             TokenKind::Describe => "describe",
+            TokenKind::Disable => "disable",
             TokenKind::Distinct => "distinct",
             TokenKind::Do => "do",
             TokenKind::Drop => "drop",
             TokenKind::Else => "else",
             TokenKind::End => "end",
             TokenKind::Engine => "engine",
+            TokenKind::Except => "except",
             TokenKind::Exec => "exec",
             TokenKind::Execute => "execute",
             TokenKind::Exists => "exists",
@@ -1084,7 +1206,7 @@ impl<'a> TokenKind<'a> {
             TokenKind::False => "false",
             TokenKind::Fetch => "fetch",
             TokenKind::First => "first",
-            TokenKind::FirstValue => "firstvalue",
+            TokenKind::FirstValue => "first_value",
             TokenKind::Float => "float",
             TokenKind::Floor => "floor",
             TokenKind::Following => "following",
@@ -1093,11 +1215,13 @@ impl<'a> TokenKind<'a> {
             TokenKind::Full => "full",
             TokenKind::Function => "function",
             TokenKind::Getdate => "getdate",
+            TokenKind::Go => "go",
             TokenKind::Group => "group",
             TokenKind::Having => "having",
             TokenKind::Hour => "hour",
             TokenKind::Hours => "hours",
             TokenKind::Identity => "identity",
+            TokenKind::Ilike => "ilike",
             TokenKind::If => "if",
             TokenKind::In => "in",
             TokenKind::Increment => "increment",
@@ -1109,18 +1233,22 @@ impl<'a> TokenKind<'a> {
             TokenKind::Int => "int",
             TokenKind::Into => "into",
             TokenKind::Is => "is",
+            TokenKind::Isolation => "isolation",
             TokenKind::Join => "join",
             TokenKind::Key => "key",
             TokenKind::Lag => "lag",
             TokenKind::Last => "last",
-            TokenKind::LastValue => "lastvalue",
+            TokenKind::LastValue => "last_value",
             TokenKind::Lead => "lead",
             TokenKind::Left => "left",
+            TokenKind::Level => "level",
             TokenKind::Like => "like",
             TokenKind::Limit => "limit",
             TokenKind::Log => "log",
             TokenKind::Log10 => "log10",
+            TokenKind::Matched => "matched",
             TokenKind::Max => "max",
+            TokenKind::Merge => "merge",
             TokenKind::Microsecond => "microsecond",
             TokenKind::Microseconds => "microseconds",
             TokenKind::Millisecond => "millisecond",
@@ -1130,11 +1258,15 @@ impl<'a> TokenKind<'a> {
             TokenKind::Month => "month",
             TokenKind::Nanosecond => "nanosecond",
             TokenKind::Nanoseconds => "nanoseconds",
+            TokenKind::Natural => "natural",
             TokenKind::Nchar => "nchar",
+            TokenKind::Newid => "newid",
+            TokenKind::Newsequentialid => "newsequentialid",
             TokenKind::Next => "next",
             TokenKind::Not => "not",
             TokenKind::Null => "null",
             TokenKind::Nullif => "nullif",
+            TokenKind::Nulls => "nulls",
             TokenKind::Numeric => "numeric",
             TokenKind::Nvarchar => "nvarchar",
             TokenKind::Offset => "offset",
@@ -1143,19 +1275,26 @@ impl<'a> TokenKind<'a> {
             TokenKind::Or => "or",
             TokenKind::Order => "order",
             TokenKind::Outer => "outer",
+            TokenKind::Output => "output",
             TokenKind::Over => "over",
             TokenKind::Partition => "partition",
+            TokenKind::Partitions => "partitions",
             TokenKind::Password => "password",
             TokenKind::Percent => "percent",
             TokenKind::Pi => "pi",
             TokenKind::Power => "power",
             TokenKind::Preceding => "preceding",
+            TokenKind::Primary => "primary",
             TokenKind::Procedure => "procedure",
             TokenKind::Radians => "radians",
-            TokenKind::Rands => "rands",
+            TokenKind::Rand => "rand",
             TokenKind::Range => "range",
             TokenKind::Rank => "rank",
+            TokenKind::Read => "read",
             TokenKind::Real => "real",
+            TokenKind::Rebuild => "rebuild",
+            TokenKind::Reorganize => "reorganize",
+            TokenKind::Repeatable => "repeatable",
             TokenKind::Return => "return",
             TokenKind::Returns => "returns",
             TokenKind::Revoke => "revoke",
@@ -1166,9 +1305,10 @@ impl<'a> TokenKind<'a> {
             TokenKind::Row => "row",
             TokenKind::Rowid => "rowid",
             TokenKind::Rows => "rows",
-            TokenKind::RowNumber => "rownumber",
+            TokenKind::RowNumber => "row_number",
             TokenKind::Second => "second",
             TokenKind::Select => "select",
+            TokenKind::Serializable => "serializable",
             TokenKind::Set => "set",
             TokenKind::Sign => "sign",
             TokenKind::Sin => "sin",
@@ -1190,6 +1330,7 @@ impl<'a> TokenKind<'a> {
             TokenKind::Ties => "ties",
             TokenKind::Time => "time",
             TokenKind::Tinyint => "tinyint",
+            TokenKind::To => "to",
             TokenKind::Top => "top",
             TokenKind::Transaction => "transaction",
             TokenKind::Trigger => "trigger",
@@ -1204,6 +1345,7 @@ impl<'a> TokenKind<'a> {
             TokenKind::Upper => "upper",
             TokenKind::Use => "use",
             TokenKind::User => "user",
+            TokenKind::Using => "using",
             TokenKind::Uuid => "uuid",
             TokenKind::Value => "value",
             TokenKind::Values => "values",
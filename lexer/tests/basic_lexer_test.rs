@@ -78,6 +78,50 @@ fn test_identifiers_quoted() {
     assert_eq!(expected_tokens, tokens);
 }
 
+#[test]
+fn test_brackets_and_braces() {
+    let input = "select [col], arr[0], data['key'] {}";
+    let lexer = Lexer::new(input);
+    let mut tokens = Vec::new();
+    for result in lexer {
+        let token = result.unwrap();
+        tokens.push(token.kind());
+        if result.is_ok_and(|t| t.shallow_eq_token_kind(&TokenKind::Eof)) {
+            break;
+        }
+    }
+
+    let expected_tokens = vec![
+        TokenKind::Select,
+        TokenKind::QuotedIdentifier("col"),
+        TokenKind::Comma,
+        TokenKind::Identifier("arr"),
+        TokenKind::LeftBracket,
+        TokenKind::NumberLiteral("0"),
+        TokenKind::RightBracket,
+        TokenKind::Comma,
+        TokenKind::Identifier("data"),
+        TokenKind::LeftBracket,
+        TokenKind::StringLiteral("key"),
+        TokenKind::RightBracket,
+        TokenKind::LeftBrace,
+        TokenKind::RightBrace,
+        TokenKind::Eof,
+    ];
+
+    assert_eq!(expected_tokens, tokens);
+    assert_eq!(
+        "[]{}",
+        format!(
+            "{}{}{}{}",
+            TokenKind::LeftBracket,
+            TokenKind::RightBracket,
+            TokenKind::LeftBrace,
+            TokenKind::RightBrace
+        )
+    );
+}
+
 #[test]
 fn test_string() {
     let input = "select name as 'SuperName', id from users";
@@ -134,6 +178,86 @@ fn test_comment() {
     assert_eq!(expected_tokens, tokens);
 }
 
+#[test]
+fn test_isolation_level_keywords() {
+    let input = "read COMMITTED Uncommitted commited";
+    let lexer = Lexer::new(input);
+    let mut tokens = Vec::new();
+    for result in lexer {
+        let token = result.unwrap();
+        tokens.push(token.kind());
+        if result.is_ok_and(|t| t.shallow_eq_token_kind(&TokenKind::Eof)) {
+            break;
+        }
+    }
+
+    let expected_tokens = vec![
+        TokenKind::Read,
+        TokenKind::Committed,
+        TokenKind::Uncommitted,
+        TokenKind::Identifier("commited"),
+        TokenKind::Eof,
+    ];
+
+    assert_eq!(expected_tokens, tokens);
+    assert_eq!("committed", TokenKind::Committed.to_string());
+}
+
+#[test]
+fn test_unicode_string_literal() {
+    let input = "SELECT '🎉 party' AS msg, naïve -- ünïcode\n1";
+    let lexer = Lexer::new(input);
+    let mut tokens = Vec::new();
+    for result in lexer {
+        let token = result.unwrap();
+        tokens.push((token.kind(), token.location()));
+        if result.is_ok_and(|t| t.shallow_eq_token_kind(&TokenKind::Eof)) {
+            break;
+        }
+    }
+
+    let expected_tokens = vec![
+        (TokenKind::Select, Span::new(0, 5)),
+        (TokenKind::StringLiteral("🎉 party"), Span::new(7, 18)),
+        (TokenKind::As, Span::new(20, 21)),
+        (TokenKind::Identifier("msg"), Span::new(23, 25)),
+        (TokenKind::Comma, Span::new(26, 26)),
+        (TokenKind::Identifier("naïve"), Span::new(28, 33)),
+        (TokenKind::Comment("ünïcode"), Span::new(35, 47)),
+        (TokenKind::NumberLiteral("1"), Span::new(48, 48)),
+        (TokenKind::Eof, Span::new(49, 49)),
+    ];
+
+    assert_eq!(expected_tokens, tokens);
+}
+
+#[test]
+fn test_percent_sign() {
+    let input = "a % b %= 2 top 10 percent";
+    let lexer = Lexer::new(input);
+    let mut tokens = Vec::new();
+    for result in lexer {
+        let token = result.unwrap();
+        tokens.push(token.kind());
+        if result.is_ok_and(|t| t.shallow_eq_token_kind(&TokenKind::Eof)) {
+            break;
+        }
+    }
+    let expected_tokens = vec![
+        TokenKind::Identifier("a"),
+        TokenKind::PercentSign,
+        TokenKind::Identifier("b"),
+        TokenKind::PercentEqual,
+        TokenKind::NumberLiteral("2"),
+        TokenKind::Top,
+        TokenKind::NumberLiteral("10"),
+        TokenKind::Percent,
+        TokenKind::Eof,
+    ];
+
+    assert_eq!(expected_tokens, tokens);
+}
+
 #[test]
 fn test_illegal_string_literal() {
     let input = "select name as 'SuperName, yess id from users";
@@ -185,3 +309,231 @@ fn test_illegal_quoted_identifier() {
 
     assert_eq!(expected_tokens, tokens);
 }
+
+#[test]
+fn test_unrecognized_token() {
+    let input = "select # from users";
+    let error = LexicalError {
+        error: LexicalErrorType::UnrecognizedToken { ch: '#' },
+        span: Span { start: 7, end: 7 },
+    };
+
+    assert_eq!(Err(error), Lexer::new(input).tokenize());
+}
+
+#[test]
+fn test_tokenize() {
+    let input = "select name from users";
+    let tokens = Lexer::new(input)
+        .tokenize()
+        .expect("expected the input to lex");
+    let expected_tokens = vec![
+        TokenKind::Select,
+        TokenKind::Identifier("name"),
+        TokenKind::From,
+        TokenKind::Identifier("users"),
+        TokenKind::Eof,
+    ];
+
+    assert_eq!(
+        expected_tokens,
+        tokens.iter().map(|t| t.kind()).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        Lexer::new(input).tokenize_lossy(),
+        tokens.into_iter().map(Ok).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_tokenize_unterminated_string() {
+    let input = "select 'name, [id from users";
+    let error = LexicalError {
+        error: LexicalErrorType::UnexpectedStringEnd,
+        span: Span { start: 8, end: 27 },
+    };
+
+    assert_eq!(Err(error), Lexer::new(input).tokenize());
+
+    let results = Lexer::new(input)
+        .tokenize_lossy()
+        .into_iter()
+        .map(|result| result.map(|t| t.kind()))
+        .collect::<Vec<_>>();
+    let expected_results = vec![Ok(TokenKind::Select), Err(error), Ok(TokenKind::Eof)];
+
+    assert_eq!(expected_results, results);
+}
+
+#[test]
+fn test_peek_n() {
+    let input = "select t.* from t";
+    let mut lexer = Lexer::new(input);
+
+    assert_eq!(Some(TokenKind::Asterisk), lexer.peek_n(3).map(|t| t.kind()));
+    assert_eq!(Some(TokenKind::Select), lexer.peek_n(0).map(|t| t.kind()));
+    assert_eq!(Some(TokenKind::Period), lexer.peek_n(2).map(|t| t.kind()));
+    assert_eq!(Some(TokenKind::Eof), lexer.peek_n(10).map(|t| t.kind()));
+
+    let expected_tokens = Lexer::new(input).tokenize();
+    assert_eq!(expected_tokens, lexer.clone().tokenize());
+
+    let _ = lexer.next();
+    assert_eq!(
+        Some(TokenKind::Identifier("t")),
+        lexer.peek_n(0).map(|t| t.kind())
+    );
+    assert_eq!(Some(Span::new(9, 9)), lexer.peek_n(2).map(|t| t.location()));
+    let tokens = lexer.tokenize().expect("expected the input to lex");
+    assert_eq!(
+        expected_tokens.as_deref().ok().map(|t| &t[1..]),
+        Some(&tokens[..])
+    );
+}
+
+#[test]
+fn test_peek_n_error() {
+    let input = "select 'name";
+    let mut lexer = Lexer::new(input);
+
+    assert_eq!(None, lexer.peek_n(1));
+    assert_eq!(Some(TokenKind::Eof), lexer.peek_n(2).map(|t| t.kind()));
+    assert_eq!(Lexer::new(input).tokenize_lossy(), lexer.tokenize_lossy());
+}
+
+#[test]
+fn test_number_literals() {
+    let input = "select 1.50, 100., .5 from t";
+    let tokens = Lexer::new(input)
+        .tokenize()
+        .expect("expected the input to lex")
+        .into_iter()
+        .map(|t| (t.kind(), t.location()))
+        .collect::<Vec<_>>();
+
+    let expected_tokens = vec![
+        (TokenKind::Select, Span::new(0, 5)),
+        (TokenKind::NumberLiteral("1.50"), Span::new(7, 10)),
+        (TokenKind::Comma, Span::new(11, 11)),
+        (TokenKind::NumberLiteral("100."), Span::new(13, 16)),
+        (TokenKind::Comma, Span::new(17, 17)),
+        (TokenKind::NumberLiteral(".5"), Span::new(19, 20)),
+        (TokenKind::From, Span::new(22, 25)),
+        (TokenKind::Identifier("t"), Span::new(27, 27)),
+        (TokenKind::Eof, Span::new(28, 28)),
+    ];
+
+    assert_eq!(expected_tokens, tokens);
+}
+
+#[test]
+fn test_leading_period_numbers() {
+    let tests = [
+        (
+            ".5",
+            vec![(TokenKind::NumberLiteral(".5"), Span::new(0, 1))],
+        ),
+        (
+            "(.5)",
+            vec![
+                (TokenKind::LeftParen, Span::new(0, 0)),
+                (TokenKind::NumberLiteral(".5"), Span::new(1, 2)),
+                (TokenKind::RightParen, Span::new(3, 3)),
+            ],
+        ),
+        (
+            "1.5",
+            vec![(TokenKind::NumberLiteral("1.5"), Span::new(0, 2))],
+        ),
+        (
+            "a.b",
+            vec![
+                (TokenKind::Identifier("a"), Span::new(0, 0)),
+                (TokenKind::Period, Span::new(1, 1)),
+                (TokenKind::Identifier("b"), Span::new(2, 2)),
+            ],
+        ),
+        // a period right after a name is part of a compound name
+        (
+            "a.5",
+            vec![
+                (TokenKind::Identifier("a"), Span::new(0, 0)),
+                (TokenKind::Period, Span::new(1, 1)),
+                (TokenKind::NumberLiteral("5"), Span::new(2, 2)),
+            ],
+        ),
+        (
+            "[a].5",
+            vec![
+                (TokenKind::QuotedIdentifier("a"), Span::new(0, 2)),
+                (TokenKind::Period, Span::new(3, 3)),
+                (TokenKind::NumberLiteral("5"), Span::new(4, 4)),
+            ],
+        ),
+    ];
+
+    for (input, mut expected_tokens) in tests {
+        let tokens = Lexer::new(input)
+            .tokenize()
+            .expect("expected the input to lex")
+            .into_iter()
+            .map(|t| (t.kind(), t.location()))
+            .collect::<Vec<_>>();
+        let eof = input.len() as u32;
+        expected_tokens.push((TokenKind::Eof, Span::new(eof, eof)));
+
+        assert_eq!(expected_tokens, tokens, "{input}");
+    }
+}
+
+#[test]
+fn test_batch_separator() {
+    let tests = [
+        ("GO", vec![(TokenKind::Go, Span::new(0, 1))]),
+        (
+            "select 1\n  go 3 -- run it three times\n",
+            vec![
+                (TokenKind::Select, Span::new(0, 5)),
+                (TokenKind::NumberLiteral("1"), Span::new(7, 7)),
+                (TokenKind::Go, Span::new(11, 12)),
+                (TokenKind::NumberLiteral("3"), Span::new(14, 14)),
+                (TokenKind::Comment("run it three times"), Span::new(16, 37)),
+            ],
+        ),
+        // anywhere but alone on its line go is just a name
+        (
+            "select go",
+            vec![
+                (TokenKind::Select, Span::new(0, 5)),
+                (TokenKind::Identifier("go"), Span::new(7, 8)),
+            ],
+        ),
+        (
+            "go\nselect",
+            vec![
+                (TokenKind::Go, Span::new(0, 1)),
+                (TokenKind::Select, Span::new(3, 8)),
+            ],
+        ),
+        (
+            "go select",
+            vec![
+                (TokenKind::Identifier("go"), Span::new(0, 1)),
+                (TokenKind::Select, Span::new(3, 8)),
+            ],
+        ),
+    ];
+
+    for (input, mut expected_tokens) in tests {
+        let tokens = Lexer::new(input)
+            .tokenize()
+            .expect("expected the input to lex")
+            .into_iter()
+            .map(|t| (t.kind(), t.location()))
+            .collect::<Vec<_>>();
+        let eof = input.len() as u32;
+        expected_tokens.push((TokenKind::Eof, Span::new(eof, eof)));
+
+        assert_eq!(expected_tokens, tokens, "{input}");
+    }
+}
@@ -3,6 +3,7 @@ use std::fmt;
 use super::{Keyword, Symbol};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DataType {
     Int(Keyword),
     BigInt(Keyword),
@@ -17,15 +18,36 @@ pub enum DataType {
     Decimal(Keyword, Option<NumericSize>),
     Numeric(Keyword, Option<NumericSize>),
     Varchar(Keyword, Option<DataTypeSize>),
+    Nvarchar(Keyword, Option<DataTypeSize>),
+    Char(Keyword, Option<DataTypeSize>),
+    Nchar(Keyword, Option<DataTypeSize>),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DataTypeSize {
     pub left_paren: Symbol,
-    pub size: u32,
+    pub size: DataTypeLength,
     pub right_paren: Symbol,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DataTypeLength {
+    Number(u32),
+    // only the variable length string types can be sized with MAX
+    Max(Keyword),
+}
+
+impl fmt::Display for DataTypeLength {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DataTypeLength::Number(n) => write!(f, "{}", n),
+            DataTypeLength::Max(k) => write!(f, "{}", k),
+        }
+    }
+}
+
 impl fmt::Display for DataTypeSize {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}{}{}", self.left_paren, self.size, self.right_paren)
@@ -33,6 +55,7 @@ impl fmt::Display for DataTypeSize {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NumericSize {
     pub left_paren: Symbol,
     pub precision: u32,
@@ -83,7 +106,10 @@ impl fmt::Display for DataType {
                 }
                 Ok(())
             }
-            DataType::Varchar(k, s) => {
+            DataType::Varchar(k, s)
+            | DataType::Nvarchar(k, s)
+            | DataType::Char(k, s)
+            | DataType::Nchar(k, s) => {
                 write!(f, "{}", k)?;
                 if let Some(s) = s {
                     write!(f, "{}", s)?;
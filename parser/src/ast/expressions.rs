@@ -1,42 +1,49 @@
 use super::{
-    display_list_comma_separated, display_list_delimiter_separated, DataType, Keyword,
-    SelectStatement, Symbol,
+    display_list_comma_separated, display_list_delimiter_separated, DataType, Keyword, Statement,
+    Symbol,
 };
 use crate::error::{parse_error, ParseError, ParseErrorType};
 use core::fmt;
 use lexer::{Span, Token, TokenKind};
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ComparisonOperator {
     pub location: Span,
     pub kind: ComparisonOperatorKind,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ArithmeticOperator {
     pub location: Span,
     pub kind: ArithmeticOperatorKind,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UnaryOperator {
     pub location: Span,
     pub kind: UnaryOperatorKind,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Literal {
     pub location: Span,
     pub content: String,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OrderByArg {
     pub column: Expression,
     pub order_kw: Option<Keyword>,
+    pub nulls_kws: Option<Vec<Keyword>>,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OverClause {
     pub over_kw: Keyword,
     pub left_paren: Symbol,
@@ -49,6 +56,7 @@ pub struct OverClause {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WindowFrame {
     pub rows_or_range: RowsOrRange,
     pub rows_or_range_kw: Keyword,
@@ -61,6 +69,7 @@ pub struct WindowFrame {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Expression {
     Asterisk(Symbol),
     Identifier(Literal),
@@ -97,6 +106,7 @@ pub enum Expression {
     Function {
         name: Box<FunctionName>,
         left_paren: Symbol,
+        set_quantifier: Option<Keyword>,
         args: Option<Vec<Expression>>,
         right_paren: Symbol,
         over: Option<Box<OverClause>>,
@@ -125,9 +135,20 @@ pub enum Expression {
     },
     Subquery {
         left_paren: Symbol,
-        select_statement: Box<SelectStatement>,
+        statement: Box<Statement>,
         right_paren: Symbol,
     },
+    Grouping {
+        left_paren: Symbol,
+        expression: Box<Expression>,
+        right_paren: Symbol,
+    },
+    Subscript {
+        base: Box<Expression>,
+        left_bracket: Symbol,
+        index: Box<Expression>,
+        right_bracket: Symbol,
+    },
     Between {
         test_expression: Box<Expression>,
         not_kw: Option<Keyword>,
@@ -166,6 +187,7 @@ pub enum Expression {
         match_expression: Box<Expression>,
         not_kw: Option<Keyword>,
         like_kw: Keyword,
+        case_insensitive: bool,
         pattern: Box<Expression>,
     },
     SimpleCase {
@@ -182,6 +204,7 @@ pub enum Expression {
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ComparisonOperatorKind {
     Equal,
     NotEqualBang,
@@ -193,6 +216,7 @@ pub enum ComparisonOperatorKind {
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ArithmeticOperatorKind {
     Plus,
     Minus,
@@ -202,24 +226,28 @@ pub enum ArithmeticOperatorKind {
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum UnaryOperatorKind {
     Plus,
     Minus,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FunctionName {
     Builtin(Keyword),
     User(Expression),
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RowsOrRange {
     Rows,
     Range,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WindowFrameBound {
     CurrentRow,
     Preceding(Expression),
@@ -229,6 +257,7 @@ pub enum WindowFrameBound {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CaseCondition {
     WhenCondition {
         when_kw: Keyword,
@@ -262,7 +291,7 @@ impl fmt::Display for ComparisonOperatorKind {
             ComparisonOperatorKind::NotEqualArrow => f.write_str("<>"),
             ComparisonOperatorKind::GreaterThan => f.write_str(">"),
             ComparisonOperatorKind::GreaterThanEqual => f.write_str(">="),
-            ComparisonOperatorKind::LessThan => f.write_str("<="),
+            ComparisonOperatorKind::LessThan => f.write_str("<"),
             ComparisonOperatorKind::LessThanEqual => f.write_str("<="),
         }
     }
@@ -477,7 +506,7 @@ impl fmt::Display for Expression {
                 left,
                 right,
             } => write!(f, "{} {} {}", left, operator, right),
-            Expression::Unary { operator, right } => write!(f, "{} {}", operator, right),
+            Expression::Unary { operator, right } => write!(f, "{}{}", operator, right),
             Expression::And {
                 and_kw,
                 left,
@@ -487,11 +516,15 @@ impl fmt::Display for Expression {
             Expression::Function {
                 name,
                 left_paren,
+                set_quantifier,
                 args,
                 right_paren,
                 over,
             } => {
                 write!(f, "{}{}", name, left_paren)?;
+                if let Some(set_quantifier) = set_quantifier {
+                    write!(f, "{} ", set_quantifier)?;
+                }
                 if let Some(args_vec) = args {
                     display_list_comma_separated(args_vec, f)?;
                 }
@@ -534,11 +567,24 @@ impl fmt::Display for Expression {
             }
             Expression::Subquery {
                 left_paren,
-                select_statement,
+                statement,
                 right_paren,
             } => {
-                write!(f, "{}{}{}", left_paren, select_statement, right_paren)
+                write!(f, "{}{}{}", left_paren, statement, right_paren)
             }
+            Expression::Grouping {
+                left_paren,
+                expression,
+                right_paren,
+            } => {
+                write!(f, "{}{}{}", left_paren, expression, right_paren)
+            }
+            Expression::Subscript {
+                base,
+                left_bracket,
+                index,
+                right_bracket,
+            } => write!(f, "{}{}{}{}", base, left_bracket, index, right_bracket),
             Expression::InSubquery {
                 test_expression,
                 in_kw,
@@ -617,6 +663,7 @@ impl fmt::Display for Expression {
                 not_kw,
                 like_kw,
                 pattern,
+                ..
             } => {
                 write!(f, "{}", match_expression)?;
                 if let Some(not_kw) = not_kw {
@@ -706,10 +753,16 @@ impl fmt::Display for RowsOrRange {
 
 impl fmt::Display for OrderByArg {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match &self.order_kw {
-            Some(kw) => write!(f, "{} {}", self.column, kw),
-            None => write!(f, "{}", self.column),
+        write!(f, "{}", self.column)?;
+        if let Some(kw) = &self.order_kw {
+            write!(f, " {}", kw)?;
         }
+        if let Some(nulls_kws) = &self.nulls_kws {
+            f.write_str(" ")?;
+            display_list_delimiter_separated(nulls_kws, " ", f)?;
+        }
+
+        Ok(())
     }
 }
 
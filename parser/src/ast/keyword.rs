@@ -5,6 +5,7 @@ use lexer::{Span, Token, TokenKind};
 use crate::error::{parse_error, ParseError, ParseErrorType};
 
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Keyword {
     pub location: Span,
     pub kind: KeywordKind,
@@ -36,6 +37,7 @@ impl<'a> TryFrom<Token<'a>> for Keyword {
             TokenKind::Alter => KeywordKind::Alter,
             TokenKind::And => KeywordKind::And,
             TokenKind::Any => KeywordKind::Any,
+            TokenKind::Apply => KeywordKind::Apply,
             TokenKind::As => KeywordKind::As,
             TokenKind::Asc => KeywordKind::Asc,
             TokenKind::Asin => KeywordKind::Asin,
@@ -56,12 +58,13 @@ impl<'a> TryFrom<Token<'a>> for Keyword {
             TokenKind::Column => KeywordKind::Column,
             TokenKind::Columns => KeywordKind::Columns,
             TokenKind::Commit => KeywordKind::Commit,
-            TokenKind::Commited => KeywordKind::Commited,
+            TokenKind::Committed => KeywordKind::Committed,
             TokenKind::Constraint => KeywordKind::Constraint,
             TokenKind::Cos => KeywordKind::Cos,
             TokenKind::Cot => KeywordKind::Cot,
             TokenKind::Count => KeywordKind::Count,
             TokenKind::Create => KeywordKind::Create,
+            TokenKind::Cross => KeywordKind::Cross,
             TokenKind::Current => KeywordKind::Current,
             TokenKind::Date => KeywordKind::Date,
             TokenKind::Datetime => KeywordKind::Datetime,
@@ -76,12 +79,14 @@ impl<'a> TryFrom<Token<'a>> for Keyword {
             TokenKind::DenseRank => KeywordKind::DenseRank,
             TokenKind::Desc => KeywordKind::Desc,
             TokenKind::Describe => KeywordKind::Describe,
+            TokenKind::Disable => KeywordKind::Disable,
             TokenKind::Distinct => KeywordKind::Distinct,
             TokenKind::Do => KeywordKind::Do,
             TokenKind::Drop => KeywordKind::Drop,
             TokenKind::Else => KeywordKind::Else,
             TokenKind::End => KeywordKind::End,
             TokenKind::Engine => KeywordKind::Engine,
+            TokenKind::Except => KeywordKind::Except,
             TokenKind::Exec => KeywordKind::Exec,
             TokenKind::Execute => KeywordKind::Execute,
             TokenKind::Exists => KeywordKind::Exists,
@@ -98,11 +103,13 @@ impl<'a> TryFrom<Token<'a>> for Keyword {
             TokenKind::Full => KeywordKind::Full,
             TokenKind::Function => KeywordKind::Function,
             TokenKind::Getdate => KeywordKind::Getdate,
+            TokenKind::Go => KeywordKind::Go,
             TokenKind::Group => KeywordKind::Group,
             TokenKind::Having => KeywordKind::Having,
             TokenKind::Hour => KeywordKind::Hour,
             TokenKind::Hours => KeywordKind::Hours,
             TokenKind::Identity => KeywordKind::Identity,
+            TokenKind::Ilike => KeywordKind::Ilike,
             TokenKind::If => KeywordKind::If,
             TokenKind::In => KeywordKind::In,
             TokenKind::Increment => KeywordKind::Increment,
@@ -114,6 +121,7 @@ impl<'a> TryFrom<Token<'a>> for Keyword {
             TokenKind::Int => KeywordKind::Int,
             TokenKind::Into => KeywordKind::Into,
             TokenKind::Is => KeywordKind::Is,
+            TokenKind::Isolation => KeywordKind::Isolation,
             TokenKind::Join => KeywordKind::Join,
             TokenKind::Key => KeywordKind::Key,
             TokenKind::Lag => KeywordKind::Lag,
@@ -121,11 +129,14 @@ impl<'a> TryFrom<Token<'a>> for Keyword {
             TokenKind::LastValue => KeywordKind::LastValue,
             TokenKind::Lead => KeywordKind::Lead,
             TokenKind::Left => KeywordKind::Left,
+            TokenKind::Level => KeywordKind::Level,
             TokenKind::Like => KeywordKind::Like,
             TokenKind::Limit => KeywordKind::Limit,
             TokenKind::Log => KeywordKind::Log,
             TokenKind::Log10 => KeywordKind::Log10,
+            TokenKind::Matched => KeywordKind::Matched,
             TokenKind::Max => KeywordKind::Max,
+            TokenKind::Merge => KeywordKind::Merge,
             TokenKind::Microsecond => KeywordKind::Microsecond,
             TokenKind::Microseconds => KeywordKind::Microseconds,
             TokenKind::Millisecond => KeywordKind::Millisecond,
@@ -135,11 +146,15 @@ impl<'a> TryFrom<Token<'a>> for Keyword {
             TokenKind::Month => KeywordKind::Month,
             TokenKind::Nanosecond => KeywordKind::Nanosecond,
             TokenKind::Nanoseconds => KeywordKind::Nanoseconds,
+            TokenKind::Natural => KeywordKind::Natural,
             TokenKind::Nchar => KeywordKind::Nchar,
+            TokenKind::Newid => KeywordKind::Newid,
+            TokenKind::Newsequentialid => KeywordKind::Newsequentialid,
             TokenKind::Next => KeywordKind::Next,
             TokenKind::Not => KeywordKind::Not,
             TokenKind::Null => KeywordKind::Null,
             TokenKind::Nullif => KeywordKind::Nullif,
+            TokenKind::Nulls => KeywordKind::Nulls,
             TokenKind::Numeric => KeywordKind::Numeric,
             TokenKind::Nvarchar => KeywordKind::Nvarchar,
             TokenKind::Offset => KeywordKind::Offset,
@@ -148,19 +163,26 @@ impl<'a> TryFrom<Token<'a>> for Keyword {
             TokenKind::Or => KeywordKind::Or,
             TokenKind::Order => KeywordKind::Order,
             TokenKind::Outer => KeywordKind::Outer,
+            TokenKind::Output => KeywordKind::Output,
             TokenKind::Over => KeywordKind::Over,
             TokenKind::Partition => KeywordKind::Partition,
+            TokenKind::Partitions => KeywordKind::Partitions,
             TokenKind::Password => KeywordKind::Password,
             TokenKind::Percent => KeywordKind::Percent,
             TokenKind::Pi => KeywordKind::Pi,
             TokenKind::Power => KeywordKind::Power,
             TokenKind::Preceding => KeywordKind::Preceding,
+            TokenKind::Primary => KeywordKind::Primary,
             TokenKind::Procedure => KeywordKind::Procedure,
             TokenKind::Radians => KeywordKind::Radians,
-            TokenKind::Rands => KeywordKind::Rands,
+            TokenKind::Rand => KeywordKind::Rand,
             TokenKind::Range => KeywordKind::Range,
             TokenKind::Rank => KeywordKind::Rank,
+            TokenKind::Read => KeywordKind::Read,
             TokenKind::Real => KeywordKind::Real,
+            TokenKind::Rebuild => KeywordKind::Rebuild,
+            TokenKind::Reorganize => KeywordKind::Reorganize,
+            TokenKind::Repeatable => KeywordKind::Repeatable,
             TokenKind::Return => KeywordKind::Return,
             TokenKind::Returns => KeywordKind::Returns,
             TokenKind::Revoke => KeywordKind::Revoke,
@@ -174,6 +196,7 @@ impl<'a> TryFrom<Token<'a>> for Keyword {
             TokenKind::RowNumber => KeywordKind::RowNumber,
             TokenKind::Second => KeywordKind::Second,
             TokenKind::Select => KeywordKind::Select,
+            TokenKind::Serializable => KeywordKind::Serializable,
             TokenKind::Set => KeywordKind::Set,
             TokenKind::Sign => KeywordKind::Sign,
             TokenKind::Sin => KeywordKind::Sin,
@@ -195,6 +218,7 @@ impl<'a> TryFrom<Token<'a>> for Keyword {
             TokenKind::Ties => KeywordKind::Ties,
             TokenKind::Time => KeywordKind::Time,
             TokenKind::Tinyint => KeywordKind::Tinyint,
+            TokenKind::To => KeywordKind::To,
             TokenKind::Top => KeywordKind::Top,
             TokenKind::Transaction => KeywordKind::Transaction,
             TokenKind::Trigger => KeywordKind::Trigger,
@@ -209,6 +233,7 @@ impl<'a> TryFrom<Token<'a>> for Keyword {
             TokenKind::Upper => KeywordKind::Upper,
             TokenKind::Use => KeywordKind::Use,
             TokenKind::User => KeywordKind::User,
+            TokenKind::Using => KeywordKind::Using,
             TokenKind::Uuid => KeywordKind::Uuid,
             TokenKind::Value => KeywordKind::Value,
             TokenKind::Values => KeywordKind::Values,
@@ -250,6 +275,46 @@ impl fmt::Display for Keyword {
     }
 }
 
+impl KeywordKind {
+    /// The minimum and maximum number of arguments a builtin function accepts,
+    /// or `None` if the call is not checked.
+    pub fn builtin_fn_arity(&self) -> Option<(usize, usize)> {
+        match self {
+            KeywordKind::Getdate
+            | KeywordKind::Newid
+            | KeywordKind::Newsequentialid
+            | KeywordKind::Pi => Some((0, 0)),
+            KeywordKind::Rand => Some((0, 1)),
+            KeywordKind::Abs
+            | KeywordKind::Acos
+            | KeywordKind::Asin
+            | KeywordKind::Atan
+            | KeywordKind::Ceil
+            | KeywordKind::Ceiling
+            | KeywordKind::Cos
+            | KeywordKind::Cot
+            | KeywordKind::Day
+            | KeywordKind::Degrees
+            | KeywordKind::Exp
+            | KeywordKind::Floor
+            | KeywordKind::Log10
+            | KeywordKind::Month
+            | KeywordKind::Radians
+            | KeywordKind::Sign
+            | KeywordKind::Sin
+            | KeywordKind::Sqrt
+            | KeywordKind::Square
+            | KeywordKind::Tan
+            | KeywordKind::Upper
+            | KeywordKind::Year => Some((1, 1)),
+            KeywordKind::Log => Some((1, 2)),
+            KeywordKind::Nullif | KeywordKind::Power => Some((2, 2)),
+            KeywordKind::Round => Some((2, 3)),
+            _ => None,
+        }
+    }
+}
+
 impl fmt::Display for KeywordKind {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -259,6 +324,7 @@ impl fmt::Display for KeywordKind {
             KeywordKind::Alter => f.write_str("alter"),
             KeywordKind::And => f.write_str("and"),
             KeywordKind::Any => f.write_str("any"),
+            KeywordKind::Apply => f.write_str("apply"),
             KeywordKind::As => f.write_str("as"),
             KeywordKind::Asc => f.write_str("asc"),
             KeywordKind::Asin => f.write_str("asin"),
@@ -279,12 +345,13 @@ impl fmt::Display for KeywordKind {
             KeywordKind::Column => f.write_str("column"),
             KeywordKind::Columns => f.write_str("columns"),
             KeywordKind::Commit => f.write_str("commit"),
-            KeywordKind::Commited => f.write_str("commited"),
+            KeywordKind::Committed => f.write_str("committed"),
             KeywordKind::Constraint => f.write_str("constraint"),
             KeywordKind::Cos => f.write_str("cos"),
             KeywordKind::Cot => f.write_str("cot"),
             KeywordKind::Count => f.write_str("count"),
             KeywordKind::Create => f.write_str("create"),
+            KeywordKind::Cross => f.write_str("cross"),
             KeywordKind::Current => f.write_str("current"),
             KeywordKind::Date => f.write_str("date"),
             KeywordKind::Datetime => f.write_str("datetime"),
@@ -296,15 +363,17 @@ impl fmt::Display for KeywordKind {
             KeywordKind::Degrees => f.write_str("degrees"),
             KeywordKind::Default => f.write_str("default"),
             KeywordKind::Delete => f.write_str("delete"),
-            KeywordKind::DenseRank => f.write_str("denserank"),
+            KeywordKind::DenseRank => f.write_str("dense_rank"),
             KeywordKind::Desc => f.write_str("desc"),
             KeywordKind::Describe => f.write_str("describe"),
+            KeywordKind::Disable => f.write_str("disable"),
             KeywordKind::Distinct => f.write_str("distinct"),
             KeywordKind::Do => f.write_str("do"),
             KeywordKind::Drop => f.write_str("drop"),
             KeywordKind::Else => f.write_str("else"),
             KeywordKind::End => f.write_str("end"),
             KeywordKind::Engine => f.write_str("engine"),
+            KeywordKind::Except => f.write_str("except"),
             KeywordKind::Exec => f.write_str("exec"),
             KeywordKind::Execute => f.write_str("execute"),
             KeywordKind::Exists => f.write_str("exists"),
@@ -312,7 +381,7 @@ impl fmt::Display for KeywordKind {
             KeywordKind::False => f.write_str("false"),
             KeywordKind::Fetch => f.write_str("fetch"),
             KeywordKind::First => f.write_str("first"),
-            KeywordKind::FirstValue => f.write_str("firstvalue"),
+            KeywordKind::FirstValue => f.write_str("first_value"),
             KeywordKind::Float => f.write_str("float"),
             KeywordKind::Floor => f.write_str("floor"),
             KeywordKind::Following => f.write_str("following"),
@@ -321,11 +390,13 @@ impl fmt::Display for KeywordKind {
             KeywordKind::Full => f.write_str("full"),
             KeywordKind::Function => f.write_str("function"),
             KeywordKind::Getdate => f.write_str("getdate"),
+            KeywordKind::Go => f.write_str("go"),
             KeywordKind::Group => f.write_str("group"),
             KeywordKind::Having => f.write_str("having"),
             KeywordKind::Hour => f.write_str("hour"),
             KeywordKind::Hours => f.write_str("hours"),
             KeywordKind::Identity => f.write_str("identity"),
+            KeywordKind::Ilike => f.write_str("ilike"),
             KeywordKind::If => f.write_str("if"),
             KeywordKind::In => f.write_str("in"),
             KeywordKind::Increment => f.write_str("increment"),
@@ -337,18 +408,22 @@ impl fmt::Display for KeywordKind {
             KeywordKind::Int => f.write_str("int"),
             KeywordKind::Into => f.write_str("into"),
             KeywordKind::Is => f.write_str("is"),
+            KeywordKind::Isolation => f.write_str("isolation"),
             KeywordKind::Join => f.write_str("join"),
             KeywordKind::Key => f.write_str("key"),
             KeywordKind::Lag => f.write_str("lag"),
             KeywordKind::Last => f.write_str("last"),
-            KeywordKind::LastValue => f.write_str("lastvalue"),
+            KeywordKind::LastValue => f.write_str("last_value"),
             KeywordKind::Lead => f.write_str("lead"),
             KeywordKind::Left => f.write_str("left"),
+            KeywordKind::Level => f.write_str("level"),
             KeywordKind::Like => f.write_str("like"),
             KeywordKind::Limit => f.write_str("limit"),
             KeywordKind::Log => f.write_str("log"),
             KeywordKind::Log10 => f.write_str("log10"),
+            KeywordKind::Matched => f.write_str("matched"),
             KeywordKind::Max => f.write_str("max"),
+            KeywordKind::Merge => f.write_str("merge"),
             KeywordKind::Microsecond => f.write_str("microsecond"),
             KeywordKind::Microseconds => f.write_str("microseconds"),
             KeywordKind::Millisecond => f.write_str("millisecond"),
@@ -358,11 +433,15 @@ impl fmt::Display for KeywordKind {
             KeywordKind::Month => f.write_str("month"),
             KeywordKind::Nanosecond => f.write_str("nanosecond"),
             KeywordKind::Nanoseconds => f.write_str("nanoseconds"),
+            KeywordKind::Natural => f.write_str("natural"),
             KeywordKind::Nchar => f.write_str("nchar"),
+            KeywordKind::Newid => f.write_str("newid"),
+            KeywordKind::Newsequentialid => f.write_str("newsequentialid"),
             KeywordKind::Next => f.write_str("next"),
             KeywordKind::Not => f.write_str("not"),
             KeywordKind::Null => f.write_str("null"),
             KeywordKind::Nullif => f.write_str("nullif"),
+            KeywordKind::Nulls => f.write_str("nulls"),
             KeywordKind::Numeric => f.write_str("numeric"),
             KeywordKind::Nvarchar => f.write_str("nvarchar"),
             KeywordKind::Offset => f.write_str("offset"),
@@ -371,19 +450,26 @@ impl fmt::Display for KeywordKind {
             KeywordKind::Or => f.write_str("or"),
             KeywordKind::Order => f.write_str("order"),
             KeywordKind::Outer => f.write_str("outer"),
+            KeywordKind::Output => f.write_str("output"),
             KeywordKind::Over => f.write_str("over"),
             KeywordKind::Partition => f.write_str("partition"),
+            KeywordKind::Partitions => f.write_str("partitions"),
             KeywordKind::Password => f.write_str("password"),
             KeywordKind::Percent => f.write_str("percent"),
             KeywordKind::Pi => f.write_str("pi"),
             KeywordKind::Power => f.write_str("power"),
             KeywordKind::Preceding => f.write_str("preceding"),
+            KeywordKind::Primary => f.write_str("primary"),
             KeywordKind::Procedure => f.write_str("procedure"),
             KeywordKind::Radians => f.write_str("radians"),
-            KeywordKind::Rands => f.write_str("rands"),
+            KeywordKind::Rand => f.write_str("rand"),
             KeywordKind::Range => f.write_str("range"),
             KeywordKind::Rank => f.write_str("rank"),
+            KeywordKind::Read => f.write_str("read"),
             KeywordKind::Real => f.write_str("real"),
+            KeywordKind::Rebuild => f.write_str("rebuild"),
+            KeywordKind::Reorganize => f.write_str("reorganize"),
+            KeywordKind::Repeatable => f.write_str("repeatable"),
             KeywordKind::Return => f.write_str("return"),
             KeywordKind::Returns => f.write_str("returns"),
             KeywordKind::Revoke => f.write_str("revoke"),
@@ -394,14 +480,16 @@ impl fmt::Display for KeywordKind {
             KeywordKind::Row => f.write_str("row"),
             KeywordKind::Rowid => f.write_str("rowid"),
             KeywordKind::Rows => f.write_str("rows"),
-            KeywordKind::RowNumber => f.write_str("rownumber"),
+            KeywordKind::RowNumber => f.write_str("row_number"),
             KeywordKind::Second => f.write_str("second"),
             KeywordKind::Select => f.write_str("select"),
+            KeywordKind::Serializable => f.write_str("serializable"),
             KeywordKind::Set => f.write_str("set"),
             KeywordKind::Sign => f.write_str("sign"),
             KeywordKind::Sin => f.write_str("sin"),
             KeywordKind::Smallint => f.write_str("smallint"),
             KeywordKind::Snapshot => f.write_str("snapshot"),
+            KeywordKind::Source => f.write_str("source"),
             KeywordKind::Some => f.write_str("some"),
             KeywordKind::Sqrt => f.write_str("sqrt"),
             KeywordKind::Square => f.write_str("square"),
@@ -413,11 +501,13 @@ impl fmt::Display for KeywordKind {
             KeywordKind::Sum => f.write_str("sum"),
             KeywordKind::Table => f.write_str("table"),
             KeywordKind::Tan => f.write_str("tan"),
+            KeywordKind::Target => f.write_str("target"),
             KeywordKind::Temp => f.write_str("temp"),
             KeywordKind::Then => f.write_str("then"),
             KeywordKind::Ties => f.write_str("ties"),
             KeywordKind::Time => f.write_str("time"),
             KeywordKind::Tinyint => f.write_str("tinyint"),
+            KeywordKind::To => f.write_str("to"),
             KeywordKind::Top => f.write_str("top"),
             KeywordKind::Transaction => f.write_str("transaction"),
             KeywordKind::Trigger => f.write_str("trigger"),
@@ -432,6 +522,7 @@ impl fmt::Display for KeywordKind {
             KeywordKind::Upper => f.write_str("upper"),
             KeywordKind::Use => f.write_str("use"),
             KeywordKind::User => f.write_str("user"),
+            KeywordKind::Using => f.write_str("using"),
             KeywordKind::Uuid => f.write_str("uuid"),
             KeywordKind::Value => f.write_str("value"),
             KeywordKind::Values => f.write_str("values"),
@@ -450,6 +541,7 @@ impl fmt::Display for KeywordKind {
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum KeywordKind {
     Abs,
     Acos,
@@ -457,6 +549,7 @@ pub enum KeywordKind {
     Alter,
     And,
     Any,
+    Apply,
     As,
     Asc,
     Asin,
@@ -477,12 +570,13 @@ pub enum KeywordKind {
     Column,
     Columns,
     Commit,
-    Commited,
+    Committed,
     Constraint,
     Cos,
     Cot,
     Count,
     Create,
+    Cross,
     Current,
     Date,
     Datetime,
@@ -497,12 +591,14 @@ pub enum KeywordKind {
     DenseRank,
     Desc,
     Describe,
+    Disable,
     Distinct,
     Do,
     Drop,
     Else,
     End,
     Engine,
+    Except,
     Exec,
     Execute,
     Exists,
@@ -519,11 +615,13 @@ pub enum KeywordKind {
     Full,
     Function,
     Getdate,
+    Go,
     Group,
     Having,
     Hour,
     Hours,
     Identity,
+    Ilike,
     If,
     In,
     Increment,
@@ -535,6 +633,7 @@ pub enum KeywordKind {
     Int,
     Into,
     Is,
+    Isolation,
     Join,
     Key,
     Lag,
@@ -542,11 +641,14 @@ pub enum KeywordKind {
     LastValue,
     Lead,
     Left,
+    Level,
     Like,
     Limit,
     Log,
     Log10,
+    Matched,
     Max,
+    Merge,
     Microsecond,
     Microseconds,
     Millisecond,
@@ -556,11 +658,15 @@ pub enum KeywordKind {
     Month,
     Nanosecond,
     Nanoseconds,
+    Natural,
     Nchar,
+    Newid,
+    Newsequentialid,
     Next,
     Not,
     Null,
     Nullif,
+    Nulls,
     Numeric,
     Nvarchar,
     Offset,
@@ -569,19 +675,26 @@ pub enum KeywordKind {
     Or,
     Order,
     Outer,
+    Output,
     Over,
     Partition,
+    Partitions,
     Password,
     Percent,
     Pi,
     Power,
     Preceding,
+    Primary,
     Procedure,
     Radians,
-    Rands,
+    Rand,
     Range,
     Rank,
+    Read,
     Real,
+    Rebuild,
+    Reorganize,
+    Repeatable,
     Return,
     Returns,
     Revoke,
@@ -595,11 +708,13 @@ pub enum KeywordKind {
     RowNumber,
     Second,
     Select,
+    Serializable,
     Set,
     Sign,
     Sin,
     Smallint,
     Snapshot,
+    Source,
     Some,
     Sqrt,
     Square,
@@ -611,11 +726,13 @@ pub enum KeywordKind {
     Sum,
     Table,
     Tan,
+    Target,
     Temp,
     Then,
     Ties,
     Time,
     Tinyint,
+    To,
     Top,
     Transaction,
     Trigger,
@@ -630,6 +747,7 @@ pub enum KeywordKind {
     Upper,
     Use,
     User,
+    Using,
     Uuid,
     Value,
     Values,
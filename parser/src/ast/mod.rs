@@ -1,23 +1,32 @@
 mod data_type;
 mod expressions;
 mod keyword;
+mod spans;
 mod utils;
 
 use crate::error::{parse_error, ParseError, ParseErrorType};
 use core::fmt;
-pub use data_type::{DataType, DataTypeSize, NumericSize};
+pub use data_type::{DataType, DataTypeLength, DataTypeSize, NumericSize};
 pub use expressions::*;
 pub use keyword::{Keyword, KeywordKind};
 use lexer::{Span, Token, TokenKind};
 pub use utils::*;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SymbolKind {
     LeftParen,
     RightParen,
     Asterisk,
     Semicolon,
     EqualSign,
+    LeftBracket,
+    RightBracket,
+    PlusEqual,
+    MinusEqual,
+    DivideEqual,
+    MultiplyEqual,
+    PercentEqual,
 }
 
 impl fmt::Display for SymbolKind {
@@ -28,11 +37,19 @@ impl fmt::Display for SymbolKind {
             SymbolKind::Asterisk => f.write_str("*"),
             SymbolKind::Semicolon => f.write_str(";"),
             SymbolKind::EqualSign => f.write_str("="),
+            SymbolKind::LeftBracket => f.write_str("["),
+            SymbolKind::RightBracket => f.write_str("]"),
+            SymbolKind::PlusEqual => f.write_str("+="),
+            SymbolKind::MinusEqual => f.write_str("-="),
+            SymbolKind::DivideEqual => f.write_str("/="),
+            SymbolKind::MultiplyEqual => f.write_str("*="),
+            SymbolKind::PercentEqual => f.write_str("%="),
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Symbol {
     pub kind: SymbolKind,
     pub location: Span,
@@ -52,6 +69,13 @@ impl<'a> From<Token<'a>> for Symbol {
             &TokenKind::RightParen => SymbolKind::RightParen,
             &TokenKind::SemiColon => SymbolKind::Semicolon,
             &TokenKind::Equal => SymbolKind::EqualSign,
+            &TokenKind::LeftBracket => SymbolKind::LeftBracket,
+            &TokenKind::RightBracket => SymbolKind::RightBracket,
+            &TokenKind::PlusEqual => SymbolKind::PlusEqual,
+            &TokenKind::MinusEqual => SymbolKind::MinusEqual,
+            &TokenKind::DivideEqual => SymbolKind::DivideEqual,
+            &TokenKind::MultiplyEqual => SymbolKind::MultiplyEqual,
+            &TokenKind::PercentEqual => SymbolKind::PercentEqual,
             _ => unreachable!(),
         };
         Symbol {
@@ -62,12 +86,14 @@ impl<'a> From<Token<'a>> for Symbol {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Comment {
     pub content: String,
     pub span: Span,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CommonTableExpression {
     pub name: Expression,
     pub columns: Option<ExpressionList>,
@@ -78,6 +104,7 @@ pub struct CommonTableExpression {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExpressionList {
     pub left_paren: Symbol,
     pub items: Vec<Expression>,
@@ -85,6 +112,7 @@ pub struct ExpressionList {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CommonTableExpressionStatement {
     Select(SelectStatement),
     Insert(InsertStatement),
@@ -93,11 +121,13 @@ pub enum CommonTableExpressionStatement {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Statement {
     Select(SelectStatement),
     Insert(InsertStatement),
     Update(UpdateStatement),
     Delete(DeleteStatement),
+    Merge(MergeStatement),
     CTE {
         with_kw: Keyword,
         ctes: Vec<CommonTableExpression>,
@@ -113,37 +143,70 @@ pub enum Statement {
         equal_sign: Symbol,
         value: Expression,
     },
+    SetIsolationLevel {
+        set_kw: Keyword,
+        transaction_isolation_level_kws: Vec<Keyword>,
+        level: IsolationLevel,
+        level_kws: Vec<Keyword>,
+    },
     Execute {
         exec_kw: Keyword,
         procedure_name: Expression,
         parameters: Vec<ProcedureParameter>,
     },
-    Union {
-        select: SelectStatement,
-        unions: Vec<Union>,
+    SetOperation {
+        left: Box<Statement>,
+        op: Keyword,
+        all: Option<Keyword>,
+        right: Box<Statement>,
+    },
+    CreateTable {
+        create_kw: Keyword,
+        table_kw: Keyword,
+        name: Expression,
+        left_paren: Symbol,
+        columns: Vec<ColumnDef>,
+        right_paren: Symbol,
+    },
+    Truncate {
+        truncate_kw: Keyword,
+        table_kw: Keyword,
+        table: Expression,
+        partitions: Option<TruncatePartitions>,
+    },
+    AlterIndex {
+        alter_kw: Keyword,
+        index_kw: Keyword,
+        // either the name of the index or the all keyword
+        name: Expression,
+        on_kw: Keyword,
+        table: Expression,
+        action: AlterIndexAction,
+    },
+    // GO is not sql, it tells the client where one batch ends and the next
+    // begins, optionally running the batch before it `count` times
+    BatchSeparator {
+        go_kw: Keyword,
+        count: Option<Expression>,
     },
 }
 
 #[derive(Debug, PartialEq, Clone)]
-pub struct Union {
-    pub union_kw: Keyword,
-    pub all_kw: Option<Keyword>,
-    pub select: SelectStatement,
-}
-
-#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProcedureParameter {
     pub name: Option<(ProcedureParameterName, Symbol)>,
     pub value: Expression,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProcedureParameterName {
     pub location: Span,
     pub content: String,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LocalVariable {
     pub name: Expression,
     pub data_type: DataType,
@@ -151,6 +214,77 @@ pub struct LocalVariable {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColumnDef {
+    pub name: Expression,
+    pub data_type: DataType,
+    pub constraints: Vec<ColumnConstraint>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColumnConstraint {
+    Null(Keyword),
+    NotNull {
+        not_kw: Keyword,
+        null_kw: Keyword,
+    },
+    PrimaryKey {
+        primary_kw: Keyword,
+        key_kw: Keyword,
+    },
+    Identity {
+        identity_kw: Keyword,
+        seed_increment: Option<IdentitySeedIncrement>,
+    },
+    Default {
+        default_kw: Keyword,
+        value: Expression,
+    },
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IdentitySeedIncrement {
+    pub left_paren: Symbol,
+    pub seed: Expression,
+    pub increment: Expression,
+    pub right_paren: Symbol,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TruncatePartitions {
+    pub with_kw: Keyword,
+    pub left_paren: Symbol,
+    pub partitions_kw: Keyword,
+    pub partitions_left_paren: Symbol,
+    pub partitions: Vec<PartitionRange>,
+    pub partitions_right_paren: Symbol,
+    pub right_paren: Symbol,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PartitionRange {
+    Single(Expression),
+    Range {
+        start: Expression,
+        to_kw: Keyword,
+        end: Expression,
+    },
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AlterIndexAction {
+    Rebuild(Keyword),
+    Reorganize(Keyword),
+    Disable(Keyword),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Query {
     pub statements: Vec<Statement>,
 }
@@ -161,11 +295,22 @@ impl Query {
             statements: Vec::new(),
         }
     }
+
+    /// splits the statements into the batches separated by GO, leaving out
+    /// the separators themselves and any batch with nothing in it
+    pub fn batches(&self) -> Vec<&[Statement]> {
+        self.statements
+            .split(|statement| matches!(statement, Statement::BatchSeparator { .. }))
+            .filter(|batch| !batch.is_empty())
+            .collect()
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SelectItem {
     Wildcard(Symbol),
+    QualifiedWildcard(Expression),
     Unnamed(Expression),
     WithAlias {
         expression: Expression,
@@ -184,9 +329,11 @@ pub enum SelectItem {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InsertStatement {
     Values {
         insert_kw: Keyword,
+        insert_top: Option<Top>,
         into_kw: Option<Keyword>,
         object: Expression,
         columns: Option<ExpressionList>,
@@ -195,6 +342,7 @@ pub enum InsertStatement {
     },
     Table {
         insert_kw: Keyword,
+        insert_top: Option<Top>,
         into_kw: Option<Keyword>,
         object: Expression,
         select_kw: Keyword,
@@ -206,25 +354,103 @@ pub enum InsertStatement {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateStatement {
+    pub update_kw: Keyword,
     pub top: Option<Top>,
     pub table: Expression,
+    pub set_kw: Keyword,
     pub update_columns: Vec<Expression>,
+    pub output: Option<OutputClause>,
     pub from: Option<TableArg>,
-    pub where_clause: Option<Expression>,
+    pub where_clause: Option<WhereClause>,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeleteStatement {
+    pub delete_kw: Keyword,
     pub top: Option<Top>,
     pub table: TableArg,
-    pub where_clause: Option<Expression>,
+    pub output: Option<OutputClause>,
+    pub where_clause: Option<WhereClause>,
+}
+
+// the rows changed by an UPDATE, DELETE or MERGE, read from the inserted and
+// deleted pseudo tables
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OutputClause {
+    pub output_kw: Keyword,
+    pub columns: Vec<SelectItem>,
+    pub into_table: Option<OutputInto>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OutputInto {
+    pub into_kw: Keyword,
+    pub table: Expression,
+    pub columns: Option<ExpressionList>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MergeStatement {
+    pub merge_kw: Keyword,
+    pub top: Option<Top>,
+    pub into_kw: Option<Keyword>,
+    pub target: TableSource,
+    pub using_kw: Keyword,
+    pub source: TableSource,
+    pub on_kw: Keyword,
+    pub condition: Expression,
+    pub clauses: Vec<MergeClause>,
+    pub output: Option<OutputClause>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MergeClause {
+    // WHEN [NOT] MATCHED [BY TARGET | BY SOURCE]
+    pub when_kws: Vec<Keyword>,
+    pub match_kind: MergeMatchKind,
+    pub condition: Option<(Keyword, Expression)>,
+    pub then_kw: Keyword,
+    pub action: MergeAction,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MergeMatchKind {
+    Matched,
+    NotMatchedByTarget,
+    NotMatchedBySource,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MergeAction {
+    Update {
+        update_kw: Keyword,
+        set_kw: Keyword,
+        update_columns: Vec<Expression>,
+    },
+    Delete(Keyword),
+    Insert {
+        insert_kw: Keyword,
+        columns: Option<ExpressionList>,
+        values_kw: Keyword,
+        values: ExpressionList,
+    },
 }
 
 #[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SelectStatement {
     pub select: Keyword,
     pub distinct: Option<Keyword>,
+    pub distinct_on: Option<(Keyword, ExpressionList)>,
     pub all: Option<Keyword>,
     pub top: Option<Top>,
     pub columns: Vec<SelectItem>,
@@ -234,6 +460,7 @@ pub struct SelectStatement {
     pub group_by: Option<GroupByClause>,
     pub having: Option<HavingClause>,
     pub order_by: Option<OrderByClause>,
+    pub limit: Option<LimitClause>,
 }
 
 impl SelectStatement {
@@ -243,6 +470,7 @@ impl SelectStatement {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Top {
     pub top: Keyword,
     pub with_ties: Option<Vec<Keyword>>,
@@ -251,18 +479,21 @@ pub struct Top {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WhereClause {
     pub where_kw: Keyword,
     pub expression: Expression,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GroupByClause {
     pub group_by_kws: Vec<Keyword>,
     pub expressions: Vec<Expression>,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OrderByClause {
     pub order_by_kws: Vec<Keyword>,
     pub expressions: Vec<OrderByArg>,
@@ -270,24 +501,37 @@ pub struct OrderByClause {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LimitClause {
+    pub limit_kw: Keyword,
+    pub value: Expression,
+    pub offset: Option<(Keyword, Expression)>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OffsetFetchClause {
     pub offset: OffsetArg,
     pub fetch: Option<FetchArg>,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HavingClause {
     pub having_kw: Keyword,
     pub expression: Expression,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IntoArg {
+    pub into_kw: Keyword,
     pub table: Expression,
-    pub file_group: Option<Expression>,
+    pub file_group: Option<(Keyword, Expression)>,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TableSource {
     Table {
         name: Expression,
@@ -295,15 +539,35 @@ pub enum TableSource {
     },
     Derived {
         query: Expression,
+        as_kw: Option<Keyword>,
         alias: Expression,
     },
     TableValuedFunction {
         function: Expression,
         alias: Option<Expression>,
     },
+    // joins wrapped in parentheses so they are joined before the tables
+    // around them, like the (b JOIN c ON ...) in a JOIN (b JOIN c ON ...) ON ...
+    JoinGroup {
+        left_paren: Symbol,
+        table: Box<TableSource>,
+        joins: Vec<Join>,
+        right_paren: Symbol,
+    },
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IsolationLevel {
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Snapshot,
+    Serializable,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum JoinType {
     Inner,
     Left,
@@ -312,9 +576,18 @@ pub enum JoinType {
     RightOuter,
     Full,
     FullOuter,
+    CrossApply,
+    OuterApply,
+    // natural joins match on the columns both tables share so they never
+    // have an ON condition
+    Natural,
+    NaturalLeft,
+    NaturalRight,
+    NaturalFull,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Join {
     pub join: Vec<Keyword>,
     pub join_type: JoinType,
@@ -323,12 +596,14 @@ pub struct Join {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct JoinCondition {
     pub on_kw: Keyword,
     pub condition: Expression,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableArg {
     pub from: Keyword,
     pub table: TableSource,
@@ -336,6 +611,7 @@ pub struct TableArg {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OffsetArg {
     pub offset_kw: Keyword,
     pub value: Expression,
@@ -345,12 +621,14 @@ pub struct OffsetArg {
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RowOrRows {
     Row,
     Rows,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FetchArg {
     pub fetch_kw: Keyword,
     pub value: Expression,
@@ -364,6 +642,7 @@ pub struct FetchArg {
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NextOrFirst {
     Next,
     First,
@@ -428,6 +707,17 @@ impl fmt::Display for Statement {
                 equal_sign,
                 value,
             } => write!(f, "{} {} {} {}", set_kw, name, equal_sign, value),
+            Statement::SetIsolationLevel {
+                set_kw,
+                transaction_isolation_level_kws,
+                level_kws,
+                ..
+            } => {
+                write!(f, "{} ", set_kw)?;
+                display_list_delimiter_separated(transaction_isolation_level_kws, " ", f)?;
+                f.write_str(" ")?;
+                display_list_delimiter_separated(level_kws, " ", f)
+            }
             Statement::Execute {
                 exec_kw,
                 procedure_name,
@@ -439,39 +729,156 @@ impl fmt::Display for Statement {
             Statement::Insert(insert) => write!(f, "{}", insert),
             Statement::Update(update) => write!(f, "{}", update),
             Statement::Delete(delete) => write!(f, "{}", delete),
-            Statement::Union { select, unions } => {
-                write!(f, "{}", select)?;
-                for union in unions.iter() {
-                    write!(f, "\n{}", union)?;
+            Statement::Merge(merge) => write!(f, "{}", merge),
+            Statement::CreateTable {
+                create_kw,
+                table_kw,
+                name,
+                left_paren,
+                columns,
+                right_paren,
+            } => {
+                write!(f, "{} {} {} {}", create_kw, table_kw, name, left_paren)?;
+                display_list_comma_separated(columns, f)?;
+                write!(f, "{}", right_paren)
+            }
+            Statement::Truncate {
+                truncate_kw,
+                table_kw,
+                table,
+                partitions,
+            } => {
+                write!(f, "{} {} {}", truncate_kw, table_kw, table)?;
+                if let Some(partitions) = partitions {
+                    write!(f, " {}", partitions)?;
+                }
+                Ok(())
+            }
+            Statement::AlterIndex {
+                alter_kw,
+                index_kw,
+                name,
+                on_kw,
+                table,
+                action,
+            } => write!(
+                f,
+                "{} {} {} {} {} {}",
+                alter_kw, index_kw, name, on_kw, table, action
+            ),
+            Statement::BatchSeparator { go_kw, count } => {
+                write!(f, "{}", go_kw)?;
+                if let Some(count) = count {
+                    write!(f, " {}", count)?;
                 }
-
                 Ok(())
             }
+            Statement::SetOperation {
+                left,
+                op,
+                all,
+                right,
+            } => {
+                write!(f, "{} {}", left, op)?;
+                if let Some(all) = all {
+                    write!(f, " {}", all)?;
+                }
+                write!(f, " {}", right)
+            }
         }
     }
 }
 
-impl fmt::Display for Union {
+impl fmt::Display for LocalVariable {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.union_kw)?;
-        if let Some(kw) = &self.all_kw {
-            write!(f, " {}", kw)?;
+        write!(f, "{} {}", self.name, self.data_type)?;
+        if let Some(value) = &self.value {
+            write!(f, " {} {}", value.0, value.1)?;
         }
-        write!(f, "{}", self.select)
+
+        Ok(())
     }
 }
 
-impl fmt::Display for LocalVariable {
+impl fmt::Display for ColumnDef {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{} {}", self.name, self.data_type)?;
-        if let Some(value) = &self.value {
-            write!(f, " {} {}", value.0, value.1)?;
+        for constraint in &self.constraints {
+            write!(f, " {}", constraint)?;
         }
 
         Ok(())
     }
 }
 
+impl fmt::Display for ColumnConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ColumnConstraint::Null(null_kw) => write!(f, "{}", null_kw),
+            ColumnConstraint::NotNull { not_kw, null_kw } => write!(f, "{} {}", not_kw, null_kw),
+            ColumnConstraint::PrimaryKey { primary_kw, key_kw } => {
+                write!(f, "{} {}", primary_kw, key_kw)
+            }
+            ColumnConstraint::Identity {
+                identity_kw,
+                seed_increment,
+            } => {
+                write!(f, "{}", identity_kw)?;
+                if let Some(seed_increment) = seed_increment {
+                    write!(f, "{}", seed_increment)?;
+                }
+                Ok(())
+            }
+            ColumnConstraint::Default { default_kw, value } => {
+                write!(f, "{} {}", default_kw, value)
+            }
+        }
+    }
+}
+
+impl fmt::Display for IdentitySeedIncrement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}{}, {}{}",
+            self.left_paren, self.seed, self.increment, self.right_paren
+        )
+    }
+}
+
+impl fmt::Display for TruncatePartitions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {}{} {}",
+            self.with_kw, self.left_paren, self.partitions_kw, self.partitions_left_paren
+        )?;
+        display_list_comma_separated(&self.partitions, f)?;
+        write!(f, "{}{}", self.partitions_right_paren, self.right_paren)
+    }
+}
+
+impl fmt::Display for PartitionRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PartitionRange::Single(partition) => write!(f, "{}", partition),
+            PartitionRange::Range { start, to_kw, end } => {
+                write!(f, "{} {} {}", start, to_kw, end)
+            }
+        }
+    }
+}
+
+impl fmt::Display for AlterIndexAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AlterIndexAction::Rebuild(kw)
+            | AlterIndexAction::Reorganize(kw)
+            | AlterIndexAction::Disable(kw) => write!(f, "{}", kw),
+        }
+    }
+}
+
 impl fmt::Display for ProcedureParameter {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if let Some(name) = &self.name {
@@ -529,7 +936,9 @@ impl fmt::Display for SelectItem {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match &self {
             SelectItem::Wildcard(s) => write!(f, "{}", s),
-            SelectItem::Unnamed(expr) => write!(f, "{}", expr),
+            SelectItem::QualifiedWildcard(expr) | SelectItem::Unnamed(expr) => {
+                write!(f, "{}", expr)
+            }
             SelectItem::WithAlias {
                 expression,
                 as_kw,
@@ -575,6 +984,7 @@ impl fmt::Display for InsertStatement {
         match self {
             InsertStatement::Values {
                 insert_kw,
+                insert_top,
                 into_kw,
                 object,
                 columns,
@@ -582,6 +992,9 @@ impl fmt::Display for InsertStatement {
                 values,
             } => {
                 write!(f, "{}", insert_kw)?;
+                if let Some(insert_top) = insert_top {
+                    write!(f, " {}", insert_top)?;
+                }
                 if let Some(into_kw) = into_kw {
                     write!(f, " {}", into_kw)?;
                 }
@@ -598,6 +1011,7 @@ impl fmt::Display for InsertStatement {
             }
             InsertStatement::Table {
                 insert_kw,
+                insert_top,
                 into_kw,
                 object,
                 select_kw,
@@ -607,6 +1021,9 @@ impl fmt::Display for InsertStatement {
                 where_clause,
             } => {
                 write!(f, "{}", insert_kw)?;
+                if let Some(insert_top) = insert_top {
+                    write!(f, " {}", insert_top)?;
+                }
                 if let Some(into_kw) = into_kw {
                     write!(f, " {}", into_kw)?;
                 }
@@ -629,40 +1046,125 @@ impl fmt::Display for InsertStatement {
 
 impl fmt::Display for DeleteStatement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "DELETE ")?;
+        write!(f, "{} ", self.delete_kw)?;
         if let Some(top) = &self.top {
             write!(f, "{} ", top)?;
         }
-        write!(f, "FROM {} ", self.table)?;
+        write!(f, "{}", self.table)?;
+        if let Some(output) = &self.output {
+            write!(f, " {}", output)?;
+        }
         if let Some(where_clause) = &self.where_clause {
-            write!(f, " WHERE {}", where_clause)?;
+            write!(f, " {}", where_clause)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for OutputClause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ", self.output_kw)?;
+        display_list_comma_separated(&self.columns, f)?;
+        if let Some(into_table) = &self.into_table {
+            write!(f, " {} {}", into_table.into_kw, into_table.table)?;
+            if let Some(columns) = &into_table.columns {
+                write!(f, " {}", columns)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for MergeStatement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.merge_kw)?;
+        if let Some(top) = &self.top {
+            write!(f, " {}", top)?;
+        }
+        if let Some(into_kw) = &self.into_kw {
+            write!(f, " {}", into_kw)?;
+        }
+        write!(
+            f,
+            " {} {} {} {} {}",
+            self.target, self.using_kw, self.source, self.on_kw, self.condition
+        )?;
+        for clause in &self.clauses {
+            write!(f, " {}", clause)?;
+        }
+        if let Some(output) = &self.output {
+            write!(f, " {}", output)?;
         }
 
         Ok(())
     }
 }
 
+impl fmt::Display for MergeClause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        display_list_delimiter_separated(&self.when_kws, " ", f)?;
+        if let Some((and_kw, condition)) = &self.condition {
+            write!(f, " {} {}", and_kw, condition)?;
+        }
+        write!(f, " {} {}", self.then_kw, self.action)
+    }
+}
+
+impl fmt::Display for MergeAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MergeAction::Update {
+                update_kw,
+                set_kw,
+                update_columns,
+            } => {
+                write!(f, "{} {} ", update_kw, set_kw)?;
+                display_list_comma_separated(update_columns, f)
+            }
+            MergeAction::Delete(delete_kw) => write!(f, "{}", delete_kw),
+            MergeAction::Insert {
+                insert_kw,
+                columns,
+                values_kw,
+                values,
+            } => {
+                write!(f, "{}", insert_kw)?;
+                if let Some(columns) = columns {
+                    write!(f, " {}", columns)?;
+                }
+                write!(f, " {} {}", values_kw, values)
+            }
+        }
+    }
+}
+
 impl fmt::Display for UpdateStatement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "UPDATE ")?;
+        write!(f, "{} ", self.update_kw)?;
         if let Some(top) = &self.top {
             write!(f, "{} ", top)?;
         }
-        write!(f, "{} ", self.table)?;
-        f.write_str("SET ")?;
+        write!(f, "{} {} ", self.table, self.set_kw)?;
 
         if !self.update_columns.is_empty() {
             display_list_comma_separated(&self.update_columns, f)?;
         }
 
+        // OUTPUT
+        if let Some(output) = &self.output {
+            write!(f, " {}", output)?;
+        }
+
         // FROM
         if let Some(from_table) = &self.from {
-            write!(f, " FROM {}", from_table)?;
+            write!(f, " {}", from_table)?;
         }
 
         // WHERE
         if let Some(where_clause) = &self.where_clause {
-            write!(f, " WHERE {}", where_clause)?;
+            write!(f, " {}", where_clause)?;
         }
 
         Ok(())
@@ -679,6 +1181,11 @@ impl fmt::Display for SelectStatement {
             write!(f, " {}", distinct)?;
         }
 
+        // ON
+        if let Some(distinct_on) = &self.distinct_on {
+            write!(f, " {} {}", distinct_on.0, distinct_on.1)?;
+        }
+
         // ALL
         if let Some(distinct) = &self.all {
             write!(f, " {}", distinct)?;
@@ -696,7 +1203,7 @@ impl fmt::Display for SelectStatement {
         }
 
         if let Some(into_table) = &self.into_table {
-            write!(f, " INTO {} ", into_table)?;
+            write!(f, " {}", into_table)?;
         }
 
         // FROM
@@ -737,6 +1244,11 @@ impl fmt::Display for SelectStatement {
             }
         }
 
+        // LIMIT
+        if let Some(limit_clause) = &self.limit {
+            write!(f, " {}", limit_clause)?;
+        }
+
         Ok(())
     }
 }
@@ -764,6 +1276,17 @@ impl fmt::Display for WhereClause {
     }
 }
 
+impl fmt::Display for LimitClause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.limit_kw, self.value)?;
+        if let Some(offset) = &self.offset {
+            write!(f, " {} {}", offset.0, offset.1)?;
+        }
+
+        Ok(())
+    }
+}
+
 impl fmt::Display for OffsetFetchClause {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", &self.offset)?;
@@ -778,10 +1301,11 @@ impl fmt::Display for OffsetFetchClause {
 
 impl fmt::Display for IntoArg {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match &self.file_group {
-            Some(file_group) => write!(f, "INTO {} ON {}", self.table, file_group),
-            None => write!(f, "INTO {}", self.table),
+        write!(f, "{} {}", self.into_kw, self.table)?;
+        if let Some((on_kw, file_group)) = &self.file_group {
+            write!(f, " {} {}", on_kw, file_group)?;
         }
+        Ok(())
     }
 }
 
@@ -794,8 +1318,16 @@ impl fmt::Display for TableSource {
                 }
                 None => write!(f, "{}", name),
             },
-            TableSource::Derived { query, alias } => {
-                write!(f, "{} {}", query, alias)
+            TableSource::Derived {
+                query,
+                as_kw,
+                alias,
+            } => {
+                write!(f, "{}", query)?;
+                if let Some(as_kw) = as_kw {
+                    write!(f, " {}", as_kw)?;
+                }
+                write!(f, " {}", alias)
             }
             TableSource::TableValuedFunction { function, alias } => {
                 write!(f, "{}", function)?;
@@ -805,6 +1337,18 @@ impl fmt::Display for TableSource {
 
                 Ok(())
             }
+            TableSource::JoinGroup {
+                left_paren,
+                table,
+                joins,
+                right_paren,
+            } => {
+                write!(f, "{}{}", left_paren, table)?;
+                for join in joins {
+                    write!(f, " {}", join)?;
+                }
+                write!(f, "{}", right_paren)
+            }
         }
     }
 }
@@ -819,6 +1363,12 @@ impl fmt::Display for JoinType {
             JoinType::RightOuter => write!(f, "RIGHT JOIN OUTER"),
             JoinType::Full => write!(f, "FULL JOIN "),
             JoinType::FullOuter => write!(f, "FULL JOIN OUTER"),
+            JoinType::CrossApply => write!(f, "CROSS APPLY"),
+            JoinType::OuterApply => write!(f, "OUTER APPLY"),
+            JoinType::Natural => write!(f, "NATURAL JOIN"),
+            JoinType::NaturalLeft => write!(f, "NATURAL LEFT JOIN"),
+            JoinType::NaturalRight => write!(f, "NATURAL RIGHT JOIN"),
+            JoinType::NaturalFull => write!(f, "NATURAL FULL JOIN"),
         }
     }
 }
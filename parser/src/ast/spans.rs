@@ -0,0 +1,38 @@
+use lexer::Span;
+
+use crate::ast::{Expression, SelectStatement};
+use crate::visitor_mut::VisitorMut;
+
+// resets every span so only the structure and content of a node is left to
+// compare
+struct ClearSpans;
+
+impl VisitorMut for ClearSpans {
+    type Result = ();
+
+    fn visit_span(&mut self, span: &mut Span) {
+        *span = Span::default();
+    }
+}
+
+impl Expression {
+    /// Compares two expressions by structure and content, ignoring where in
+    /// the input each of them was parsed from.
+    pub fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        let (mut left, mut right) = (self.clone(), other.clone());
+        ClearSpans.visit_expression(&mut left);
+        ClearSpans.visit_expression(&mut right);
+        left == right
+    }
+}
+
+impl SelectStatement {
+    /// Compares two select statements by structure and content, ignoring where
+    /// in the input each of them was parsed from.
+    pub fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        let (mut left, mut right) = (self.clone(), other.clone());
+        ClearSpans.visit_select_statement(&mut left);
+        ClearSpans.visit_select_statement(&mut right);
+        left == right
+    }
+}
@@ -0,0 +1,19 @@
+use core::fmt;
+
+/// The flavor of SQL the parser accepts. `TSql` only allows syntax that SQL Server
+/// understands while `Generic` also accepts constructs borrowed from other databases.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Dialect {
+    #[default]
+    TSql,
+    Generic,
+}
+
+impl fmt::Display for Dialect {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Dialect::TSql => f.write_str("T-SQL"),
+            Dialect::Generic => f.write_str("generic"),
+        }
+    }
+}
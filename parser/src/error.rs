@@ -1,4 +1,6 @@
-use lexer::{LexicalError, Span, TokenKind};
+use lexer::{lookup_keyword, LexicalError, Span, TokenKind};
+
+use crate::dialect::Dialect;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct ParseError<'a> {
@@ -12,7 +14,9 @@ pub enum ParseErrorType<'a> {
         token: TokenKind<'a>,
         expected: Vec<String>,
     },
-    UnrecognizedEof,
+    UnrecognizedEof {
+        expected: Vec<String>,
+    },
     ExpectedKeyword,
     ExpectedFunctionName,
     EmptySelectColumns,
@@ -27,16 +31,54 @@ pub enum ParseErrorType<'a> {
     ExpectedSubqueryOrExpressionList,
     MissingRowsOrRangeInWindowFrameClause,
     MissingAliasAfterAsKeyword,
+    MissingDerivedTableAlias,
+    AliasOnQualifiedWildcard,
     ExpectedUnboundedPrecedingCurrentRowOrNumberPreceding,
     ExpectedUnboundedFollowingCurrentRowOrNumberFollowing,
     ExpectedLocalVariable,
+    MultipleVariablesInSet,
     ExpectedObjectToInsertTo,
+    ExpectedColumnName,
+    ExpectedIndexName,
+    IntoWithSetOperation,
+    OffsetRequiresOrderBy,
+    NaturalJoinWithCondition,
+    WildcardAsOperand,
+    TopPercentOutOfRange,
+    NegativeTopQuantity,
+    TrailingComma,
+    ExpressionTooDeeplyNested {
+        max_depth: usize,
+    },
+    AsteriskWithOtherFunctionArgs,
+    WrongNumberOfFunctionArgs {
+        function: String,
+        min: usize,
+        max: usize,
+        found: usize,
+    },
     InvalidOrUnimplementedStatement,
+    NotSupportedInDialect {
+        feature: String,
+        dialect: Dialect,
+    },
     LexerError {
         error: LexicalError,
     },
 }
 
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseWarning {
+    pub warning: ParseWarningType,
+    pub span: Span,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseWarningType {
+    CteColumnCountMismatch { expected: usize, found: usize },
+    OrderByInSubqueryWithoutTop,
+}
+
 pub fn parse_error<T>(error: ParseErrorType, span: Span) -> Result<T, ParseError> {
     Err(ParseError { error, span })
 }
@@ -48,25 +90,100 @@ pub fn parse_lexical_error<'a>(error: LexicalError) -> ParseError<'a> {
     }
 }
 
-impl<'a> ParseError<'a> {
-    pub fn location(&self, input: &'a str) -> String {
-        let mut line_number: u32 = 1;
-        let mut column_number: u32 = 1;
+fn span_location(input: &str, span: Span) -> String {
+    let mut line_number: u32 = 1;
+    let mut column_number: u32 = 1;
 
-        for (i, c) in input.char_indices() {
-            if i == self.span.start as usize {
-                break;
-            }
+    for (i, c) in input.char_indices() {
+        if i == span.start as usize {
+            break;
+        }
+
+        if c == '\n' {
+            line_number += 1;
+            column_number = 1;
+        } else {
+            column_number += 1;
+        }
+    }
+
+    format!("line: {} col: {}", line_number, column_number)
+}
+
+// the line of input holding the start of the span with carets under the part
+// of that line the span covers
+fn span_pointer(input: &str, span: Span) -> String {
+    let start = (span.start as usize).min(input.len());
+    let line_start = input[..start].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = input[start..].find('\n').map_or(input.len(), |i| start + i);
+    let line = input[line_start..line_end].trim_end_matches('\r');
+
+    // the span end is the start of its last char so step over that char, a
+    // span running onto the next lines is only underlined up to the line end
+    let end = (span.end as usize).max(start);
+    let token_end = input
+        .get(end..)
+        .and_then(|rest| rest.chars().next())
+        .map_or(input.len(), |c| end + c.len_utf8())
+        .min(line_start + line.len())
+        .max(start);
+    let width = input[start..token_end].chars().count().max(1);
+
+    // keep tabs so the carets line up however wide the tabs are shown
+    let padding: String = input[line_start..start]
+        .chars()
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+
+    format!("{}\n{}{}", line, padding, "^".repeat(width))
+}
+
+// each expected item on its own line below the message
+fn expected_list(expected: &[String]) -> String {
+    expected.iter().map(|s| format!("\n- {}", s)).collect()
+}
 
-            if c == '\n' {
-                line_number += 1;
-                column_number = 1;
-            } else {
-                column_number += 1;
+// describes a lone expected item the way the found token is described, so
+// `by` reads as "the keyword BY" and `expression` as "an expression"
+fn describe_expected(expected: &str) -> String {
+    if lookup_keyword(expected).is_some() {
+        format!("the keyword {}", expected.to_uppercase())
+    } else if !expected.chars().any(char::is_alphanumeric) {
+        format!("`{}`", expected)
+    } else if expected.starts_with(['a', 'e', 'i', 'o', 'u']) {
+        format!("an {}", expected)
+    } else {
+        format!("a {}", expected)
+    }
+}
+
+impl ParseWarning {
+    pub fn location(&self, input: &str) -> String {
+        span_location(input, self.span)
+    }
+
+    pub fn details(&self) -> String {
+        match &self.warning {
+            ParseWarningType::CteColumnCountMismatch { expected, found } => {
+                format!("I expected the common table expression to select {expected} column(s) but found {found}")
+            }
+            ParseWarningType::OrderByInSubqueryWithoutTop => {
+                "I expected TOP or OFFSET with this ORDER BY, a subquery can't be ordered otherwise"
+                    .into()
             }
         }
+    }
+}
 
-        format!("line: {} col: {}", line_number, column_number)
+impl<'a> ParseError<'a> {
+    pub fn location(&self, input: &'a str) -> String {
+        span_location(input, self.span)
+    }
+
+    /// Shows the line of `input` the error is on with `^` under the tokens
+    /// that caused it.
+    pub fn render(&self, input: &str) -> String {
+        span_pointer(input, self.span)
     }
 
     pub fn details(&self) -> String {
@@ -79,7 +196,7 @@ impl<'a> ParseError<'a> {
                     TokenKind::NumberLiteral(_) => "a number".into(),
                     TokenKind::LocalVariable(_) => "a local variable".into(),
                     TokenKind::Comment(_) => "a comment".into(),
-                    TokenKind::Eof => "end of file".into(),
+                    TokenKind::Eof => "the end of the input".into(),
                     TokenKind::Comma
                     | TokenKind::LeftParen
                     | TokenKind::RightParen
@@ -96,29 +213,53 @@ impl<'a> ParseError<'a> {
                     | TokenKind::Asterisk
                     | TokenKind::PercentSign
                     | TokenKind::Period
-                    | TokenKind::SemiColon => token.to_string(),
-                    _ => format!("the keyword {}", token),
+                    | TokenKind::SemiColon
+                    | TokenKind::LeftBracket
+                    | TokenKind::RightBracket
+                    | TokenKind::LeftBrace
+                    | TokenKind::RightBrace
+                    | TokenKind::PlusEqual
+                    | TokenKind::MinusEqual
+                    | TokenKind::DivideEqual
+                    | TokenKind::MultiplyEqual
+                    | TokenKind::PercentEqual => format!("`{}`", token),
+                    _ => format!("the keyword {}", token.to_string().to_uppercase()),
                 };
-                let message = std::iter::once(format!(
-                    "I was not expecting this. Found {found}, expected one of: "
-                ))
-                .chain(expected.iter().map(|s| format!("- {} ", s)))
-                .collect();
 
-                message
+                match &expected[..] {
+                    [] => format!("I was not expecting {found} here"),
+                    [expected] => format!(
+                        "I expected {} but found {found}",
+                        describe_expected(expected)
+                    ),
+                    _ => format!(
+                        "I was not expecting {found} here, expected one of:{}",
+                        expected_list(expected)
+                    ),
+                }
             }
-            ParseErrorType::UnrecognizedEof => "I was not expecting an end of file".into(),
+            ParseErrorType::UnrecognizedEof { expected } => match &expected[..] {
+                [] => "I was not expecting the input to end here".into(),
+                [expected] => format!(
+                    "I expected {} but the input ended here",
+                    describe_expected(expected)
+                ),
+                _ => format!(
+                    "I was not expecting the input to end here, expected one of:{}",
+                    expected_list(expected)
+                ),
+            },
             ParseErrorType::ExpectedKeyword => "I was exepecting a keyword".into(),
             ParseErrorType::ExpectedFunctionName => "I expected a function name".into(),
             ParseErrorType::EmptySelectColumns => "I expected columns to select from table".into(),
             ParseErrorType::EmptyGroupByClause => "I expected a group by clause".into(),
-            ParseErrorType::EmptyPartitionByClause => "I expected a partition by clause".into(),
+            ParseErrorType::EmptyPartitionByClause => "I expected something to partition by".into(),
             ParseErrorType::ExpectedComparisonOperator => "I expected a comparison operator".into(),
             ParseErrorType::ExpectedArithmeticOperator => "I expected an arithmetic operator".into(),
             ParseErrorType::ExpectedUnaryOperator => "I expected a unary operator".into(),
             ParseErrorType::EmptyOrderByArgs => "I expected columns to order by".into(),
             ParseErrorType::ExpectedDataType => "I expected a data type".into(),
-            ParseErrorType::ExpectedDataTypeSize => "I expected a float precision".into(),
+            ParseErrorType::ExpectedDataTypeSize => "I expected a data type size".into(),
             ParseErrorType::ExpectedSubqueryOrExpressionList => {
                 "I expected subquery or expression list".into()
             }
@@ -128,6 +269,12 @@ impl<'a> ParseError<'a> {
             ParseErrorType::MissingAliasAfterAsKeyword => {
                 "I expected an alias after as keyword".into()
             }
+            ParseErrorType::AliasOnQualifiedWildcard => {
+                "I did not expect an alias after a qualified wildcard".into()
+            }
+            ParseErrorType::MissingDerivedTableAlias => {
+                "I expected an alias after the derived table".into()
+            }
             ParseErrorType::ExpectedUnboundedPrecedingCurrentRowOrNumberPreceding => {
                 "I expected unbounded preceding current row or number preceding".into()
             }
@@ -135,12 +282,62 @@ impl<'a> ParseError<'a> {
                 "I expected unbounded following current row or number following".into()
             }
             ParseErrorType::ExpectedLocalVariable => "I expected a local variable".into(),
+            ParseErrorType::MultipleVariablesInSet => {
+                "I expected SET to assign a single variable, use SELECT to assign several".into()
+            }
+            ParseErrorType::ExpressionTooDeeplyNested { max_depth } => {
+                format!("I expected expressions to nest at most {max_depth} levels deep")
+            }
+            ParseErrorType::TrailingComma => {
+                "I did not expect a comma at the end of the list".into()
+            }
+            ParseErrorType::AsteriskWithOtherFunctionArgs => {
+                "I expected `*` to be the only function argument".into()
+            }
+            ParseErrorType::WrongNumberOfFunctionArgs {
+                function,
+                min,
+                max,
+                found,
+            } => {
+                let expected = if min == max {
+                    min.to_string()
+                } else {
+                    format!("{min} to {max}")
+                };
+                format!("I expected {expected} argument(s) for {function} but found {found}")
+            }
+            ParseErrorType::IntoWithSetOperation => {
+                "I did not expect INTO in a query combined with UNION, EXCEPT or INTERSECT".into()
+            }
+            ParseErrorType::OffsetRequiresOrderBy => {
+                "I expected an ORDER BY clause before OFFSET".into()
+            }
+            ParseErrorType::NaturalJoinWithCondition => {
+                "I was not expecting an ON condition after a NATURAL JOIN".into()
+            }
+            ParseErrorType::WildcardAsOperand => {
+                "I was not expecting a wildcard here, it can only be used as a select item".into()
+            }
+            ParseErrorType::TopPercentOutOfRange => {
+                "I expected a top percent value between 0 and 100".into()
+            }
+            ParseErrorType::NegativeTopQuantity => {
+                "I expected a top value that is not negative".into()
+            }
             ParseErrorType::ExpectedObjectToInsertTo => {
                 "I expected an object to insert into".into()
             }
+            ParseErrorType::ExpectedColumnName => "I expected a column name".into(),
+            ParseErrorType::ExpectedIndexName => "I expected an index name".into(),
             ParseErrorType::InvalidOrUnimplementedStatement => {
                 "I was not expecting an invalid or a statement that is not implemented yet".into()
             }
+            ParseErrorType::NotSupportedInDialect { feature, dialect } => {
+                format!(
+                    "I was not expecting {feature}, it is not supported by the {dialect} dialect"
+                )
+            }
             ParseErrorType::LexerError { error } => error.details(),
         }
     }
@@ -0,0 +1,201 @@
+use std::cmp::Ordering;
+
+use crate::ast::{
+    ArithmeticOperatorKind, ComparisonOperatorKind, Expression, KeywordKind, UnaryOperatorKind,
+};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Value {
+    Number(f64),
+    Bool(bool),
+    Str(String),
+    Null,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EvalError {
+    ColumnReference,
+    DivisionByZero,
+    TypeMismatch,
+    InvalidNumber(String),
+    Unsupported,
+}
+
+/// Evaluates an expression that does not reference any columns, e.g.
+/// `1 + 2 * 3` or `5 BETWEEN 1 AND 10`. Null propagates through operators the
+/// way it does in sql, so `NULL = 1` is null rather than false.
+pub fn eval_scalar(expr: &Expression) -> Result<Value, EvalError> {
+    match expr {
+        Expression::NumberLiteral(l) => l
+            .content
+            .parse()
+            .map(Value::Number)
+            .map_err(|_| EvalError::InvalidNumber(l.content.clone())),
+        Expression::StringLiteral(l) => Ok(Value::Str(l.content.clone())),
+        Expression::Keyword(k) => match k.kind {
+            KeywordKind::Null => Ok(Value::Null),
+            KeywordKind::True => Ok(Value::Bool(true)),
+            KeywordKind::False => Ok(Value::Bool(false)),
+            _ => Err(EvalError::Unsupported),
+        },
+        Expression::Identifier(_)
+        | Expression::QuotedIdentifier(_)
+        | Expression::Compound(_)
+        | Expression::Asterisk(_) => Err(EvalError::ColumnReference),
+        Expression::Grouping { expression, .. } => eval_scalar(expression),
+        Expression::Unary { operator, right } => match (operator.kind, eval_scalar(right)?) {
+            (_, Value::Null) => Ok(Value::Null),
+            (UnaryOperatorKind::Plus, Value::Number(n)) => Ok(Value::Number(n)),
+            (UnaryOperatorKind::Minus, Value::Number(n)) => Ok(Value::Number(-n)),
+            _ => Err(EvalError::TypeMismatch),
+        },
+        Expression::Arithmetic {
+            operator,
+            left,
+            right,
+        } => arithmetic(operator.kind, eval_scalar(left)?, eval_scalar(right)?),
+        Expression::Comparison {
+            operator,
+            left,
+            right,
+        } => Ok(from_truth(compare(
+            operator.kind,
+            &eval_scalar(left)?,
+            &eval_scalar(right)?,
+        )?)),
+        Expression::And { left, right, .. } => {
+            let left = truth(eval_scalar(left)?)?;
+            let right = truth(eval_scalar(right)?)?;
+            Ok(from_truth(match (left, right) {
+                (Some(false), _) | (_, Some(false)) => Some(false),
+                (Some(true), Some(true)) => Some(true),
+                _ => None,
+            }))
+        }
+        Expression::Or { left, right, .. } => {
+            let left = truth(eval_scalar(left)?)?;
+            let right = truth(eval_scalar(right)?)?;
+            Ok(from_truth(match (left, right) {
+                (Some(true), _) | (_, Some(true)) => Some(true),
+                (Some(false), Some(false)) => Some(false),
+                _ => None,
+            }))
+        }
+        Expression::Not { expression, .. } => {
+            Ok(from_truth(truth(eval_scalar(expression)?)?.map(|b| !b)))
+        }
+        Expression::Between {
+            test_expression,
+            not_kw,
+            begin,
+            end,
+            ..
+        } => {
+            let value = eval_scalar(test_expression)?;
+            let above = compare(
+                ComparisonOperatorKind::GreaterThanEqual,
+                &value,
+                &eval_scalar(begin)?,
+            )?;
+            let below = compare(
+                ComparisonOperatorKind::LessThanEqual,
+                &value,
+                &eval_scalar(end)?,
+            )?;
+            let between = match (above, below) {
+                (Some(false), _) | (_, Some(false)) => Some(false),
+                (Some(true), Some(true)) => Some(true),
+                _ => None,
+            };
+            Ok(from_truth(between.map(|b| b != not_kw.is_some())))
+        }
+        Expression::InExpressionList {
+            test_expression,
+            not_kw,
+            list,
+            ..
+        } => {
+            let value = eval_scalar(test_expression)?;
+            // the result is null when nothing matches but the list has a null
+            // in it, since the null could have been equal
+            let mut found = Some(false);
+            for item in list {
+                match compare(ComparisonOperatorKind::Equal, &value, &eval_scalar(item)?)? {
+                    Some(true) => {
+                        found = Some(true);
+                        break;
+                    }
+                    Some(false) => {}
+                    None => found = None,
+                }
+            }
+            Ok(from_truth(found.map(|b| b != not_kw.is_some())))
+        }
+        _ => Err(EvalError::Unsupported),
+    }
+}
+
+fn arithmetic(
+    operator: ArithmeticOperatorKind,
+    left: Value,
+    right: Value,
+) -> Result<Value, EvalError> {
+    match (left, right) {
+        (Value::Null, _) | (_, Value::Null) => Ok(Value::Null),
+        (Value::Number(left), Value::Number(right)) => match operator {
+            ArithmeticOperatorKind::Plus => Ok(Value::Number(left + right)),
+            ArithmeticOperatorKind::Minus => Ok(Value::Number(left - right)),
+            ArithmeticOperatorKind::Multiply => Ok(Value::Number(left * right)),
+            ArithmeticOperatorKind::Divide | ArithmeticOperatorKind::Modulus if right == 0.0 => {
+                Err(EvalError::DivisionByZero)
+            }
+            ArithmeticOperatorKind::Divide => Ok(Value::Number(left / right)),
+            ArithmeticOperatorKind::Modulus => Ok(Value::Number(left % right)),
+        },
+        // + doubles as string concatenation
+        (Value::Str(left), Value::Str(right)) if operator == ArithmeticOperatorKind::Plus => {
+            Ok(Value::Str(left + &right))
+        }
+        _ => Err(EvalError::TypeMismatch),
+    }
+}
+
+// compares two values, giving none when either side is null
+fn compare(
+    operator: ComparisonOperatorKind,
+    left: &Value,
+    right: &Value,
+) -> Result<Option<bool>, EvalError> {
+    let ordering = match (left, right) {
+        (Value::Null, _) | (_, Value::Null) => return Ok(None),
+        (Value::Number(left), Value::Number(right)) => left.partial_cmp(right),
+        (Value::Str(left), Value::Str(right)) => Some(left.cmp(right)),
+        (Value::Bool(left), Value::Bool(right)) => Some(left.cmp(right)),
+        _ => None,
+    }
+    .ok_or(EvalError::TypeMismatch)?;
+
+    Ok(Some(match operator {
+        ComparisonOperatorKind::Equal => ordering == Ordering::Equal,
+        ComparisonOperatorKind::NotEqualBang | ComparisonOperatorKind::NotEqualArrow => {
+            ordering != Ordering::Equal
+        }
+        ComparisonOperatorKind::GreaterThan => ordering == Ordering::Greater,
+        ComparisonOperatorKind::GreaterThanEqual => ordering != Ordering::Less,
+        ComparisonOperatorKind::LessThan => ordering == Ordering::Less,
+        ComparisonOperatorKind::LessThanEqual => ordering != Ordering::Greater,
+    }))
+}
+
+// reads a value as a three valued boolean where none is unknown
+fn truth(value: Value) -> Result<Option<bool>, EvalError> {
+    match value {
+        Value::Bool(b) => Ok(Some(b)),
+        Value::Null => Ok(None),
+        _ => Err(EvalError::TypeMismatch),
+    }
+}
+
+fn from_truth(truth: Option<bool>) -> Value {
+    truth.map_or(Value::Null, Value::Bool)
+}
@@ -1,11 +1,31 @@
 use lexer::TokenKind;
 
+pub const STATEMENT_START: &[TokenKind<'static>] = &[
+    TokenKind::Select,
+    TokenKind::Insert,
+    TokenKind::Update,
+    TokenKind::Delete,
+    TokenKind::Merge,
+    TokenKind::With,
+    TokenKind::Declare,
+    TokenKind::Set,
+    TokenKind::Exec,
+    TokenKind::Execute,
+    TokenKind::Create,
+    TokenKind::Truncate,
+    TokenKind::Alter,
+    TokenKind::Go,
+];
+
 pub const SELECT_ITEM_TYPE_START: &'static [TokenKind<'static>] = &[
     TokenKind::Identifier(""),
     TokenKind::QuotedIdentifier(""),
     TokenKind::NumberLiteral(""),
     TokenKind::StringLiteral(""),
     TokenKind::LocalVariable(""),
+    TokenKind::Null,
+    TokenKind::True,
+    TokenKind::False,
     TokenKind::LeftParen,
     TokenKind::Case,
     TokenKind::Asterisk,
@@ -22,6 +42,9 @@ pub const EXPRESSION_LIST_START: &'static [TokenKind<'static>] = &[
     TokenKind::NumberLiteral(""),
     TokenKind::StringLiteral(""),
     TokenKind::LocalVariable(""),
+    TokenKind::Null,
+    TokenKind::True,
+    TokenKind::False,
 ];
 
 pub const BUILTIN_FN_START: &'static [TokenKind<'static>] = &[
@@ -36,6 +59,7 @@ pub const BUILTIN_FN_START: &'static [TokenKind<'static>] = &[
     TokenKind::Cos,
     TokenKind::Cot,
     TokenKind::Count,
+    TokenKind::Day,
     TokenKind::Degrees,
     TokenKind::DenseRank,
     TokenKind::Exp,
@@ -45,13 +69,19 @@ pub const BUILTIN_FN_START: &'static [TokenKind<'static>] = &[
     TokenKind::Log10,
     TokenKind::Max,
     TokenKind::Min,
+    TokenKind::Month,
+    TokenKind::Newid,
+    TokenKind::Newsequentialid,
     TokenKind::Nullif,
     TokenKind::Pi,
     TokenKind::Power,
     TokenKind::Radians,
+    TokenKind::Rand,
     TokenKind::Rank,
     TokenKind::Round,
     TokenKind::RowNumber,
+    TokenKind::Sign,
+    TokenKind::Sin,
     TokenKind::Sqrt,
     TokenKind::Square,
     TokenKind::Stage,
@@ -59,8 +89,10 @@ pub const BUILTIN_FN_START: &'static [TokenKind<'static>] = &[
     TokenKind::Stdevp,
     TokenKind::Sum,
     TokenKind::Tan,
+    TokenKind::Upper,
     TokenKind::Var,
     TokenKind::Varp,
+    TokenKind::Year,
 ];
 
 pub const ORDER_BY_ARGS_START: &'static [TokenKind<'static>] = &[
@@ -70,8 +102,17 @@ pub const ORDER_BY_ARGS_START: &'static [TokenKind<'static>] = &[
     TokenKind::LocalVariable(""),
 ];
 
-pub const PARTITION_BY_START: &'static [TokenKind<'static>] =
-    &[TokenKind::Identifier(""), TokenKind::QuotedIdentifier("")];
+pub const PARTITION_BY_START: &'static [TokenKind<'static>] = &[
+    TokenKind::Identifier(""),
+    TokenKind::QuotedIdentifier(""),
+    TokenKind::NumberLiteral(""),
+    TokenKind::StringLiteral(""),
+    TokenKind::LocalVariable(""),
+    TokenKind::LeftParen,
+    TokenKind::Case,
+    TokenKind::Minus,
+    TokenKind::Plus,
+];
 
 pub const FUNCTION_ARGS_START: &'static [TokenKind<'static>] = &[
     TokenKind::Identifier(""),
@@ -79,6 +120,13 @@ pub const FUNCTION_ARGS_START: &'static [TokenKind<'static>] = &[
     TokenKind::NumberLiteral(""),
     TokenKind::StringLiteral(""),
     TokenKind::LocalVariable(""),
+    TokenKind::Null,
+    TokenKind::True,
+    TokenKind::False,
+    TokenKind::LeftParen,
+    TokenKind::Case,
+    TokenKind::Minus,
+    TokenKind::Plus,
 ];
 
 pub const TABLE_SOURCE_START: &'static [TokenKind<'static>] = &[
@@ -88,3 +136,30 @@ pub const TABLE_SOURCE_START: &'static [TokenKind<'static>] = &[
     TokenKind::LeftParen,
 ];
 
+pub const JOIN_START: &[TokenKind<'static>] = &[
+    TokenKind::Join,
+    TokenKind::Inner,
+    TokenKind::Left,
+    TokenKind::Right,
+    TokenKind::Full,
+    TokenKind::Cross,
+    TokenKind::Outer,
+    TokenKind::Natural,
+];
+
+pub const LIST_END: &[TokenKind<'static>] = &[
+    TokenKind::RightParen,
+    TokenKind::SemiColon,
+    TokenKind::Eof,
+    TokenKind::From,
+    TokenKind::Where,
+    TokenKind::Group,
+    TokenKind::Having,
+    TokenKind::Order,
+    TokenKind::Into,
+    TokenKind::Union,
+    TokenKind::Except,
+    TokenKind::Intersect,
+    TokenKind::Offset,
+    TokenKind::Limit,
+];
@@ -0,0 +1,366 @@
+use std::fmt::Write;
+
+use lexer::Span;
+
+use crate::ast::{
+    ArithmeticOperator, CaseCondition, ColumnConstraint, ColumnDef, CommonTableExpression,
+    ComparisonOperator, DataType, DeleteStatement, Expression, ExpressionList, FunctionName,
+    GroupByClause, HavingClause, InsertStatement, IntoArg, Join, JoinCondition, Keyword,
+    LimitClause, LocalVariable, MergeClause, MergeStatement, OffsetFetchClause, OrderByArg,
+    OrderByClause, OutputClause, OverClause, Query, SelectItem, SelectStatement, Statement, Symbol,
+    TableArg, TableSource, Top, UnaryOperator, UpdateStatement, WhereClause, WindowFrame,
+};
+use crate::visitor::{
+    walk_case_condition, walk_column_constraint, walk_column_def, walk_common_table_expression,
+    walk_data_type, walk_delete_statement, walk_expression, walk_expression_list,
+    walk_function_name, walk_function_over_clause, walk_function_over_clause_window_frame,
+    walk_group_by_clause, walk_having_clause, walk_insert_statement, walk_into_arg,
+    walk_limit_clause, walk_local_variable, walk_merge_clause, walk_merge_statement,
+    walk_order_by_arg, walk_order_by_clause, walk_order_by_offset_fetch_clause, walk_output_clause,
+    walk_query, walk_select_item, walk_select_statement, walk_set_operation, walk_statement,
+    walk_table_clause, walk_table_join, walk_table_join_condition, walk_table_source,
+    walk_top_clause, walk_update_statement, walk_where_clause, Visitor,
+};
+
+/// Serializes a parsed query into a JSON tree of `{"type", "children"}` nodes.
+///
+/// Tokens (keywords, symbols, operators and literals) are emitted as leaf nodes
+/// carrying their source span, so the output can be consumed by tooling that
+/// cannot link against the AST types directly.
+#[derive(Default)]
+pub struct JsonVisitor {
+    output: String,
+    first_child: Vec<bool>,
+}
+
+impl JsonVisitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn dump(query: &Query) -> String {
+        let mut visitor = Self::new();
+        visitor.visit_query(query);
+        visitor.output
+    }
+
+    fn begin_value(&mut self) {
+        if let Some(first) = self.first_child.last_mut() {
+            if !*first {
+                self.output.push(',');
+            }
+            *first = false;
+        }
+    }
+
+    fn node(&mut self, node_type: &str, children: impl FnOnce(&mut Self)) {
+        self.begin_value();
+        let _ = write!(self.output, r#"{{"type":"{}","children":["#, node_type);
+        self.first_child.push(true);
+        children(self);
+        self.first_child.pop();
+        self.output.push_str("]}");
+    }
+
+    fn leaf(&mut self, node_type: &str, fields: &[(&str, &str)], span: &Span) {
+        self.begin_value();
+        let _ = write!(self.output, r#"{{"type":"{}""#, node_type);
+        for (name, value) in fields {
+            let _ = write!(self.output, r#","{}":"{}""#, name, escape(value));
+        }
+        let _ = write!(
+            self.output,
+            r#","span":{{"start":{},"end":{}}}}}"#,
+            span.start, span.end
+        );
+    }
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn expression_type(expr: &Expression) -> &'static str {
+    match expr {
+        Expression::Asterisk(_) => "Asterisk",
+        Expression::Identifier(_) => "Identifier",
+        Expression::QuotedIdentifier(_) => "QuotedIdentifier",
+        Expression::StringLiteral(_) => "StringLiteral",
+        Expression::NumberLiteral(_) => "NumberLiteral",
+        Expression::LocalVariable(_) => "LocalVariable",
+        Expression::Keyword(_) => "Keyword",
+        Expression::Compound(_) => "Compound",
+        Expression::Arithmetic { .. } => "Arithmetic",
+        Expression::And { .. } => "And",
+        Expression::Or { .. } => "Or",
+        Expression::Comparison { .. } => "Comparison",
+        Expression::Unary { .. } => "Unary",
+        Expression::Function { .. } => "Function",
+        Expression::Cast { .. } => "Cast",
+        Expression::InExpressionList { .. } => "InExpressionList",
+        Expression::InSubquery { .. } => "InSubquery",
+        Expression::Subquery { .. } => "Subquery",
+        Expression::Grouping { .. } => "Grouping",
+        Expression::Subscript { .. } => "Subscript",
+        Expression::Between { .. } => "Between",
+        Expression::Not { .. } => "Not",
+        Expression::Exists { .. } => "Exists",
+        Expression::All { .. } => "All",
+        Expression::Some { .. } => "Some",
+        Expression::Any { .. } => "Any",
+        Expression::Like { .. } => "Like",
+        Expression::SimpleCase { .. } => "SimpleCase",
+        Expression::SearchedCase { .. } => "SearchedCase",
+    }
+}
+
+impl Visitor for JsonVisitor {
+    type Result = ();
+
+    fn visit_query(&mut self, query: &Query) -> Self::Result {
+        self.node("Query", |v| walk_query(v, query));
+    }
+
+    fn visit_statement(&mut self, stmt: &Statement) -> Self::Result {
+        let node_type = match stmt {
+            Statement::CTE { .. } => "CommonTableExpressionStatement",
+            Statement::Declare { .. } => "DeclareStatement",
+            Statement::SetLocalVariable { .. } => "SetLocalVariableStatement",
+            Statement::SetIsolationLevel { .. } => "SetIsolationLevelStatement",
+            Statement::Execute { .. } => "ExecuteStatement",
+            Statement::CreateTable { .. } => "CreateTableStatement",
+            Statement::Truncate { .. } => "TruncateStatement",
+            Statement::AlterIndex { .. } => "AlterIndexStatement",
+            Statement::BatchSeparator { .. } => "BatchSeparator",
+            // these variants have a dedicated visit method that emits the node
+            _ => return walk_statement(self, stmt),
+        };
+        self.node(node_type, |v| walk_statement(v, stmt));
+    }
+
+    fn visit_select_statement(&mut self, stmt: &SelectStatement) -> Self::Result {
+        self.node("SelectStatement", |v| walk_select_statement(v, stmt));
+    }
+
+    fn visit_insert_statement(&mut self, stmt: &InsertStatement) -> Self::Result {
+        self.node("InsertStatement", |v| walk_insert_statement(v, stmt));
+    }
+
+    fn visit_update_statement(&mut self, stmt: &UpdateStatement) -> Self::Result {
+        self.node("UpdateStatement", |v| walk_update_statement(v, stmt));
+    }
+
+    fn visit_delete_statement(&mut self, stmt: &DeleteStatement) -> Self::Result {
+        self.node("DeleteStatement", |v| walk_delete_statement(v, stmt));
+    }
+
+    fn visit_merge_statement(&mut self, stmt: &MergeStatement) -> Self::Result {
+        self.node("MergeStatement", |v| walk_merge_statement(v, stmt));
+    }
+
+    fn visit_merge_clause(&mut self, clause: &MergeClause) -> Self::Result {
+        self.node("MergeClause", |v| walk_merge_clause(v, clause));
+    }
+
+    fn visit_output_clause(&mut self, output: &OutputClause) -> Self::Result {
+        self.node("OutputClause", |v| walk_output_clause(v, output));
+    }
+
+    fn visit_set_operation(
+        &mut self,
+        left: &Statement,
+        op: &Keyword,
+        all: &Option<Keyword>,
+        right: &Statement,
+    ) -> Self::Result {
+        self.node("SetOperation", |v| {
+            walk_set_operation(v, left, op, all, right)
+        });
+    }
+
+    fn visit_expression(&mut self, expr: &Expression) -> Self::Result {
+        match expr {
+            Expression::Identifier(l)
+            | Expression::QuotedIdentifier(l)
+            | Expression::StringLiteral(l)
+            | Expression::NumberLiteral(l)
+            | Expression::LocalVariable(l) => self.leaf(
+                expression_type(expr),
+                &[("content", &l.content)],
+                &l.location,
+            ),
+            Expression::Keyword(k) => self.visit_keyword(k),
+            _ => self.node(expression_type(expr), |v| walk_expression(v, expr)),
+        }
+    }
+
+    fn visit_keyword(&mut self, keyword: &Keyword) -> Self::Result {
+        self.leaf(
+            "Keyword",
+            &[("kind", &keyword.kind.to_string())],
+            &keyword.location,
+        );
+    }
+
+    fn visit_symbol(&mut self, symbol: &Symbol) -> Self::Result {
+        self.leaf(
+            "Symbol",
+            &[("kind", &format!("{:?}", symbol.kind))],
+            &symbol.location,
+        );
+    }
+
+    fn visit_comparison_operator(&mut self, op: &ComparisonOperator) -> Self::Result {
+        self.leaf(
+            "ComparisonOperator",
+            &[("kind", &format!("{:?}", op.kind))],
+            &op.location,
+        );
+    }
+
+    fn visit_arithmetic_operator(&mut self, op: &ArithmeticOperator) -> Self::Result {
+        self.leaf(
+            "ArithmeticOperator",
+            &[("kind", &format!("{:?}", op.kind))],
+            &op.location,
+        );
+    }
+
+    fn visit_unary_operator(&mut self, op: &UnaryOperator) -> Self::Result {
+        self.leaf(
+            "UnaryOperator",
+            &[("kind", &format!("{:?}", op.kind))],
+            &op.location,
+        );
+    }
+
+    fn visit_data_type(&mut self, data_type: &DataType) -> Self::Result {
+        self.node("DataType", |v| walk_data_type(v, data_type));
+    }
+
+    fn visit_top_clause(&mut self, top_clause: &Top) -> Self::Result {
+        self.node("Top", |v| walk_top_clause(v, top_clause));
+    }
+
+    fn visit_select_item(&mut self, select_item: &SelectItem) -> Self::Result {
+        self.node("SelectItem", |v| walk_select_item(v, select_item));
+    }
+
+    fn visit_into_arg(&mut self, into_arg: &IntoArg) -> Self::Result {
+        self.node("IntoArg", |v| walk_into_arg(v, into_arg));
+    }
+
+    fn visit_table_clause(&mut self, table_clause: &TableArg) -> Self::Result {
+        self.node("TableArg", |v| walk_table_clause(v, table_clause));
+    }
+
+    fn visit_table_source(&mut self, table_source: &TableSource) -> Self::Result {
+        self.node("TableSource", |v| walk_table_source(v, table_source));
+    }
+
+    fn visit_table_join(&mut self, table_join: &Join) -> Self::Result {
+        self.node("Join", |v| walk_table_join(v, table_join));
+    }
+
+    fn visit_table_join_condition(&mut self, table_join_condition: &JoinCondition) -> Self::Result {
+        self.node("JoinCondition", |v| {
+            walk_table_join_condition(v, table_join_condition)
+        });
+    }
+
+    fn visit_where_clause(&mut self, where_clause: &WhereClause) -> Self::Result {
+        self.node("WhereClause", |v| walk_where_clause(v, where_clause));
+    }
+
+    fn visit_group_by_clause(&mut self, group_by_clause: &GroupByClause) -> Self::Result {
+        self.node("GroupByClause", |v| {
+            walk_group_by_clause(v, group_by_clause)
+        });
+    }
+
+    fn visit_having_clause(&mut self, having_clause: &HavingClause) -> Self::Result {
+        self.node("HavingClause", |v| walk_having_clause(v, having_clause));
+    }
+
+    fn visit_order_by_clause(&mut self, order_by_clause: &OrderByClause) -> Self::Result {
+        self.node("OrderByClause", |v| {
+            walk_order_by_clause(v, order_by_clause)
+        });
+    }
+
+    fn visit_order_by_arg(&mut self, order_by_arg: &OrderByArg) -> Self::Result {
+        self.node("OrderByArg", |v| walk_order_by_arg(v, order_by_arg));
+    }
+
+    fn visit_order_by_offset_fetch_clause(
+        &mut self,
+        offset_fetch_clause: &OffsetFetchClause,
+    ) -> Self::Result {
+        self.node("OffsetFetchClause", |v| {
+            walk_order_by_offset_fetch_clause(v, offset_fetch_clause)
+        });
+    }
+
+    fn visit_limit_clause(&mut self, limit_clause: &LimitClause) -> Self::Result {
+        self.node("LimitClause", |v| walk_limit_clause(v, limit_clause));
+    }
+
+    fn visit_function_name(&mut self, fn_name: &FunctionName) -> Self::Result {
+        self.node("FunctionName", |v| walk_function_name(v, fn_name));
+    }
+
+    fn visit_function_over_clause(&mut self, over_clause: &OverClause) -> Self::Result {
+        self.node("OverClause", |v| walk_function_over_clause(v, over_clause));
+    }
+
+    fn visit_function_over_clause_window_frame(
+        &mut self,
+        window_frame: &WindowFrame,
+    ) -> Self::Result {
+        self.node("WindowFrame", |v| {
+            walk_function_over_clause_window_frame(v, window_frame)
+        });
+    }
+
+    fn visit_case_condition(&mut self, case_condition: &CaseCondition) -> Self::Result {
+        self.node("CaseCondition", |v| walk_case_condition(v, case_condition));
+    }
+
+    fn visit_common_table_expression(&mut self, cte: &CommonTableExpression) -> Self::Result {
+        self.node("CommonTableExpression", |v| {
+            walk_common_table_expression(v, cte)
+        });
+    }
+
+    fn visit_expression_list(&mut self, list: &ExpressionList) -> Self::Result {
+        self.node("ExpressionList", |v| walk_expression_list(v, list));
+    }
+
+    fn visit_local_variable(&mut self, local_variable: &LocalVariable) -> Self::Result {
+        self.node("LocalVariable", |v| walk_local_variable(v, local_variable));
+    }
+
+    fn visit_column_def(&mut self, column_def: &ColumnDef) -> Self::Result {
+        self.node("ColumnDef", |v| walk_column_def(v, column_def));
+    }
+
+    fn visit_column_constraint(&mut self, constraint: &ColumnConstraint) -> Self::Result {
+        self.node("ColumnConstraint", |v| {
+            walk_column_constraint(v, constraint)
+        });
+    }
+}
@@ -1,46 +1,126 @@
 pub mod ast;
+pub mod dialect;
 pub mod error;
+pub mod eval;
 mod expr_start;
+pub mod json;
 mod operator;
+pub mod optimize;
 pub mod visitor;
+pub mod visitor_mut;
 
 use crate::ast::Keyword;
-use crate::error::{parse_error, ParseError, ParseErrorType};
+use crate::dialect::Dialect;
+use crate::error::{parse_error, ParseError, ParseErrorType, ParseWarning, ParseWarningType};
+use crate::eval::{eval_scalar, Value};
 use crate::expr_start::{
-    BUILTIN_FN_START, EXPRESSION_LIST_START, FUNCTION_ARGS_START, GROUP_BY_START,
-    ORDER_BY_ARGS_START, PARTITION_BY_START, SELECT_ITEM_TYPE_START, TABLE_SOURCE_START,
+    BUILTIN_FN_START, EXPRESSION_LIST_START, FUNCTION_ARGS_START, GROUP_BY_START, JOIN_START,
+    LIST_END, ORDER_BY_ARGS_START, PARTITION_BY_START, SELECT_ITEM_TYPE_START, STATEMENT_START,
+    TABLE_SOURCE_START,
 };
 use crate::operator::{get_precedence, Precedence};
 use ast::{Comment, DataTypeSize, Symbol};
 use error::parse_lexical_error;
-use lexer::{Lexer, Span, Token, TokenKind};
+use lexer::{Lexer, LexerResult, Span, Token, TokenKind};
+
+// how deeply expressions may nest before the parser gives up instead of
+// overflowing the stack, sql server itself only allows 32 levels of nesting
+pub const DEFAULT_MAX_EXPRESSION_DEPTH: usize = 32;
+
+// where the parser pulls its tokens from, either lexing the input as it goes
+// or replaying tokens that were produced ahead of time. once the tokens run
+// out they keep producing eof just like the lexer does
+#[derive(Debug, Clone)]
+enum TokenSource<'a> {
+    Lexer(Lexer<'a>),
+    Tokens {
+        tokens: std::vec::IntoIter<Token<'a>>,
+        eof: Span,
+    },
+}
+
+impl<'a> Iterator for TokenSource<'a> {
+    type Item = LexerResult<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            TokenSource::Lexer(lexer) => lexer.next(),
+            TokenSource::Tokens { tokens, eof } => Some(Ok(tokens
+                .next()
+                .unwrap_or_else(|| Token::new(TokenKind::Eof, *eof)))),
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct Parser<'a> {
-    lexer: Lexer<'a>,
+    lexer: TokenSource<'a>,
+    dialect: Dialect,
     // tokens: Vec<Token<'a>>,
     peek_token: Option<Token<'a>>,
+    expression_depth: usize,
+    max_expression_depth: usize,
 
     comments: Vec<Comment>,
     parse_errors: Vec<ParseError<'a>>,
+    parse_warnings: Vec<ParseWarning>,
 }
 
 impl<'a> Parser<'a> {
     pub fn new(lexer: Lexer<'a>) -> Self {
+        Self::with_dialect(lexer, Dialect::default())
+    }
+
+    pub fn with_dialect(lexer: Lexer<'a>, dialect: Dialect) -> Self {
+        // only the generic dialect has subscripts, t-sql reads name[x] as a
+        // name followed by the quoted identifier [x]
+        let lexer = lexer.with_bracket_subscripts(dialect == Dialect::Generic);
+        Self::with_token_source(TokenSource::Lexer(lexer), dialect)
+    }
+
+    /// Parses tokens that were already produced, for tooling that lexes the
+    /// input itself or rewrites the token stream before parsing.
+    pub fn from_tokens(tokens: Vec<Token<'a>>) -> Self {
+        let eof_position = tokens.last().map_or(0, |t| t.location().end + 1);
+        let token_source = TokenSource::Tokens {
+            tokens: tokens.into_iter(),
+            eof: Span::new(eof_position, eof_position),
+        };
+        Self::with_token_source(token_source, Dialect::default())
+    }
+
+    fn with_token_source(lexer: TokenSource<'a>, dialect: Dialect) -> Self {
         let mut parser = Parser {
             lexer,
+            dialect,
             peek_token: None,
+            expression_depth: 0,
+            max_expression_depth: DEFAULT_MAX_EXPRESSION_DEPTH,
             comments: vec![],
             parse_errors: vec![],
+            parse_warnings: vec![],
         };
         parser.advance();
         parser
     }
 
+    pub fn with_max_expression_depth(mut self, max_expression_depth: usize) -> Self {
+        self.max_expression_depth = max_expression_depth;
+        self
+    }
+
+    pub fn dialect(&self) -> Dialect {
+        self.dialect
+    }
+
     pub fn errors(&self) -> &[ParseError<'a>] {
         &self.parse_errors
     }
 
+    pub fn warnings(&self) -> &[ParseWarning] {
+        &self.parse_warnings
+    }
+
     pub fn comments(&self) -> &[Comment] {
         &self.comments
     }
@@ -107,7 +187,6 @@ impl<'a> Parser<'a> {
         self.unexpected_token(vec![token_kind.to_string()])
     }
 
-    #[allow(dead_code)]
     fn expect_token_any(&mut self, token_kinds: &[TokenKind]) -> Result<Token<'a>, ParseError<'a>> {
         if self.token_is_any(token_kinds) {
             let tok = self.peek_token.unwrap();
@@ -132,6 +211,11 @@ impl<'a> Parser<'a> {
 
     fn unexpected_token<A>(&self, expected: Vec<String>) -> Result<A, ParseError<'a>> {
         match self.peek_token {
+            // running out of input mid clause gets its own error so it is not
+            // reported as a stray token
+            Some(t) if t.kind() == TokenKind::Eof => {
+                parse_error(ParseErrorType::UnrecognizedEof { expected }, t.location())
+            }
             Some(t) => parse_error(
                 ParseErrorType::UnexpectedToken {
                     token: *t.kind_as_ref(),
@@ -140,10 +224,7 @@ impl<'a> Parser<'a> {
                 t.location(),
             ),
             None => parse_error(
-                ParseErrorType::UnexpectedToken {
-                    token: TokenKind::Eof,
-                    expected,
-                },
+                ParseErrorType::UnrecognizedEof { expected },
                 Span::default(),
             ),
         }
@@ -159,6 +240,10 @@ impl<'a> Parser<'a> {
 
     fn expect_function_args_start(&mut self) -> Result<(), ParseError<'a>> {
         if let Some(token) = self.peek_token {
+            // an argument can be another call like UPPER(LTRIM(x))
+            if token.kind_as_ref().builtin_fn() {
+                return Ok(());
+            }
             for start_token in FUNCTION_ARGS_START {
                 if start_token.shallow_eq_token(token.kind_as_ref()) {
                     return Ok(());
@@ -175,7 +260,7 @@ impl<'a> Parser<'a> {
 
     fn expect_select_item_start(&mut self) -> Result<(), ParseError<'a>> {
         if let Some(token) = self.peek_token {
-            if token.kind_as_ref().builtin_fn() {
+            if token.kind_as_ref().builtin_fn() || self.keyword_as_identifier().is_some() {
                 return Ok(());
             }
             for start_token in SELECT_ITEM_TYPE_START {
@@ -232,6 +317,10 @@ impl<'a> Parser<'a> {
 
     fn expect_order_by_args_start(&mut self) -> Result<(), ParseError<'a>> {
         if let Some(token) = self.peek_token {
+            // ordering by a function result or a column named like one
+            if token.kind_as_ref().builtin_fn() {
+                return Ok(());
+            }
             for start_token in ORDER_BY_ARGS_START {
                 if start_token.shallow_eq_token(token.kind_as_ref()) {
                     return Ok(());
@@ -248,6 +337,10 @@ impl<'a> Parser<'a> {
 
     fn expect_partition_by_start(&mut self) -> Result<(), ParseError<'a>> {
         if let Some(token) = self.peek_token {
+            // partitioning on a function result like YEAR(d) is allowed
+            if token.kind_as_ref().builtin_fn() {
+                return Ok(());
+            }
             for start_token in PARTITION_BY_START {
                 if start_token.shallow_eq_token(token.kind_as_ref()) {
                     return Ok(());
@@ -278,6 +371,56 @@ impl<'a> Parser<'a> {
         )
     }
 
+    // natural joins and ilike don't exist in t-sql, so outside the spots
+    // where they would be a join or a like t-sql reads them as names
+    fn keyword_as_identifier(&self) -> Option<ast::Expression> {
+        match self.peek_token {
+            Some(token)
+                if self.dialect == Dialect::TSql
+                    && matches!(token.kind(), TokenKind::Natural | TokenKind::Ilike) =>
+            {
+                Some(ast::Expression::Identifier(ast::Literal {
+                    location: token.location(),
+                    content: token.kind().to_string(),
+                }))
+            }
+            _ => None,
+        }
+    }
+
+    // the tokens that can start a join in this dialect, t-sql has no natural
+    // joins so listing NATURAL as expected would only mislead
+    fn expected_join_start(&self) -> Vec<String> {
+        JOIN_START
+            .iter()
+            .filter(|kind| self.dialect != Dialect::TSql || **kind != TokenKind::Natural)
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    // the name after a select item or table that gives it an alias, if any
+    fn peek_alias(&self) -> Result<Option<ast::Expression>, ParseError<'a>> {
+        match self.peek_token.map(|t| t.kind()) {
+            Some(
+                TokenKind::Identifier(_)
+                | TokenKind::QuotedIdentifier(_)
+                | TokenKind::StringLiteral(_),
+            ) => Ok(Some(ast::Expression::try_from(self.peek_token)?)),
+            _ => Ok(self.keyword_as_identifier()),
+        }
+    }
+
+    fn consume_list_comma(&mut self) -> Result<(), ParseError<'a>> {
+        let comma = self.expect_token(&TokenKind::Comma)?;
+
+        // a comma followed by the end of the list is a trailing comma
+        if self.peek_token.is_none() || self.token_is_any(LIST_END) {
+            return parse_error(ParseErrorType::TrailingComma, comma.location());
+        }
+
+        Ok(())
+    }
+
     fn maybe_keyword(&mut self, kind: TokenKind) -> Option<Keyword> {
         if let Some(token) = self.peek_token {
             if token.shallow_eq_token_kind(&kind) {
@@ -303,69 +446,117 @@ impl<'a> Parser<'a> {
 impl<'a> Parser<'a> {
     pub fn parse(&mut self) -> ast::Query {
         let mut query = ast::Query::new();
+        let mut expect_semicolon = false;
 
         while self.peek_token.is_some_and(|t| t.kind() != TokenKind::Eof) {
-            if !query.statements.is_empty() {
+            // stray semicolons only end the previous statement, they are not statements
+            if self.token_is(&TokenKind::SemiColon) {
+                self.advance();
+                expect_semicolon = false;
+                continue;
+            }
+            // a batch separator ends the statement before it like a semicolon
+            if self.token_is(&TokenKind::Go) {
+                expect_semicolon = false;
+            }
+            if expect_semicolon {
                 if let Err(e) = self.expect_token(&TokenKind::SemiColon) {
                     self.parse_errors.push(e);
+                    self.skip_to_next_statement(None);
+                    expect_semicolon = false;
+                    continue;
                 }
             }
             let token = self.peek_token.unwrap();
-            match self.parse_statement(token) {
-                Ok(statement) => query.statements.push(statement),
-                Err(parse_error) => self.parse_errors.push(parse_error),
+            let errors_before = self.parse_errors.len();
+            let result = self.parse_statement(token);
+            // the lexer drops text it can't read so a statement with a lexical
+            // error is never kept, and parse errors after it only repeat it
+            let lexical_error = self.parse_errors[errors_before..]
+                .iter()
+                .find(|e| matches!(e.error, ParseErrorType::LexerError { .. }))
+                .map(|e| e.span);
+            match (result, lexical_error) {
+                (Ok(statement), None) => {
+                    expect_semicolon = !matches!(statement, ast::Statement::BatchSeparator { .. });
+                    query.statements.push(statement);
+                }
+                (Ok(_), Some(_)) => {
+                    self.skip_to_next_statement(None);
+                    expect_semicolon = false;
+                }
+                (Err(parse_error), lexical_error) => {
+                    if lexical_error.is_none_or(|span| parse_error.span.start < span.start) {
+                        self.parse_errors.push(parse_error);
+                    }
+                    self.skip_to_next_statement(Some(token.kind()));
+                    expect_semicolon = false;
+                }
             }
         }
 
         query
     }
 
+    /// skips the rest of a statement that failed to parse so that parsing can
+    /// pick up again at the next `;`, `GO` or statement keyword. statement
+    /// keywords inside parentheses or that carry on the failed statement, like
+    /// the SELECT of an INSERT ... SELECT, are skipped with the rest of it
+    fn skip_to_next_statement(&mut self, statement_start: Option<TokenKind<'a>>) {
+        let mut depth = 0usize;
+        let mut previous = None;
+        while let Some(token) = self.peek_token.filter(|t| t.kind() != TokenKind::Eof) {
+            let kind = token.kind();
+            // a semicolon or batch separator is never inside a statement, so
+            // they end it even when its parentheses are unbalanced
+            if matches!(kind, TokenKind::SemiColon | TokenKind::Go) {
+                break;
+            }
+            if depth == 0
+                && self.token_is_any(STATEMENT_START)
+                && !continues_statement(statement_start, previous, kind)
+            {
+                break;
+            }
+            match kind {
+                TokenKind::LeftParen => depth += 1,
+                TokenKind::RightParen => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+            previous = Some(kind);
+            self.advance();
+        }
+    }
+
+    /// parses the whole input, returning every error found instead of the query
+    /// when the input is malformed
+    pub fn try_parse(&mut self) -> Result<ast::Query, Vec<ParseError<'a>>> {
+        let query = self.parse();
+        if self.parse_errors.is_empty() {
+            Ok(query)
+        } else {
+            Err(self.parse_errors.clone())
+        }
+    }
+
     fn parse_statement(&mut self, token: Token<'a>) -> Result<ast::Statement, ParseError<'a>> {
         let statement = match token.kind_as_ref() {
-            TokenKind::Select => {
-                let select_statement = self.parse_select_statement()?;
-                if self.token_is(&TokenKind::Union) {
-                    let mut unions = vec![];
-                    while self.token_is(&TokenKind::Union) {
-                        let union_kw = self.consume_keyword(TokenKind::Union)?;
-                        let all_kw = self.maybe_keyword(TokenKind::All);
-                        let select = self.parse_select_statement()?;
-                        unions.push(ast::Union {
-                            union_kw,
-                            all_kw,
-                            select,
-                        });
-                    }
-                    ast::Statement::Union {
-                        select: select_statement,
-                        unions,
-                    }
-                } else {
-                    ast::Statement::Select(select_statement)
-                }
-            }
+            TokenKind::Select => self.parse_select_or_set_operation()?,
             TokenKind::Insert => self.parse_insert_statement()?,
-
-            // TokenKind::Update => {
-            //     return Ok(ast::Statement::Update(self.parse_update_statement()?))
-            // }
-            // TokenKind::Delete => {
-            //     return Ok(ast::Statement::Delete(self.parse_delete_statement()?))
-            // }
+            TokenKind::Update => ast::Statement::Update(self.parse_update_statement()?),
+            TokenKind::Delete => ast::Statement::Delete(self.parse_delete_statement()?),
+            TokenKind::Merge => ast::Statement::Merge(self.parse_merge_statement()?),
             TokenKind::With =>  self.parse_cte_statement()?,
             TokenKind::Declare => self.parse_declare_statement()?,
-            TokenKind::Set =>  self.parse_set_local_variable_statement()?,
+            TokenKind::Set => self.parse_set_statement()?,
             TokenKind::Exec | TokenKind::Execute => self.parse_execute_statement()?,
+            TokenKind::Create => self.parse_create_table()?,
+            TokenKind::Truncate => self.parse_truncate_statement()?,
+            TokenKind::Alter => self.parse_alter_index()?,
+            TokenKind::Go => self.parse_batch_separator()?,
             _ => {
-                let err = self.unexpected_token(vec![
-                    TokenKind::Select.to_string(),
-                    TokenKind::Insert.to_string(),
-                    TokenKind::With.to_string(),
-                    TokenKind::Declare.to_string(),
-                    TokenKind::Set.to_string(),
-                    TokenKind::Exec.to_string(),
-                    TokenKind::Execute.to_string(),
-                ]);
+                let err =
+                    self.unexpected_token(STATEMENT_START.iter().map(|s| s.to_string()).collect());
                 self.advance();
                 return err;
             }
@@ -374,8 +565,73 @@ impl<'a> Parser<'a> {
         Ok(statement)
     }
 
+    // UNION and EXCEPT combine INTERSECT chains, so INTERSECT binds tighter.
+    // both levels are left associative
+    fn parse_select_or_set_operation(&mut self) -> Result<ast::Statement, ParseError<'a>> {
+        let mut left = self.parse_intersect_operation()?;
+        while self.token_is_any(&[TokenKind::Union, TokenKind::Except]) {
+            let op = self.parse_set_operator(&left)?;
+            let all = if op.kind == ast::KeywordKind::Union {
+                self.maybe_keyword(TokenKind::All)
+            } else {
+                None
+            };
+            let right = self.parse_intersect_operation()?;
+            if let Some(into_location) = first_into_location(&right) {
+                return parse_error(ParseErrorType::IntoWithSetOperation, into_location);
+            }
+            left = ast::Statement::SetOperation {
+                left: Box::new(left),
+                op,
+                all,
+                right: Box::new(right),
+            };
+        }
+
+        Ok(left)
+    }
+
+    fn parse_intersect_operation(&mut self) -> Result<ast::Statement, ParseError<'a>> {
+        let mut left = ast::Statement::Select(self.parse_select_statement()?);
+        while self.token_is(&TokenKind::Intersect) {
+            let op = self.parse_set_operator(&left)?;
+            let right = self.parse_select_statement()?;
+            if let Some(into_table) = &right.into_table {
+                return parse_error(
+                    ParseErrorType::IntoWithSetOperation,
+                    into_table.into_kw.location,
+                );
+            }
+            left = ast::Statement::SetOperation {
+                left: Box::new(left),
+                op,
+                all: None,
+                right: Box::new(ast::Statement::Select(right)),
+            };
+        }
+
+        Ok(left)
+    }
+
+    // consumes the set operator after `left`. only the first select of a
+    // query can have an INTO, and then only when it isn't combined with
+    // another select, so the error points at that INTO
+    fn parse_set_operator(&mut self, left: &ast::Statement) -> Result<Keyword, ParseError<'a>> {
+        if let Some(into_location) = first_into_location(left) {
+            return parse_error(ParseErrorType::IntoWithSetOperation, into_location);
+        }
+        let op = Keyword::try_from(self.peek_token)?;
+        self.advance();
+        Ok(op)
+    }
+
     fn parse_insert_statement(&mut self) -> Result<ast::Statement, ParseError<'a>> {
         let insert_kw = self.consume_keyword(TokenKind::Insert)?;
+        let insert_top = if let Some(kw) = self.maybe_keyword(TokenKind::Top) {
+            Some(self.parse_top_clause(kw)?)
+        } else {
+            None
+        };
         let into_kw = self.maybe_keyword(TokenKind::Into);
         let object = self.parse_object_table_name()?;
 
@@ -396,6 +652,7 @@ impl<'a> Parser<'a> {
             };
             let insert_statement = ast::InsertStatement::Table {
                 insert_kw,
+                insert_top,
                 into_kw,
                 object,
                 select_kw,
@@ -407,40 +664,52 @@ impl<'a> Parser<'a> {
 
             Ok(ast::Statement::Insert(insert_statement))
         } else {
-            let columns = if self.token_is(&TokenKind::LeftParen) {
-                let left_paren: Symbol = self.expect_token(&TokenKind::LeftParen)?.into();
-                let columns = self.parse_expression_list()?;
-                let right_paren: Symbol = self.expect_token(&TokenKind::RightParen)?.into();
-                Some(ast::ExpressionList {
-                    left_paren,
-                    items: columns,
-                    right_paren,
-                })
-            } else {
-                None
-            };
+            let columns = self.parse_column_list()?;
             let values_kw = self.consume_keyword(TokenKind::Values)?;
-            let left_paren: Symbol = self.expect_token(&TokenKind::LeftParen)?.into();
-            let values = self.parse_expression_list()?;
-            let right_paren: Symbol = self.expect_token(&TokenKind::RightParen)?.into();
+            let values = self.parse_values_list()?;
 
             let insert_statement = ast::InsertStatement::Values {
                 insert_kw,
+                insert_top,
                 into_kw,
                 object,
                 columns,
                 values_kw,
-                values: ast::ExpressionList {
-                    left_paren,
-                    items: values,
-                    right_paren,
-                },
+                values,
             };
 
             Ok(ast::Statement::Insert(insert_statement))
         }
     }
 
+    // the optional parenthesized columns after the table an insert writes to
+    fn parse_column_list(&mut self) -> Result<Option<ast::ExpressionList>, ParseError<'a>> {
+        if !self.token_is(&TokenKind::LeftParen) {
+            return Ok(None);
+        }
+        let left_paren: Symbol = self.expect_token(&TokenKind::LeftParen)?.into();
+        let items = self.parse_expression_list()?;
+        let right_paren: Symbol = self.expect_token(&TokenKind::RightParen)?.into();
+
+        Ok(Some(ast::ExpressionList {
+            left_paren,
+            items,
+            right_paren,
+        }))
+    }
+
+    fn parse_values_list(&mut self) -> Result<ast::ExpressionList, ParseError<'a>> {
+        let left_paren: Symbol = self.expect_token(&TokenKind::LeftParen)?.into();
+        let items = self.parse_expression_list()?;
+        let right_paren: Symbol = self.expect_token(&TokenKind::RightParen)?.into();
+
+        Ok(ast::ExpressionList {
+            left_paren,
+            items,
+            right_paren,
+        })
+    }
+
     fn parse_object_table_name(&mut self) -> Result<ast::Expression, ParseError<'a>> {
         if self.token_is_any(&[TokenKind::QuotedIdentifier(""), TokenKind::Identifier("")]) {
             let object = ast::Expression::try_from(self.peek_token)?;
@@ -456,13 +725,328 @@ impl<'a> Parser<'a> {
         }
     }
 
-    fn parse_set_local_variable_statement(&mut self) -> Result<ast::Statement, ParseError<'a>> {
+    fn parse_update_statement(&mut self) -> Result<ast::UpdateStatement, ParseError<'a>> {
+        let update_kw = self.consume_keyword(TokenKind::Update)?;
+        let top = if let Some(kw) = self.maybe_keyword(TokenKind::Top) {
+            Some(self.parse_top_clause(kw)?)
+        } else {
+            None
+        };
+        let table = self.parse_object_table_name()?;
         let set_kw = self.consume_keyword(TokenKind::Set)?;
+        let update_columns = self.parse_expression_list()?;
+
+        let output = if let Some(kw) = self.maybe_keyword(TokenKind::Output) {
+            Some(self.parse_output_clause(kw)?)
+        } else {
+            None
+        };
+
+        let from = if let Some(kw) = self.maybe_keyword(TokenKind::From) {
+            Some(self.parse_table_arg(kw)?)
+        } else {
+            None
+        };
+
+        let where_clause = if let Some(kw) = self.maybe_keyword(TokenKind::Where) {
+            Some(self.parse_where_clause(kw)?)
+        } else {
+            None
+        };
+
+        Ok(ast::UpdateStatement {
+            update_kw,
+            top,
+            table,
+            set_kw,
+            update_columns,
+            output,
+            from,
+            where_clause,
+        })
+    }
+
+    fn parse_delete_statement(&mut self) -> Result<ast::DeleteStatement, ParseError<'a>> {
+        let delete_kw = self.consume_keyword(TokenKind::Delete)?;
+        let top = if let Some(kw) = self.maybe_keyword(TokenKind::Top) {
+            Some(self.parse_top_clause(kw)?)
+        } else {
+            None
+        };
+        let from_kw = self.consume_keyword(TokenKind::From)?;
+        let table = self.parse_table_arg(from_kw)?;
+
+        let output = if let Some(kw) = self.maybe_keyword(TokenKind::Output) {
+            Some(self.parse_output_clause(kw)?)
+        } else {
+            None
+        };
+
+        let where_clause = if let Some(kw) = self.maybe_keyword(TokenKind::Where) {
+            Some(self.parse_where_clause(kw)?)
+        } else {
+            None
+        };
+
+        Ok(ast::DeleteStatement {
+            delete_kw,
+            top,
+            table,
+            output,
+            where_clause,
+        })
+    }
+
+    fn parse_merge_statement(&mut self) -> Result<ast::MergeStatement, ParseError<'a>> {
+        let merge_kw = self.consume_keyword(TokenKind::Merge)?;
+        let top = if let Some(kw) = self.maybe_keyword(TokenKind::Top) {
+            Some(self.parse_top_clause(kw)?)
+        } else {
+            None
+        };
+        let into_kw = self.maybe_keyword(TokenKind::Into);
+        let target = self.parse_table_source()?;
+        let using_kw = self.consume_keyword(TokenKind::Using)?;
+        let source = self.parse_table_source()?;
+        let on_kw = self.consume_keyword(TokenKind::On)?;
+        let condition = self.parse_expression(Precedence::Lowest)?;
+
+        // a merge has to do something with the rows it matches
+        if !self.token_is(&TokenKind::When) {
+            return self.unexpected_token(vec![TokenKind::When.to_string()]);
+        }
+        let mut clauses = vec![];
+        while let Some(when_kw) = self.maybe_keyword(TokenKind::When) {
+            clauses.push(self.parse_merge_clause(when_kw)?);
+        }
+
+        let output = if let Some(kw) = self.maybe_keyword(TokenKind::Output) {
+            Some(self.parse_output_clause(kw)?)
+        } else {
+            None
+        };
+
+        Ok(ast::MergeStatement {
+            merge_kw,
+            top,
+            into_kw,
+            target,
+            using_kw,
+            source,
+            on_kw,
+            condition,
+            clauses,
+            output,
+        })
+    }
+
+    fn parse_merge_clause(&mut self, when_kw: Keyword) -> Result<ast::MergeClause, ParseError<'a>> {
+        let mut when_kws = vec![when_kw];
+        let not_kw = self.maybe_keyword(TokenKind::Not);
+        when_kws.extend(not_kw);
+        when_kws.push(self.consume_keyword(TokenKind::Matched)?);
+
+        let match_kind = match (not_kw, self.maybe_keyword(TokenKind::By)) {
+            (None, None) => ast::MergeMatchKind::Matched,
+            (Some(_), None) => ast::MergeMatchKind::NotMatchedByTarget,
+            (Some(_), Some(by_kw)) => {
+                when_kws.push(by_kw);
+                let target_or_source = self.parse_merge_target_or_source()?;
+                when_kws.push(target_or_source);
+                if target_or_source.kind == ast::KeywordKind::Target {
+                    ast::MergeMatchKind::NotMatchedByTarget
+                } else {
+                    ast::MergeMatchKind::NotMatchedBySource
+                }
+            }
+            // only rows that did not match can be missing from one side
+            (None, Some(by_kw)) => {
+                return parse_error(
+                    ParseErrorType::UnexpectedToken {
+                        token: TokenKind::By,
+                        expected: vec![TokenKind::And.to_string(), TokenKind::Then.to_string()],
+                    },
+                    by_kw.location,
+                )
+            }
+        };
+
+        let condition = if let Some(and_kw) = self.maybe_keyword(TokenKind::And) {
+            Some((and_kw, self.parse_expression(Precedence::Lowest)?))
+        } else {
+            None
+        };
+        let then_kw = self.consume_keyword(TokenKind::Then)?;
+
+        let action = if let Some(update_kw) = self.maybe_keyword(TokenKind::Update) {
+            let set_kw = self.consume_keyword(TokenKind::Set)?;
+            let update_columns = self.parse_expression_list()?;
+            ast::MergeAction::Update {
+                update_kw,
+                set_kw,
+                update_columns,
+            }
+        } else if let Some(delete_kw) = self.maybe_keyword(TokenKind::Delete) {
+            ast::MergeAction::Delete(delete_kw)
+        } else if let Some(insert_kw) = self.maybe_keyword(TokenKind::Insert) {
+            let columns = self.parse_column_list()?;
+            let values_kw = self.consume_keyword(TokenKind::Values)?;
+            let values = self.parse_values_list()?;
+            ast::MergeAction::Insert {
+                insert_kw,
+                columns,
+                values_kw,
+                values,
+            }
+        } else {
+            return self.unexpected_token(vec![
+                TokenKind::Update.to_string(),
+                TokenKind::Delete.to_string(),
+                TokenKind::Insert.to_string(),
+            ]);
+        };
+
+        Ok(ast::MergeClause {
+            when_kws,
+            match_kind,
+            condition,
+            then_kw,
+            action,
+        })
+    }
+
+    // target and source are only keywords after NOT MATCHED BY, anywhere else
+    // they are plain names
+    fn parse_merge_target_or_source(&mut self) -> Result<Keyword, ParseError<'a>> {
+        let kind = match self.peek_token.map(|t| t.kind()) {
+            Some(TokenKind::Identifier(name)) if name.eq_ignore_ascii_case("target") => {
+                ast::KeywordKind::Target
+            }
+            Some(TokenKind::Identifier(name)) if name.eq_ignore_ascii_case("source") => {
+                ast::KeywordKind::Source
+            }
+            _ => return self.unexpected_token(vec!["target".to_string(), "source".to_string()]),
+        };
+        let keyword = Keyword::new(self.peek_token.unwrap().location(), kind);
+        self.advance();
+
+        Ok(keyword)
+    }
+
+    fn parse_output_clause(
+        &mut self,
+        output_kw: Keyword,
+    ) -> Result<ast::OutputClause, ParseError<'a>> {
+        let columns = self.parse_select_items()?;
+        let into_table = if let Some(into_kw) = self.maybe_keyword(TokenKind::Into) {
+            // the rows can go to a table variable as well as a table
+            let table = if self.token_is(&TokenKind::LocalVariable("")) {
+                let table = ast::Expression::try_from(self.peek_token)?;
+                self.advance();
+                table
+            } else {
+                self.parse_object_table_name()?
+            };
+            let columns = self.parse_column_list()?;
+            Some(ast::OutputInto {
+                into_kw,
+                table,
+                columns,
+            })
+        } else {
+            None
+        };
+
+        Ok(ast::OutputClause {
+            output_kw,
+            columns,
+            into_table,
+        })
+    }
+
+    fn parse_set_statement(&mut self) -> Result<ast::Statement, ParseError<'a>> {
+        let set_kw = self.consume_keyword(TokenKind::Set)?;
+        if self.token_is(&TokenKind::Transaction) {
+            return self.parse_set_isolation_level_statement(set_kw);
+        }
+        self.parse_set_local_variable_statement(set_kw)
+    }
+
+    fn parse_set_isolation_level_statement(
+        &mut self,
+        set_kw: Keyword,
+    ) -> Result<ast::Statement, ParseError<'a>> {
+        let transaction_isolation_level_kws = vec![
+            self.consume_keyword(TokenKind::Transaction)?,
+            self.consume_keyword(TokenKind::Isolation)?,
+            self.consume_keyword(TokenKind::Level)?,
+        ];
+
+        let level_kws;
+        let level;
+        if let Some(read_kw) = self.maybe_keyword(TokenKind::Read) {
+            if let Some(kw) = self.maybe_keyword(TokenKind::Uncommitted) {
+                level_kws = vec![read_kw, kw];
+                level = ast::IsolationLevel::ReadUncommitted;
+            } else {
+                level_kws = vec![read_kw, self.consume_keyword(TokenKind::Committed)?];
+                level = ast::IsolationLevel::ReadCommitted;
+            }
+        } else if let Some(repeatable_kw) = self.maybe_keyword(TokenKind::Repeatable) {
+            level_kws = vec![repeatable_kw, self.consume_keyword(TokenKind::Read)?];
+            level = ast::IsolationLevel::RepeatableRead;
+        } else if let Some(kw) = self.maybe_keyword(TokenKind::Snapshot) {
+            level_kws = vec![kw];
+            level = ast::IsolationLevel::Snapshot;
+        } else if let Some(kw) = self.maybe_keyword(TokenKind::Serializable) {
+            level_kws = vec![kw];
+            level = ast::IsolationLevel::Serializable;
+        } else {
+            return self.unexpected_token(vec![
+                TokenKind::Read.to_string(),
+                TokenKind::Repeatable.to_string(),
+                TokenKind::Snapshot.to_string(),
+                TokenKind::Serializable.to_string(),
+            ]);
+        }
+
+        Ok(ast::Statement::SetIsolationLevel {
+            set_kw,
+            transaction_isolation_level_kws,
+            level,
+            level_kws,
+        })
+    }
+
+    fn parse_set_local_variable_statement(
+        &mut self,
+        set_kw: Keyword,
+    ) -> Result<ast::Statement, ParseError<'a>> {
+        if !self.token_is(&TokenKind::LocalVariable("")) {
+            return self.parse_error(ParseErrorType::ExpectedLocalVariable);
+        }
         let local_variable: ast::Expression =
             self.expect_token(&TokenKind::LocalVariable(""))?.into();
-        let equal_sign: Symbol = self.expect_token(&TokenKind::Equal)?.into();
+        let equal_sign: Symbol = self
+            .expect_token_any(&[
+                TokenKind::Equal,
+                TokenKind::PlusEqual,
+                TokenKind::MinusEqual,
+                TokenKind::MultiplyEqual,
+                TokenKind::DivideEqual,
+                TokenKind::PercentEqual,
+            ])?
+            .into();
         let value = self.parse_expression(Precedence::Lowest)?;
 
+        // unlike select, set assigns exactly one variable
+        if let Some(comma) = self
+            .peek_token
+            .filter(|t| t.kind_as_ref() == &TokenKind::Comma)
+        {
+            return parse_error(ParseErrorType::MultipleVariablesInSet, comma.location());
+        }
+
         Ok(ast::Statement::SetLocalVariable {
             set_kw,
             name: local_variable,
@@ -502,6 +1086,205 @@ impl<'a> Parser<'a> {
         })
     }
 
+    fn parse_create_table(&mut self) -> Result<ast::Statement, ParseError<'a>> {
+        let create_kw = self.consume_keyword(TokenKind::Create)?;
+        let table_kw = self.consume_keyword(TokenKind::Table)?;
+        let name = self.parse_object_table_name()?;
+        let left_paren: Symbol = self.expect_token(&TokenKind::LeftParen)?.into();
+
+        let mut columns = vec![];
+        loop {
+            columns.push(self.parse_column_def()?);
+            if !self.token_is(&TokenKind::Comma) {
+                break;
+            }
+            self.advance();
+        }
+
+        let right_paren: Symbol = self.expect_token(&TokenKind::RightParen)?.into();
+
+        Ok(ast::Statement::CreateTable {
+            create_kw,
+            table_kw,
+            name,
+            left_paren,
+            columns,
+            right_paren,
+        })
+    }
+
+    fn parse_truncate_statement(&mut self) -> Result<ast::Statement, ParseError<'a>> {
+        let truncate_kw = self.consume_keyword(TokenKind::Truncate)?;
+        let table_kw = self.consume_keyword(TokenKind::Table)?;
+        let table = self.parse_object_table_name()?;
+        // a cte can't follow without a semicolon so a with here is always the
+        // partitions option
+        let partitions = if let Some(with_kw) = self.maybe_keyword(TokenKind::With) {
+            Some(self.parse_truncate_partitions(with_kw)?)
+        } else {
+            None
+        };
+
+        Ok(ast::Statement::Truncate {
+            truncate_kw,
+            table_kw,
+            table,
+            partitions,
+        })
+    }
+
+    fn parse_batch_separator(&mut self) -> Result<ast::Statement, ParseError<'a>> {
+        let go_kw = self.consume_keyword(TokenKind::Go)?;
+        // the lexer only lets a number follow GO on the same line
+        let count = if self.token_is(&TokenKind::NumberLiteral("")) {
+            Some(self.parse_expression(Precedence::Lowest)?)
+        } else {
+            None
+        };
+
+        Ok(ast::Statement::BatchSeparator { go_kw, count })
+    }
+
+    fn parse_truncate_partitions(
+        &mut self,
+        with_kw: Keyword,
+    ) -> Result<ast::TruncatePartitions, ParseError<'a>> {
+        let left_paren: Symbol = self.expect_token(&TokenKind::LeftParen)?.into();
+        let partitions_kw = self.consume_keyword(TokenKind::Partitions)?;
+        let partitions_left_paren: Symbol = self.expect_token(&TokenKind::LeftParen)?.into();
+
+        let mut partitions = vec![];
+        loop {
+            let start = self.parse_expression(Precedence::Lowest)?;
+            let partition = if let Some(to_kw) = self.maybe_keyword(TokenKind::To) {
+                let end = self.parse_expression(Precedence::Lowest)?;
+                ast::PartitionRange::Range { start, to_kw, end }
+            } else {
+                ast::PartitionRange::Single(start)
+            };
+            partitions.push(partition);
+
+            if !self.token_is(&TokenKind::Comma) {
+                break;
+            }
+            self.consume_list_comma()?;
+        }
+
+        let partitions_right_paren: Symbol = self.expect_token(&TokenKind::RightParen)?.into();
+        let right_paren: Symbol = self.expect_token(&TokenKind::RightParen)?.into();
+
+        Ok(ast::TruncatePartitions {
+            with_kw,
+            left_paren,
+            partitions_kw,
+            partitions_left_paren,
+            partitions,
+            partitions_right_paren,
+            right_paren,
+        })
+    }
+
+    fn parse_alter_index(&mut self) -> Result<ast::Statement, ParseError<'a>> {
+        let alter_kw = self.consume_keyword(TokenKind::Alter)?;
+        let index_kw = self.consume_keyword(TokenKind::Index)?;
+        // all applies the action to every index on the table
+        let name = if let Some(all_kw) = self.maybe_keyword(TokenKind::All) {
+            ast::Expression::Keyword(all_kw)
+        } else if self.token_is_any(&[TokenKind::QuotedIdentifier(""), TokenKind::Identifier("")]) {
+            let name = ast::Expression::try_from(self.peek_token)?;
+            self.advance();
+            name
+        } else {
+            return self.parse_error(ParseErrorType::ExpectedIndexName);
+        };
+        let on_kw = self.consume_keyword(TokenKind::On)?;
+        let table = self.parse_object_table_name()?;
+
+        let action = if let Some(kw) = self.maybe_keyword(TokenKind::Rebuild) {
+            ast::AlterIndexAction::Rebuild(kw)
+        } else if let Some(kw) = self.maybe_keyword(TokenKind::Reorganize) {
+            ast::AlterIndexAction::Reorganize(kw)
+        } else if let Some(kw) = self.maybe_keyword(TokenKind::Disable) {
+            ast::AlterIndexAction::Disable(kw)
+        } else {
+            return self.unexpected_token(vec![
+                TokenKind::Rebuild.to_string(),
+                TokenKind::Reorganize.to_string(),
+                TokenKind::Disable.to_string(),
+            ]);
+        };
+
+        Ok(ast::Statement::AlterIndex {
+            alter_kw,
+            index_kw,
+            name,
+            on_kw,
+            table,
+            action,
+        })
+    }
+
+    fn parse_column_def(&mut self) -> Result<ast::ColumnDef, ParseError<'a>> {
+        let name =
+            if self.token_is_any(&[TokenKind::QuotedIdentifier(""), TokenKind::Identifier("")]) {
+                let name = ast::Expression::try_from(self.peek_token)?;
+                self.advance();
+                name
+            } else {
+                return self.parse_error(ParseErrorType::ExpectedColumnName);
+            };
+        let data_type = self.parse_data_type()?;
+
+        let mut constraints = vec![];
+        loop {
+            let constraint = if self.token_is(&TokenKind::Null) {
+                ast::ColumnConstraint::Null(self.consume_keyword(TokenKind::Null)?)
+            } else if self.token_is(&TokenKind::Not) {
+                let not_kw = self.consume_keyword(TokenKind::Not)?;
+                let null_kw = self.consume_keyword(TokenKind::Null)?;
+                ast::ColumnConstraint::NotNull { not_kw, null_kw }
+            } else if self.token_is(&TokenKind::Primary) {
+                let primary_kw = self.consume_keyword(TokenKind::Primary)?;
+                let key_kw = self.consume_keyword(TokenKind::Key)?;
+                ast::ColumnConstraint::PrimaryKey { primary_kw, key_kw }
+            } else if self.token_is(&TokenKind::Identity) {
+                let identity_kw = self.consume_keyword(TokenKind::Identity)?;
+                let seed_increment = if self.token_is(&TokenKind::LeftParen) {
+                    let left_paren: Symbol = self.expect_token(&TokenKind::LeftParen)?.into();
+                    let seed = self.parse_expression(Precedence::Lowest)?;
+                    let _ = self.expect_token(&TokenKind::Comma)?;
+                    let increment = self.parse_expression(Precedence::Lowest)?;
+                    let right_paren: Symbol = self.expect_token(&TokenKind::RightParen)?.into();
+                    Some(ast::IdentitySeedIncrement {
+                        left_paren,
+                        seed,
+                        increment,
+                        right_paren,
+                    })
+                } else {
+                    None
+                };
+                ast::ColumnConstraint::Identity {
+                    identity_kw,
+                    seed_increment,
+                }
+            } else if self.token_is(&TokenKind::Default) {
+                let default_kw = self.consume_keyword(TokenKind::Default)?;
+                let value = self.parse_expression(Precedence::Lowest)?;
+                ast::ColumnConstraint::Default { default_kw, value }
+            } else {
+                break;
+            };
+            constraints.push(constraint);
+        }
+
+        Ok(ast::ColumnDef {
+            name,
+            data_type,
+            constraints,
+        })
+    }
+
     fn parse_cte_statement(&mut self) -> Result<ast::Statement, ParseError<'a>> {
         let with_kw = self.consume_keyword(TokenKind::With)?;
         let mut ctes = vec![];
@@ -525,6 +1308,31 @@ impl<'a> Parser<'a> {
             let query = self.parse_select_statement()?;
             let right_paren: Symbol = self.expect_token(&TokenKind::RightParen)?.into();
 
+            // the count can only be checked when the projection is known, a
+            // wildcard could expand to any number of columns
+            if let Some(columns) = &column_list {
+                let has_wildcard = query.columns.iter().any(|column| {
+                    matches!(
+                        column,
+                        ast::SelectItem::Wildcard(_)
+                            | ast::SelectItem::QualifiedWildcard(_)
+                            | ast::SelectItem::WildcardWithAlias { .. }
+                    )
+                });
+                if !has_wildcard && columns.items.len() != query.columns.len() {
+                    self.parse_warnings.push(ParseWarning {
+                        warning: ParseWarningType::CteColumnCountMismatch {
+                            expected: columns.items.len(),
+                            found: query.columns.len(),
+                        },
+                        span: Span::new(
+                            columns.left_paren.location.start,
+                            columns.right_paren.location.end,
+                        ),
+                    });
+                }
+            }
+
             ctes.push(ast::CommonTableExpression {
                 name: cte_name,
                 columns: column_list,
@@ -615,6 +1423,9 @@ impl<'a> Parser<'a> {
 
         select_statement.select = self.consume_keyword(TokenKind::Select)?;
         select_statement.distinct = self.maybe_keyword(TokenKind::Distinct);
+        if select_statement.distinct.is_some() && self.token_is(&TokenKind::On) {
+            select_statement.distinct_on = Some(self.parse_distinct_on()?);
+        }
         select_statement.all = self.maybe_keyword(TokenKind::All);
 
         if let Some(kw) = self.maybe_keyword(TokenKind::Top) {
@@ -623,6 +1434,10 @@ impl<'a> Parser<'a> {
 
         select_statement.columns = self.parse_select_items()?;
 
+        if let Some(kw) = self.maybe_keyword(TokenKind::Into) {
+            select_statement.into_table = Some(self.parse_into_arg(kw)?);
+        }
+
         if let Some(kw) = self.maybe_keyword(TokenKind::From) {
             select_statement.table = Some(self.parse_table_arg(kw)?);
         }
@@ -643,11 +1458,33 @@ impl<'a> Parser<'a> {
         if let Some(kw) = self.maybe_keyword(TokenKind::Order) {
             let order_by_kws = vec![kw, self.consume_keyword(TokenKind::By)?];
             select_statement.order_by = Some(self.parse_order_by_clause(order_by_kws)?);
+        } else if self.token_is(&TokenKind::Offset) {
+            // offset only makes sense once the rows have an order to skip by
+            return self.parse_error(ParseErrorType::OffsetRequiresOrderBy);
+        }
+
+        if self.token_is(&TokenKind::Limit) {
+            select_statement.limit = Some(self.parse_limit_clause()?);
         }
 
         return Ok(select_statement);
     }
 
+    fn parse_into_arg(&mut self, into_kw: Keyword) -> Result<ast::IntoArg, ParseError<'a>> {
+        let table = self.parse_object_table_name()?;
+        let file_group = if let Some(on_kw) = self.maybe_keyword(TokenKind::On) {
+            Some((on_kw, self.parse_object_table_name()?))
+        } else {
+            None
+        };
+
+        Ok(ast::IntoArg {
+            into_kw,
+            table,
+            file_group,
+        })
+    }
+
     fn parse_select_items(&mut self) -> Result<Vec<ast::SelectItem>, ParseError<'a>> {
         // check if the next token is an identifier
         // return an error if the next token is not an identifier or number
@@ -656,34 +1493,31 @@ impl<'a> Parser<'a> {
         // while self.token_is_any(&SELECT_ITEM_TYPE_START) {
         loop {
             self.expect_select_item_start()?;
-            let expression = self.parse_expression(Precedence::Lowest)?;
+            // a leading asterisk is the wildcard, anywhere else it multiplies
+            let (alias, expression) = if self.token_is(&TokenKind::Asterisk) {
+                let asterisk: Symbol = self.expect_token(&TokenKind::Asterisk)?.into();
+                (None, ast::Expression::Asterisk(asterisk))
+            } else {
+                split_alias_assignment(self.parse_expression(Precedence::Lowest)?)
+            };
 
-            // column_alias = expression
-            if matches!(
-                expression,
-                ast::Expression::Identifier(..)
-                    | ast::Expression::QuotedIdentifier(..)
-                    | ast::Expression::StringLiteral(..)
-                    | ast::Expression::LocalVariable(..)
-            ) && self.token_is(&TokenKind::Equal)
-            {
-                let _ = self.expect_token(&TokenKind::Equal)?;
-                let expr = self.parse_expression(Precedence::Lowest)?;
-                columns.push(ast::SelectItem::ReverseAliasAssign {
-                    alias: expression,
-                    expression: expr,
-                });
+            if let Some(alias) = alias {
+                columns.push(ast::SelectItem::ReverseAliasAssign { alias, expression });
             } else {
                 // normal checking for alias
                 let as_kw = self.maybe_keyword(TokenKind::As);
 
+                let is_qualified_wildcard = matches!(
+                    &expression,
+                    ast::Expression::Compound(parts)
+                        if matches!(parts.last(), Some(ast::Expression::Asterisk(_)))
+                );
+
                 // check for alias
-                if self.token_is_any(&[
-                    TokenKind::Identifier(""),
-                    TokenKind::QuotedIdentifier(""),
-                    TokenKind::StringLiteral(""),
-                ]) {
-                    let alias = ast::Expression::try_from(self.peek_token)?;
+                if let Some(alias) = self.peek_alias()? {
+                    if is_qualified_wildcard {
+                        return self.parse_error(ParseErrorType::AliasOnQualifiedWildcard);
+                    }
                     self.advance();
 
                     if matches!(expression, ast::Expression::Asterisk(_)) {
@@ -704,9 +1538,13 @@ impl<'a> Parser<'a> {
                 } else if as_kw.is_none() {
                     if let ast::Expression::Asterisk(s) = expression {
                         columns.push(ast::SelectItem::Wildcard(s));
+                    } else if is_qualified_wildcard {
+                        columns.push(ast::SelectItem::QualifiedWildcard(expression));
                     } else {
                         columns.push(ast::SelectItem::Unnamed(expression));
                     }
+                } else if is_qualified_wildcard {
+                    return self.parse_error(ParseErrorType::AliasOnQualifiedWildcard);
                 } else {
                     return self.parse_error(ParseErrorType::MissingAliasAfterAsKeyword);
                 }
@@ -715,7 +1553,7 @@ impl<'a> Parser<'a> {
             if !self.token_is(&TokenKind::Comma) {
                 break;
             }
-            self.advance();
+            self.consume_list_comma()?;
         }
 
         if columns.is_empty() {
@@ -726,15 +1564,32 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_top_clause(&mut self, top_kw: Keyword) -> Result<ast::Top, ParseError<'a>> {
-        let top_expr = ast::Expression::try_from(self.peek_token)?;
-        match top_expr {
-            ast::Expression::NumberLiteral(_) => {}
-            _ => return self.unexpected_token(vec!["numeric literal".to_string()]),
-        }
+        let top_expr = if self.token_is(&TokenKind::LeftParen) {
+            self.parse_subquery_or_grouping()?
+        } else if self.token_is_any(&[
+            TokenKind::NumberLiteral(""),
+            TokenKind::Minus,
+            TokenKind::Plus,
+        ]) {
+            self.parse_prefix_expression()?
+        } else {
+            return self.unexpected_token(vec!["numeric literal".to_string()]);
+        };
 
-        self.advance();
         let percent_kw = self.maybe_keyword(TokenKind::Percent);
 
+        // only constant quantities can be checked, variables and subqueries
+        // are left for the server to reject
+        if let Ok(Value::Number(n)) = eval_scalar(&top_expr) {
+            let span = top_quantity_span(&top_expr);
+            if n < 0.0 {
+                return parse_error(ParseErrorType::NegativeTopQuantity, span);
+            }
+            if percent_kw.is_some() && n > 100.0 {
+                return parse_error(ParseErrorType::TopPercentOutOfRange, span);
+            }
+        }
+
         let with_ties_kw = if let Some(with_kw) = self.maybe_keyword(TokenKind::With) {
             let ties_kw = self.consume_keyword(TokenKind::Ties)?;
             Some(vec![with_kw, ties_kw])
@@ -770,14 +1625,56 @@ impl<'a> Parser<'a> {
         // go to period
         self.advance();
         loop {
-            self.expect_select_item_start()?;
-            if let Some(token) = self.peek_token {
-                let new_expr: ast::Expression = token.into();
-                compound.push(new_expr);
+            // a skipped part like the schema in `db..table` is an empty identifier
+            if let Some(period) = self
+                .peek_token
+                .filter(|t| t.kind_as_ref() == &TokenKind::Period)
+            {
+                compound.push(ast::Expression::Identifier(ast::Literal {
+                    location: period.location(),
+                    content: String::new(),
+                }));
+                self.advance();
+                continue;
+            }
+
+            // a part named like a function, such as the max in t.max, is a
+            // column and never a call
+            let keyword_part = self
+                .peek_token
+                .filter(|t| t.kind_as_ref().builtin_fn())
+                .map(|token| {
+                    ast::Expression::Identifier(ast::Literal {
+                        location: token.location(),
+                        content: token.kind().to_string(),
+                    })
+                })
+                .or_else(|| self.keyword_as_identifier());
+            if let Some(part) = keyword_part {
+                compound.push(part);
+                self.advance();
+                if !self.token_is(&TokenKind::Period) {
+                    break;
+                }
+                self.advance();
+                continue;
+            }
+
+            // a wildcard can only be the last part of the name
+            let is_asterisk = self.token_is(&TokenKind::Asterisk);
+            if !is_asterisk
+                && !self.token_is_any(&[TokenKind::Identifier(""), TokenKind::QuotedIdentifier("")])
+            {
+                return self.unexpected_token(vec![
+                    TokenKind::Identifier("").string_type().to_string(),
+                    TokenKind::QuotedIdentifier("").string_type().to_string(),
+                    TokenKind::Asterisk.string_type().to_string(),
+                ]);
             }
+            compound.push(ast::Expression::try_from(self.peek_token)?);
 
             self.advance();
-            if !self.token_is(&TokenKind::Period) {
+            if is_asterisk || !self.token_is(&TokenKind::Period) {
                 break;
             }
 
@@ -793,12 +1690,7 @@ impl<'a> Parser<'a> {
         // check if we have joins
 
         let mut joins = vec![];
-        if self.token_is_any(&[
-            TokenKind::Inner,
-            TokenKind::Left,
-            TokenKind::Right,
-            TokenKind::Full,
-        ]) {
+        if self.token_is_any(JOIN_START) {
             joins = self.parse_table_joins()?;
         }
 
@@ -812,12 +1704,18 @@ impl<'a> Parser<'a> {
     fn parse_table_source(&mut self) -> Result<ast::TableSource, ParseError<'a>> {
         self.expect_table_source_start()?;
 
+        if self.token_is(&TokenKind::LeftParen) {
+            return self.parse_derived_table_or_join_group();
+        }
+
         let expr = self.parse_expression(Precedence::Lowest)?;
         match expr {
+            ast::Expression::Subquery { .. } => return self.parse_derived_table(expr),
             ast::Expression::Identifier(_)
             | ast::Expression::QuotedIdentifier(_)
             | ast::Expression::LocalVariable(_)
-            | ast::Expression::Compound(_) => {
+            | ast::Expression::Compound(_)
+            | ast::Expression::Function { .. } => {
 
                 // return Ok(ast::TableSource::Table {
                 //     name: expr,
@@ -825,26 +1723,79 @@ impl<'a> Parser<'a> {
                 //     alias: None,
                 // })
             }
-            _ => return self.unexpected_token(vec!["select items".to_string()]),
+            _ => return self.unexpected_token(vec!["select item".to_string()]),
         }
 
         // check for alias
-        if self.token_is_any(&[
-            TokenKind::Identifier(""),
-            TokenKind::QuotedIdentifier(""),
-            TokenKind::StringLiteral(""),
-        ]) {
-            let alias = ast::Expression::try_from(self.peek_token)?;
+        let alias = self.peek_alias()?;
+        if alias.is_some() {
             self.advance();
-            return Ok(ast::TableSource::Table {
-                name: expr,
-                alias: Some(alias),
+        }
+
+        if let ast::Expression::Function { .. } = expr {
+            return Ok(ast::TableSource::TableValuedFunction {
+                function: expr,
+                alias,
             });
         }
 
-        Ok(ast::TableSource::Table {
-            name: expr,
-            alias: None,
+        Ok(ast::TableSource::Table { name: expr, alias })
+    }
+
+    fn parse_derived_table_or_join_group(&mut self) -> Result<ast::TableSource, ParseError<'a>> {
+        let left_paren: Symbol = self.expect_token(&TokenKind::LeftParen)?.into();
+        if self.token_is(&TokenKind::Select) {
+            let statement = self.parse_subquery_statement()?;
+            let right_paren: Symbol = self.expect_token(&TokenKind::RightParen)?.into();
+            let query = ast::Expression::Subquery {
+                left_paren,
+                statement: Box::new(statement),
+                right_paren,
+            };
+            return self.parse_derived_table(query);
+        }
+
+        let table = self.parse_table_source()?;
+        // a group can be wrapped in more parentheses without joining anything
+        // else, but a table on its own in parentheses has nothing to group
+        let joins = if matches!(table, ast::TableSource::JoinGroup { .. })
+            && self.token_is(&TokenKind::RightParen)
+        {
+            vec![]
+        } else if self.token_is_any(JOIN_START) {
+            self.parse_table_joins()?
+        } else {
+            return self.unexpected_token(self.expected_join_start());
+        };
+        let right_paren: Symbol = self.expect_token(&TokenKind::RightParen)?.into();
+
+        Ok(ast::TableSource::JoinGroup {
+            left_paren,
+            table: Box::new(table),
+            joins,
+            right_paren,
+        })
+    }
+
+    fn parse_derived_table(
+        &mut self,
+        query: ast::Expression,
+    ) -> Result<ast::TableSource, ParseError<'a>> {
+        let as_kw = self.maybe_keyword(TokenKind::As);
+        if !self.token_is_any(&[TokenKind::Identifier(""), TokenKind::QuotedIdentifier("")]) {
+            // sql server requires every derived table to have an alias
+            return match as_kw {
+                Some(_) => self.parse_error(ParseErrorType::MissingAliasAfterAsKeyword),
+                None => self.parse_error(ParseErrorType::MissingDerivedTableAlias),
+            };
+        }
+        let alias = ast::Expression::try_from(self.peek_token)?;
+        self.advance();
+
+        Ok(ast::TableSource::Derived {
+            query,
+            as_kw,
+            alias,
         })
     }
 
@@ -853,7 +1804,10 @@ impl<'a> Parser<'a> {
         loop {
             let join_type;
             let join_keyword;
-            if let Some(kw) = self.maybe_keyword(TokenKind::Inner) {
+            if let Some(kw) = self.maybe_keyword(TokenKind::Join) {
+                join_keyword = vec![kw];
+                join_type = ast::JoinType::Inner;
+            } else if let Some(kw) = self.maybe_keyword(TokenKind::Inner) {
                 join_keyword = vec![kw, self.consume_keyword(TokenKind::Join)?];
                 join_type = ast::JoinType::Inner;
             } else if let Some(kw) = self.maybe_keyword(TokenKind::Left) {
@@ -880,12 +1834,31 @@ impl<'a> Parser<'a> {
                     join_keyword = vec![kw, self.consume_keyword(TokenKind::Join)?];
                     join_type = ast::JoinType::Full;
                 }
+            } else if let Some(kw) = self.maybe_keyword(TokenKind::Cross) {
+                join_keyword = vec![kw, self.consume_keyword(TokenKind::Apply)?];
+                join_type = ast::JoinType::CrossApply;
+            } else if let Some(kw) = self.maybe_keyword(TokenKind::Outer) {
+                join_keyword = vec![kw, self.consume_keyword(TokenKind::Apply)?];
+                join_type = ast::JoinType::OuterApply;
+            } else if self.token_is(&TokenKind::Natural) {
+                (join_keyword, join_type) = self.parse_natural_join_keywords()?;
             } else {
                 break;
             }
 
             let table_source = self.parse_table_source()?;
 
+            let is_natural = matches!(
+                join_type,
+                ast::JoinType::Natural
+                    | ast::JoinType::NaturalLeft
+                    | ast::JoinType::NaturalRight
+                    | ast::JoinType::NaturalFull
+            );
+            if is_natural && self.token_is(&TokenKind::On) {
+                return self.parse_error(ParseErrorType::NaturalJoinWithCondition);
+            }
+
             if self.token_is(&TokenKind::On) {
                 let on_kw = self.consume_keyword(TokenKind::On)?;
                 let search_condition = self.parse_expression(Precedence::Lowest)?;
@@ -913,6 +1886,35 @@ impl<'a> Parser<'a> {
         Ok(joins)
     }
 
+    fn parse_natural_join_keywords(
+        &mut self,
+    ) -> Result<(Vec<Keyword>, ast::JoinType), ParseError<'a>> {
+        if self.dialect == Dialect::TSql {
+            return self.parse_error(ParseErrorType::NotSupportedInDialect {
+                feature: "NATURAL JOIN".to_string(),
+                dialect: self.dialect,
+            });
+        }
+
+        let natural_kw = self.consume_keyword(TokenKind::Natural)?;
+        let mut join_keyword = vec![natural_kw];
+        let join_type = if let Some(kw) = self.maybe_keyword(TokenKind::Left) {
+            join_keyword.push(kw);
+            ast::JoinType::NaturalLeft
+        } else if let Some(kw) = self.maybe_keyword(TokenKind::Right) {
+            join_keyword.push(kw);
+            ast::JoinType::NaturalRight
+        } else if let Some(kw) = self.maybe_keyword(TokenKind::Full) {
+            join_keyword.push(kw);
+            ast::JoinType::NaturalFull
+        } else {
+            ast::JoinType::Natural
+        };
+        join_keyword.push(self.consume_keyword(TokenKind::Join)?);
+
+        Ok((join_keyword, join_type))
+    }
+
     fn parse_function(&mut self, name: ast::Expression) -> Result<ast::Expression, ParseError<'a>> {
         let function_name = match name {
             ast::Expression::Keyword(kw) => ast::FunctionName::Builtin(kw),
@@ -923,17 +1925,38 @@ impl<'a> Parser<'a> {
         };
 
         let left_paren: Symbol = self.expect_token(&TokenKind::LeftParen)?.into();
+        let set_quantifier = self
+            .maybe_keyword(TokenKind::Distinct)
+            .or_else(|| self.maybe_keyword(TokenKind::All));
         let mut args = None;
-        if !self.token_is(&TokenKind::RightParen) {
+        if set_quantifier.is_some() || !self.token_is(&TokenKind::RightParen) {
             args = Some(self.parse_function_args()?);
         }
         let right_paren: Symbol = self.expect_token(&TokenKind::RightParen)?.into();
 
+        if let ast::FunctionName::Builtin(kw) = &function_name {
+            if let Some((min, max)) = kw.kind.builtin_fn_arity() {
+                let found = args.as_ref().map_or(0, Vec::len);
+                if found < min || found > max {
+                    return parse_error(
+                        ParseErrorType::WrongNumberOfFunctionArgs {
+                            function: kw.to_string().to_uppercase(),
+                            min,
+                            max,
+                            found,
+                        },
+                        kw.location,
+                    );
+                }
+            }
+        }
+
         if let Some(kw) = self.maybe_keyword(TokenKind::Over) {
             let over_clause = self.parse_function_over_clause(kw)?;
             return Ok(ast::Expression::Function {
                 name: Box::new(function_name),
                 left_paren,
+                set_quantifier,
                 args,
                 right_paren,
                 over: Some(Box::new(over_clause)),
@@ -943,6 +1966,7 @@ impl<'a> Parser<'a> {
         Ok(ast::Expression::Function {
             name: Box::new(function_name),
             left_paren,
+            set_quantifier,
             args,
             right_paren,
             over: None,
@@ -953,6 +1977,19 @@ impl<'a> Parser<'a> {
         let mut args = vec![];
 
         loop {
+            // a star argument like COUNT(*) must be the only argument
+            if self.token_is(&TokenKind::Asterisk) {
+                let asterisk: Symbol = self.expect_token(&TokenKind::Asterisk)?.into();
+                if !args.is_empty() || !self.token_is(&TokenKind::RightParen) {
+                    return parse_error(
+                        ParseErrorType::AsteriskWithOtherFunctionArgs,
+                        asterisk.location,
+                    );
+                }
+                args.push(ast::Expression::Asterisk(asterisk));
+                break;
+            }
+
             self.expect_function_args_start()?;
             let expr = self.parse_expression(Precedence::Lowest)?;
             args.push(expr);
@@ -960,7 +1997,7 @@ impl<'a> Parser<'a> {
             if self.token_is(&TokenKind::RightParen) {
                 break;
             }
-            self.advance()
+            self.consume_list_comma()?;
         }
 
         Ok(args)
@@ -995,7 +2032,12 @@ impl<'a> Parser<'a> {
         }
 
         let mut window_frame_clause = None;
-        if self.token_is_any(&[TokenKind::Rows, TokenKind::Range]) {
+        if self.token_is_any(&[
+            TokenKind::Rows,
+            TokenKind::Range,
+            TokenKind::Unbounded,
+            TokenKind::Current,
+        ]) {
             window_frame_clause = Some(self.parse_function_window_frame_clause()?);
         }
 
@@ -1014,8 +2056,17 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_function_partition_clause(&mut self) -> Result<Vec<ast::Expression>, ParseError<'a>> {
-        let mut args = vec![];
+        // the clause ended before any partition was given
+        if self.token_is_any(&[
+            TokenKind::RightParen,
+            TokenKind::Order,
+            TokenKind::Rows,
+            TokenKind::Range,
+        ]) {
+            return self.parse_error(ParseErrorType::EmptyPartitionByClause);
+        }
 
+        let mut args = vec![];
         loop {
             self.expect_partition_by_start()?;
             let expr = self.parse_expression(Precedence::Lowest)?;
@@ -1024,11 +2075,7 @@ impl<'a> Parser<'a> {
             if !self.token_is(&TokenKind::Comma) {
                 break;
             }
-            self.advance();
-        }
-
-        if args.is_empty() {
-            return self.parse_error(ParseErrorType::EmptyPartitionByClause);
+            self.consume_list_comma()?;
         }
 
         Ok(args)
@@ -1041,28 +2088,27 @@ impl<'a> Parser<'a> {
             self.expect_order_by_args_start()?;
             let expr = self.parse_expression(Precedence::Lowest)?;
 
-            let item = if let Some(kw) = self.maybe_keyword(TokenKind::Asc) {
-                ast::OrderByArg {
-                    column: expr,
-                    order_kw: Some(kw),
-                }
-            } else if let Some(kw) = self.maybe_keyword(TokenKind::Desc) {
-                ast::OrderByArg {
-                    column: expr,
-                    order_kw: Some(kw),
-                }
+            let order_kw = if let Some(kw) = self.maybe_keyword(TokenKind::Asc) {
+                Some(kw)
             } else {
-                ast::OrderByArg {
-                    column: expr,
-                    order_kw: None,
-                }
+                self.maybe_keyword(TokenKind::Desc)
+            };
+            let nulls_kws = if self.token_is(&TokenKind::Nulls) {
+                Some(self.parse_nulls_ordering()?)
+            } else {
+                None
+            };
+            let item = ast::OrderByArg {
+                column: expr,
+                order_kw,
+                nulls_kws,
             };
 
             items.push(item);
             if !self.token_is(&TokenKind::Comma) {
                 break;
             }
-            self.advance();
+            self.consume_list_comma()?;
         }
 
         if items.is_empty() {
@@ -1072,6 +2118,24 @@ impl<'a> Parser<'a> {
         Ok(items)
     }
 
+    fn parse_nulls_ordering(&mut self) -> Result<Vec<Keyword>, ParseError<'a>> {
+        if self.dialect == Dialect::TSql {
+            return self.parse_error(ParseErrorType::NotSupportedInDialect {
+                feature: "NULLS FIRST/LAST ordering".to_string(),
+                dialect: self.dialect,
+            });
+        }
+
+        let nulls_kw = self.consume_keyword(TokenKind::Nulls)?;
+        let first_or_last_kw = if let Some(kw) = self.maybe_keyword(TokenKind::First) {
+            kw
+        } else {
+            self.consume_keyword(TokenKind::Last)?
+        };
+
+        Ok(vec![nulls_kw, first_or_last_kw])
+    }
+
     fn parse_function_window_frame_clause(&mut self) -> Result<ast::WindowFrame, ParseError<'a>> {
         let rows_or_range_kw;
         let rows_or_range;
@@ -1137,7 +2201,7 @@ impl<'a> Parser<'a> {
             let expr = self.parse_expression(Precedence::Lowest)?;
             let following_kw = self.consume_keyword(TokenKind::Following)?;
             end_bound_keywords = vec![following_kw];
-            window_frame_bound_end = ast::WindowFrameBound::Preceding(expr);
+            window_frame_bound_end = ast::WindowFrameBound::Following(expr);
         } else {
             return self.parse_error(
                 ParseErrorType::ExpectedUnboundedFollowingCurrentRowOrNumberFollowing,
@@ -1169,7 +2233,7 @@ impl<'a> Parser<'a> {
             if !self.token_is(&TokenKind::Comma) {
                 break;
             }
-            self.advance();
+            self.consume_list_comma()?;
         }
 
         if expressions.is_empty() {
@@ -1215,6 +2279,52 @@ impl<'a> Parser<'a> {
         })
     }
 
+    fn parse_distinct_on(&mut self) -> Result<(Keyword, ast::ExpressionList), ParseError<'a>> {
+        if self.dialect == Dialect::TSql {
+            return self.parse_error(ParseErrorType::NotSupportedInDialect {
+                feature: "DISTINCT ON".to_string(),
+                dialect: self.dialect,
+            });
+        }
+
+        let on_kw = self.consume_keyword(TokenKind::On)?;
+        let left_paren: Symbol = self.expect_token(&TokenKind::LeftParen)?.into();
+        let items = self.parse_expression_list()?;
+        let right_paren: Symbol = self.expect_token(&TokenKind::RightParen)?.into();
+
+        Ok((
+            on_kw,
+            ast::ExpressionList {
+                left_paren,
+                items,
+                right_paren,
+            },
+        ))
+    }
+
+    fn parse_limit_clause(&mut self) -> Result<ast::LimitClause, ParseError<'a>> {
+        if self.dialect == Dialect::TSql {
+            return self.parse_error(ParseErrorType::NotSupportedInDialect {
+                feature: "LIMIT".to_string(),
+                dialect: self.dialect,
+            });
+        }
+
+        let limit_kw = self.consume_keyword(TokenKind::Limit)?;
+        let value = self.parse_expression(Precedence::Lowest)?;
+        let offset = if let Some(offset_kw) = self.maybe_keyword(TokenKind::Offset) {
+            Some((offset_kw, self.parse_expression(Precedence::Lowest)?))
+        } else {
+            None
+        };
+
+        Ok(ast::LimitClause {
+            limit_kw,
+            value,
+            offset,
+        })
+    }
+
     fn parse_offset_fetch_clause(
         &mut self,
         offset_kw: Keyword,
@@ -1350,7 +2460,7 @@ impl<'a> Parser<'a> {
         } else if self.token_is(&TokenKind::Float) {
             let keyword = Keyword::try_from(self.peek_token)?;
             self.advance();
-            let data_type_size = self.parse_data_type_size()?;
+            let data_type_size = self.parse_data_type_size(false)?;
             ast::DataType::Float(keyword, data_type_size)
         } else if self.token_is(&TokenKind::Decimal) {
             let keyword = Keyword::try_from(self.peek_token)?;
@@ -1365,8 +2475,23 @@ impl<'a> Parser<'a> {
         } else if self.token_is(&TokenKind::Varchar) {
             let keyword = Keyword::try_from(self.peek_token)?;
             self.advance();
-            let data_type_size = self.parse_data_type_size()?;
+            let data_type_size = self.parse_data_type_size(true)?;
             ast::DataType::Varchar(keyword, data_type_size)
+        } else if self.token_is(&TokenKind::Nvarchar) {
+            let keyword = Keyword::try_from(self.peek_token)?;
+            self.advance();
+            let data_type_size = self.parse_data_type_size(true)?;
+            ast::DataType::Nvarchar(keyword, data_type_size)
+        } else if self.token_is(&TokenKind::Char) {
+            let keyword = Keyword::try_from(self.peek_token)?;
+            self.advance();
+            let data_type_size = self.parse_data_type_size(false)?;
+            ast::DataType::Char(keyword, data_type_size)
+        } else if self.token_is(&TokenKind::Nchar) {
+            let keyword = Keyword::try_from(self.peek_token)?;
+            self.advance();
+            let data_type_size = self.parse_data_type_size(false)?;
+            ast::DataType::Nchar(keyword, data_type_size)
         } else {
             return self.parse_error(ParseErrorType::ExpectedDataType);
         };
@@ -1374,52 +2499,53 @@ impl<'a> Parser<'a> {
         Ok(data_type)
     }
 
-    fn parse_data_type_size(&mut self) -> Result<Option<DataTypeSize>, ParseError<'a>> {
-        if self.token_is(&TokenKind::LeftParen) {
-            let left_paren: Symbol = self.expect_token(&TokenKind::LeftParen)?.into();
-            let numeric_literal = self.expect_token(&TokenKind::NumberLiteral(""))?.into();
-            let size: u32 = match numeric_literal {
-                ast::Expression::NumberLiteral(n) => match n.content.parse() {
-                    Ok(n) => n,
-                    Err(_) => return self.parse_error(ParseErrorType::ExpectedDataTypeSize),
-                },
-                _ => return self.parse_error(ParseErrorType::ExpectedDataTypeSize),
-            };
-            let right_paren: Symbol = self.expect_token(&TokenKind::RightParen)?.into();
-            Ok(Some(DataTypeSize {
-                left_paren,
-                size,
-                right_paren,
-            }))
-        } else {
-            Ok(None)
+    fn parse_data_type_size(
+        &mut self,
+        allow_max: bool,
+    ) -> Result<Option<DataTypeSize>, ParseError<'a>> {
+        if !self.token_is(&TokenKind::LeftParen) {
+            return Ok(None);
         }
+        let left_paren: Symbol = self.expect_token(&TokenKind::LeftParen)?.into();
+        let size = if allow_max && self.token_is(&TokenKind::Max) {
+            ast::DataTypeLength::Max(self.consume_keyword(TokenKind::Max)?)
+        } else {
+            ast::DataTypeLength::Number(self.parse_size_number()?)
+        };
+        let right_paren: Symbol = self.expect_token(&TokenKind::RightParen)?.into();
+        Ok(Some(DataTypeSize {
+            left_paren,
+            size,
+            right_paren,
+        }))
     }
 
-    fn parse_float_precision(&mut self) -> Result<u32, ParseError<'a>> {
-        let numeric_literal: ast::Expression =
-            self.expect_token(&TokenKind::NumberLiteral(""))?.into();
-        let size: u32 = match numeric_literal {
-            ast::Expression::NumberLiteral(n) => match n.content.parse() {
-                Ok(n) => n,
-                Err(_) => return self.parse_error(ParseErrorType::ExpectedDataTypeSize),
-            },
-            _ => return self.parse_error(ParseErrorType::ExpectedDataTypeSize),
+    // reads the number inside the parentheses of a data type like VARCHAR(50)
+    // or DECIMAL(18, 4), anything else means the size is missing
+    fn parse_size_number(&mut self) -> Result<u32, ParseError<'a>> {
+        let Some(token) = self.peek_token else {
+            return self.parse_error(ParseErrorType::ExpectedDataTypeSize);
+        };
+        let TokenKind::NumberLiteral(number) = token.kind() else {
+            return self.parse_error(ParseErrorType::ExpectedDataTypeSize);
         };
+        let Ok(size) = number.parse() else {
+            return self.parse_error(ParseErrorType::ExpectedDataTypeSize);
+        };
+        self.advance();
         Ok(size)
     }
 
     fn parse_numeric_size(&mut self) -> Result<Option<ast::NumericSize>, ParseError<'a>> {
-        let left_paren: Symbol = self.expect_token(&TokenKind::LeftParen)?.into();
-        if self.token_is(&TokenKind::RightParen) {
+        if !self.token_is(&TokenKind::LeftParen) {
             return Ok(None);
         }
-
-        let float_precision = self.parse_float_precision()?;
+        let left_paren: Symbol = self.expect_token(&TokenKind::LeftParen)?.into();
+        let float_precision = self.parse_size_number()?;
 
         let scale = if self.token_is(&TokenKind::Comma) {
             let _ = self.expect_token(&TokenKind::Comma)?;
-            Some(self.parse_numeric_scale()?)
+            Some(self.parse_size_number()?)
         } else {
             None
         };
@@ -1434,20 +2560,6 @@ impl<'a> Parser<'a> {
         }))
     }
 
-    fn parse_numeric_scale(&mut self) -> Result<u32, ParseError<'a>> {
-        let numeric_literal: ast::Expression =
-            self.expect_token(&TokenKind::NumberLiteral(""))?.into();
-        let size: u32 = match numeric_literal {
-            ast::Expression::NumberLiteral(n) => match n.content.parse() {
-                Ok(n) => n,
-                Err(_) => return self.parse_error(ParseErrorType::ExpectedDataTypeSize),
-            },
-            _ => return self.parse_error(ParseErrorType::ExpectedDataTypeSize),
-        };
-
-        Ok(size)
-    }
-
     fn parse_expression_list(&mut self) -> Result<Vec<ast::Expression>, ParseError<'a>> {
         let mut expressions = vec![];
 
@@ -1459,20 +2571,68 @@ impl<'a> Parser<'a> {
             if !self.token_is(&TokenKind::Comma) {
                 break;
             }
-            self.advance();
+            self.consume_list_comma()?;
         }
 
         Ok(expressions)
     }
 
+    // parses the statement inside the parentheses of a subquery, sql server
+    // only lets a subquery be ordered when the order picks its rows
+    fn parse_subquery_statement(&mut self) -> Result<ast::Statement, ParseError<'a>> {
+        let statement = self.parse_select_or_set_operation()?;
+        if let ast::Statement::Select(select) = &statement {
+            if let Some(order_by) = &select.order_by {
+                if select.top.is_none()
+                    && select.limit.is_none()
+                    && order_by.offset_fetch_clause.is_none()
+                {
+                    let start = order_by.order_by_kws.first().map(|kw| kw.location);
+                    let end = order_by.order_by_kws.last().map(|kw| kw.location);
+                    if let (Some(start), Some(end)) = (start, end) {
+                        self.parse_warnings.push(ParseWarning {
+                            warning: ParseWarningType::OrderByInSubqueryWithoutTop,
+                            span: Span::new(start.start, end.end),
+                        });
+                    }
+                }
+            }
+        }
+
+        Ok(statement)
+    }
+
     fn parse_subquery(&mut self) -> Result<ast::Expression, ParseError<'a>> {
         let left_paren: Symbol = self.expect_token(&TokenKind::LeftParen)?.into();
-        let select_statement = self.parse_select_statement()?;
+        let statement = self.parse_subquery_statement()?;
         let right_paren: Symbol = self.expect_token(&TokenKind::RightParen)?.into();
 
         Ok(ast::Expression::Subquery {
             left_paren,
-            select_statement: Box::new(select_statement),
+            statement: Box::new(statement),
+            right_paren,
+        })
+    }
+
+    fn parse_subquery_or_grouping(&mut self) -> Result<ast::Expression, ParseError<'a>> {
+        let left_paren: Symbol = self.expect_token(&TokenKind::LeftParen)?.into();
+        if self.token_is(&TokenKind::Select) {
+            let statement = self.parse_subquery_statement()?;
+            let right_paren: Symbol = self.expect_token(&TokenKind::RightParen)?.into();
+
+            return Ok(ast::Expression::Subquery {
+                left_paren,
+                statement: Box::new(statement),
+                right_paren,
+            });
+        }
+
+        let expression = self.parse_expression(Precedence::Lowest)?;
+        let right_paren: Symbol = self.expect_token(&TokenKind::RightParen)?.into();
+
+        Ok(ast::Expression::Grouping {
+            left_paren,
+            expression: Box::new(expression),
             right_paren,
         })
     }
@@ -1485,11 +2645,11 @@ impl<'a> Parser<'a> {
     ) -> Result<ast::Expression, ParseError<'a>> {
         let left_paren: Symbol = self.expect_token(&TokenKind::LeftParen)?.into();
         let expr = if self.token_is(&TokenKind::Select) {
-            let select_statement = self.parse_select_statement()?;
+            let statement = self.parse_subquery_statement()?;
             let right_paren: Symbol = self.expect_token(&TokenKind::RightParen)?.into();
             let subquery = ast::Expression::Subquery {
                 left_paren,
-                select_statement: Box::new(select_statement),
+                statement: Box::new(statement),
                 right_paren,
             };
             ast::Expression::InSubquery {
@@ -1522,9 +2682,11 @@ impl<'a> Parser<'a> {
         not_kw: Option<Keyword>,
         between_kw: Keyword,
     ) -> Result<ast::Expression, ParseError<'a>> {
-        let begin = self.parse_prefix_expression()?;
+        // the bounds bind tighter than AND so the separator is not taken as a
+        // logical AND
+        let begin = self.parse_expression(Precedence::And)?;
         let and_kw = self.consume_keyword(TokenKind::And)?;
-        let end = self.parse_prefix_expression()?;
+        let end = self.parse_expression(Precedence::And)?;
 
         Ok(ast::Expression::Between {
             test_expression: Box::new(test_expression),
@@ -1548,14 +2710,56 @@ impl<'a> Parser<'a> {
             match_expression: Box::new(match_expression),
             not_kw,
             like_kw,
+            case_insensitive: like_kw.kind == ast::KeywordKind::Ilike,
             pattern: Box::new(expr),
         })
     }
 
+    fn parse_subscript_expression(
+        &mut self,
+        base: ast::Expression,
+    ) -> Result<ast::Expression, ParseError<'a>> {
+        // under t-sql `[` followed by an identifier is lexed as a quoted
+        // identifier, so only subscripts like `data['key']` or `arr[0]` reach
+        // this point
+        if self.dialect == Dialect::TSql {
+            return self.parse_error(ParseErrorType::NotSupportedInDialect {
+                feature: "subscript access".to_string(),
+                dialect: self.dialect,
+            });
+        }
+
+        let left_bracket: Symbol = self.expect_token(&TokenKind::LeftBracket)?.into();
+        let index = self.parse_expression(Precedence::Lowest)?;
+        let right_bracket: Symbol = self.expect_token(&TokenKind::RightBracket)?.into();
+
+        Ok(ast::Expression::Subscript {
+            base: Box::new(base),
+            left_bracket,
+            index: Box::new(index),
+            right_bracket,
+        })
+    }
+
+    fn parse_like_keyword(&mut self) -> Result<Keyword, ParseError<'a>> {
+        if self.token_is(&TokenKind::Ilike) && self.dialect == Dialect::TSql {
+            let like_kw = Keyword::try_from(self.peek_token)?;
+            return self.parse_error(ParseErrorType::NotSupportedInDialect {
+                feature: like_kw.kind.to_string().to_uppercase(),
+                dialect: self.dialect,
+            });
+        }
+
+        let like_kw = Keyword::try_from(self.peek_token)?;
+        self.advance();
+        Ok(like_kw)
+    }
+
     fn parse_case_expression(&mut self) -> Result<ast::Expression, ParseError<'a>> {
         let case_kw = self.consume_keyword(TokenKind::Case)?;
         if self.token_is(&TokenKind::When) {
-            let conditions = self.parse_case_expressions()?;
+            // each when of a searched case holds a boolean expression
+            let conditions = self.parse_case_expressions(Precedence::Lowest)?;
             let end_kw = self.consume_keyword(TokenKind::End)?;
             Ok(ast::Expression::SearchedCase {
                 case_kw,
@@ -1563,8 +2767,10 @@ impl<'a> Parser<'a> {
                 end_kw,
             })
         } else {
-            let input_expr = self.parse_expression(Precedence::Lowest)?;
-            let conditions = self.parse_case_expressions()?;
+            // the input and each when of a simple case hold values that are
+            // compared for equality so they stop before any comparison
+            let input_expr = self.parse_expression(Precedence::Comparison)?;
+            let conditions = self.parse_case_expressions(Precedence::Comparison)?;
             let end_kw = self.consume_keyword(TokenKind::End)?;
             Ok(ast::Expression::SimpleCase {
                 case_kw,
@@ -1575,12 +2781,15 @@ impl<'a> Parser<'a> {
         }
     }
 
-    fn parse_case_expressions(&mut self) -> Result<Vec<ast::CaseCondition>, ParseError<'a>> {
+    fn parse_case_expressions(
+        &mut self,
+        when_precedence: Precedence,
+    ) -> Result<Vec<ast::CaseCondition>, ParseError<'a>> {
         let mut conditions = vec![];
 
         loop {
             let when_kw = self.consume_keyword(TokenKind::When)?;
-            let when_expr = self.parse_expression(Precedence::Lowest)?;
+            let when_expr = self.parse_expression(when_precedence)?;
             let then_kw = self.consume_keyword(TokenKind::Then)?;
             let result_expr = self.parse_expression(Precedence::Lowest)?;
 
@@ -1609,11 +2818,35 @@ impl<'a> Parser<'a> {
     fn parse_expression(
         &mut self,
         precedence: Precedence,
+    ) -> Result<ast::Expression, ParseError<'a>> {
+        if self.expression_depth >= self.max_expression_depth {
+            return self.parse_error(ParseErrorType::ExpressionTooDeeplyNested {
+                max_depth: self.max_expression_depth,
+            });
+        }
+
+        self.expression_depth += 1;
+        let expression = self.parse_nested_expression(precedence);
+        self.expression_depth -= 1;
+        expression
+    }
+
+    fn parse_nested_expression(
+        &mut self,
+        precedence: Precedence,
     ) -> Result<ast::Expression, ParseError<'a>> {
         // check if the current token is an identifier
         // or if it is a prefix operator
         let mut left_expression = self.parse_prefix_expression()?;
 
+        // a qualified wildcard like t.* stands for columns, not a value, so it
+        // can't be the operand of an operator
+        if let Some(asterisk) = qualified_wildcard_asterisk(&left_expression) {
+            if precedence > Precedence::Lowest || precedence < self.peek_precedence() {
+                return parse_error(ParseErrorType::WildcardAsOperand, asterisk.location);
+            }
+        }
+
         // parse the infix expression
         while precedence < self.peek_precedence() {
             left_expression = self.parse_infix_expression(left_expression)?;
@@ -1629,7 +2862,6 @@ impl<'a> Parser<'a> {
             TokenKind::NumberLiteral(""),
             TokenKind::StringLiteral(""),
             TokenKind::LocalVariable(""),
-            TokenKind::Asterisk,
         ]) {
             let mut expr = ast::Expression::try_from(self.peek_token)?;
 
@@ -1644,56 +2876,99 @@ impl<'a> Parser<'a> {
                 expr = self.parse_compound_identifier(expr)?;
             }
 
-            // parse user defined function
-            if self.token_is(&TokenKind::LeftParen) {
+            // parse user defined function, only a name can be called so a
+            // literal or variable followed by a paren is left for the caller
+            if could_be_compound && self.token_is(&TokenKind::LeftParen) {
                 return Ok(self.parse_function(expr)?);
             }
 
             // self.advance();
             return Ok(expr);
+        } else if let Some(mut expr) = self.keyword_as_identifier() {
+            self.advance();
+            if self.token_is(&TokenKind::Period) {
+                expr = self.parse_compound_identifier(expr)?;
+            }
+            return Ok(expr);
         } else if self.token_is_any(BUILTIN_FN_START) {
-            let fn_name = ast::Expression::Keyword(ast::Keyword::try_from(self.peek_token)?);
+            let fn_kw = ast::Keyword::try_from(self.peek_token)?;
 
             self.advance();
-            // parse user defined function
             if self.token_is(&TokenKind::LeftParen) {
-                return Ok(self.parse_function(fn_name)?);
+                return Ok(self.parse_function(ast::Expression::Keyword(fn_kw))?);
             }
 
-            self.unexpected_token(vec![TokenKind::LeftParen.string_type().to_string()])?
+            // without a call the name is a column that shares its name with
+            // the function, like the count in SELECT count FROM t
+            return Ok(ast::Expression::Identifier(ast::Literal {
+                location: fn_kw.location,
+                content: fn_kw.to_string(),
+            }));
         } else if self.token_is_any(&[TokenKind::Minus, TokenKind::Plus]) {
             let unary_op = ast::UnaryOperator::try_from(self.peek_token)?;
 
             self.advance();
-            let right_expr = ast::Expression::try_from(self.peek_token)?;
-            match right_expr {
-                ast::Expression::NumberLiteral(_) => {}
-                _ => return self.unexpected_token(vec!["numeric literal".to_string()]),
+            // a minus written right against a number is part of the literal,
+            // -5 is a negative number while - 5 and -x stay unary
+            if let Some(token) = self.peek_token {
+                if let TokenKind::NumberLiteral(number) = token.kind() {
+                    let location = token.location();
+                    if unary_op.kind == ast::UnaryOperatorKind::Minus
+                        && unary_op.location.end + 1 == location.start
+                    {
+                        self.advance();
+                        return Ok(ast::Expression::NumberLiteral(ast::Literal {
+                            location: Span::new(unary_op.location.start, location.end),
+                            content: format!("-{number}"),
+                        }));
+                    }
+                }
             }
+            // unary operators bind tighter than any infix operator
+            let right_expr = self.parse_expression(Precedence::Highest)?;
 
             return Ok(ast::Expression::Unary {
                 operator: unary_op,
                 right: Box::new(right_expr),
             });
+        } else if self.token_is_any(&[TokenKind::Null, TokenKind::True, TokenKind::False]) {
+            // null and the boolean literals are kept as keywords in the tree
+            let literal = ast::Keyword::try_from(self.peek_token)?;
+            self.advance();
+            return Ok(ast::Expression::Keyword(literal));
         } else if self.token_is(&TokenKind::Cast) {
             let expr = self.parse_cast_expression()?;
             return Ok(expr);
         } else if self.token_is(&TokenKind::LeftParen) {
-            let subquery = self.parse_subquery()?;
-            return Ok(subquery);
+            let expr = self.parse_subquery_or_grouping()?;
+            return Ok(expr);
         } else if self.token_is(&TokenKind::Not) {
             let not_kw = self.consume_keyword(TokenKind::Not)?;
-            let expression = self.parse_expression(Precedence::Lowest)?;
+            // not binds tighter than and/or but looser than comparisons
+            let expression = self.parse_expression(Precedence::Not)?;
             return Ok(ast::Expression::Not {
                 not_kw,
                 expression: Box::new(expression),
             });
         } else if self.token_is(&TokenKind::Exists) {
             let exists_kw = self.consume_keyword(TokenKind::Exists)?;
-            let subquery = self.parse_subquery()?;
+            if !self.token_is(&TokenKind::LeftParen) {
+                return self.parse_error(ParseErrorType::ExpectedSubqueryOrExpressionList);
+            }
+            let left_paren: Symbol = self.expect_token(&TokenKind::LeftParen)?.into();
+            if !self.token_is(&TokenKind::Select) {
+                return self.parse_error(ParseErrorType::ExpectedSubqueryOrExpressionList);
+            }
+            let statement = self.parse_subquery_statement()?;
+            let right_paren: Symbol = self.expect_token(&TokenKind::RightParen)?.into();
+
             return Ok(ast::Expression::Exists {
                 exists_kw,
-                subquery: Box::new(subquery),
+                subquery: Box::new(ast::Expression::Subquery {
+                    left_paren,
+                    statement: Box::new(statement),
+                    right_paren,
+                }),
             });
         } else if self.token_is(&TokenKind::Case) {
             let case_expr = self.parse_case_expression()?;
@@ -1707,7 +2982,9 @@ impl<'a> Parser<'a> {
         &mut self,
         left: ast::Expression,
     ) -> Result<ast::Expression, ParseError<'a>> {
-        if self.token_is(&TokenKind::And) {
+        if self.token_is(&TokenKind::LeftBracket) {
+            return self.parse_subscript_expression(left);
+        } else if self.token_is(&TokenKind::And) {
             let precedence = self.peek_precedence();
             let and_kw = self.consume_keyword(TokenKind::And)?;
             let right = self.parse_expression(precedence)?;
@@ -1797,8 +3074,8 @@ impl<'a> Parser<'a> {
         } else if self.token_is(&TokenKind::Between) {
             let between_kw = self.consume_keyword(TokenKind::Between)?;
             return Ok(self.parse_between_expression(left, None, between_kw)?);
-        } else if self.token_is(&TokenKind::Like) {
-            let like_kw = self.consume_keyword(TokenKind::Like)?;
+        } else if self.token_is_any(&[TokenKind::Like, TokenKind::Ilike]) {
+            let like_kw = self.parse_like_keyword()?;
             return Ok(self.parse_like_expression(left, None, like_kw)?);
         } else if self.token_is(&TokenKind::Not) {
             let not_kw = self.consume_keyword(TokenKind::Not)?;
@@ -1806,7 +3083,8 @@ impl<'a> Parser<'a> {
                 return Ok(self.parse_in_expression(left, in_kw, Some(not_kw))?);
             } else if let Some(between_kw) = self.maybe_keyword(TokenKind::Between) {
                 return Ok(self.parse_between_expression(left, Some(not_kw), between_kw)?);
-            } else if let Some(like_kw) = self.maybe_keyword(TokenKind::Like) {
+            } else if self.token_is_any(&[TokenKind::Like, TokenKind::Ilike]) {
+                let like_kw = self.parse_like_keyword()?;
                 return Ok(self.parse_like_expression(left, Some(not_kw), like_kw)?);
             } else {
                 return self.parse_error(ParseErrorType::ExpectedSubqueryOrExpressionList);
@@ -1816,3 +3094,99 @@ impl<'a> Parser<'a> {
         self.unexpected_token(vec!["expression".to_string()])
     }
 }
+
+// a select item of the form `column_alias = expression` reads as an equality
+// the location of the INTO of the first select in a select or set operation
+fn first_into_location(statement: &ast::Statement) -> Option<Span> {
+    match statement {
+        ast::Statement::Select(select) => {
+            select.into_table.as_ref().map(|into| into.into_kw.location)
+        }
+        ast::Statement::SetOperation { left, .. } => first_into_location(left),
+        _ => None,
+    }
+}
+
+// whether a statement keyword is part of the statement that started with
+// `statement_start` rather than the start of the next one, given the token
+// before it
+fn continues_statement(
+    statement_start: Option<TokenKind>,
+    previous: Option<TokenKind>,
+    kind: TokenKind,
+) -> bool {
+    match (previous, kind) {
+        // the actions of a merge, like WHEN MATCHED THEN DELETE
+        (Some(TokenKind::Then), TokenKind::Insert | TokenKind::Update | TokenKind::Delete) => {
+            return true
+        }
+        (Some(TokenKind::Update), TokenKind::Set) => return true,
+        // the right side of a set operation
+        (
+            Some(TokenKind::Union | TokenKind::Except | TokenKind::Intersect | TokenKind::All),
+            TokenKind::Select,
+        ) => return true,
+        _ => {}
+    }
+    matches!(
+        (statement_start, kind),
+        (Some(TokenKind::Insert), TokenKind::Select | TokenKind::With)
+            | (Some(TokenKind::Update), TokenKind::Set)
+            | (
+                Some(TokenKind::With),
+                TokenKind::Select | TokenKind::Insert | TokenKind::Update | TokenKind::Delete
+            )
+    )
+}
+
+// comparison, but a comparison is never a valid select item on its own, so when
+// the left side can name a column the item is split back into the alias and
+// the expression it names, e.g. `IsActive = (status = 1)`
+fn split_alias_assignment(
+    expression: ast::Expression,
+) -> (Option<ast::Expression>, ast::Expression) {
+    match expression {
+        ast::Expression::Comparison {
+            operator,
+            left,
+            right,
+        } if operator.kind == ast::ComparisonOperatorKind::Equal
+            && matches!(
+                *left,
+                ast::Expression::Identifier(..)
+                    | ast::Expression::QuotedIdentifier(..)
+                    | ast::Expression::StringLiteral(..)
+                    | ast::Expression::LocalVariable(..)
+            ) =>
+        {
+            (Some(*left), *right)
+        }
+        expression => (None, expression),
+    }
+}
+
+// the span of a constant TOP quantity like `10`, `- 5` or `(2 * 50)`
+fn top_quantity_span(expression: &ast::Expression) -> Span {
+    match expression {
+        ast::Expression::NumberLiteral(literal) => literal.location,
+        ast::Expression::Unary { operator, right } => {
+            Span::new(operator.location.start, top_quantity_span(right).end)
+        }
+        ast::Expression::Grouping {
+            left_paren,
+            right_paren,
+            ..
+        } => Span::new(left_paren.location.start, right_paren.location.end),
+        _ => Span::default(),
+    }
+}
+
+fn qualified_wildcard_asterisk(expression: &ast::Expression) -> Option<&Symbol> {
+    match expression {
+        ast::Expression::Compound(parts) => match parts.last() {
+            Some(ast::Expression::Asterisk(asterisk)) => Some(asterisk),
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -16,7 +16,10 @@ pub enum Precedence {
 
 pub fn get_precedence(token: &TokenKind) -> Precedence {
     match token {
-        TokenKind::Asterisk | TokenKind::ForwardSlash => Precedence::Product,
+        TokenKind::LeftBracket => Precedence::Highest,
+        TokenKind::Asterisk | TokenKind::ForwardSlash | TokenKind::PercentSign => {
+            Precedence::Product
+        }
         TokenKind::Plus | TokenKind::Minus => Precedence::Sum,
         TokenKind::Equal
         | TokenKind::BangEqual
@@ -24,16 +27,17 @@ pub fn get_precedence(token: &TokenKind) -> Precedence {
         | TokenKind::LessThan
         | TokenKind::LessThanEqual
         | TokenKind::GreaterThan
-        | TokenKind::GreaterThanEqual => Precedence::Comparison,
-        TokenKind::Not => Precedence::Not,
-        TokenKind::And => Precedence::And,
-        TokenKind::All
-        | TokenKind::Any
+        | TokenKind::GreaterThanEqual
         | TokenKind::Between
         | TokenKind::In
         | TokenKind::Like
-        | TokenKind::Or
-        | TokenKind::Some => Precedence::OtherLogicals,
+        | TokenKind::Ilike
+        // infix not only starts not in, not between and not like
+        | TokenKind::Not => Precedence::Comparison,
+        TokenKind::And => Precedence::And,
+        TokenKind::All | TokenKind::Any | TokenKind::Or | TokenKind::Some => {
+            Precedence::OtherLogicals
+        }
         _ => Precedence::Lowest,
     }
 }
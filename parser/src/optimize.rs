@@ -0,0 +1,83 @@
+use lexer::Span;
+
+use crate::ast::{ArithmeticOperatorKind, Expression, Literal};
+use crate::visitor_mut::{walk_expression_mut, VisitorMut};
+
+/// Evaluates arithmetic between number literals ahead of time, e.g. `2 + 3 * 4`
+/// becomes `14`. Subtrees that are not constant are left as they are.
+pub fn fold_constants(mut expr: Expression) -> Expression {
+    ConstantFolder.visit_expression(&mut expr);
+    expr
+}
+
+struct ConstantFolder;
+
+impl VisitorMut for ConstantFolder {
+    type Result = ();
+
+    fn visit_expression(&mut self, expr: &mut Expression) {
+        // fold the operands first so constants bubble up from the bottom of
+        // the tree
+        walk_expression_mut(self, expr);
+
+        if let Some(folded) = fold(expr) {
+            *expr = Expression::NumberLiteral(folded);
+        }
+    }
+}
+
+fn fold(expr: &Expression) -> Option<Literal> {
+    match expr {
+        Expression::Arithmetic {
+            operator,
+            left,
+            right,
+        } => {
+            let (Expression::NumberLiteral(left), Expression::NumberLiteral(right)) =
+                (left.as_ref(), right.as_ref())
+            else {
+                return None;
+            };
+            let value = evaluate(operator.kind, integer(left)?, integer(right)?)?;
+
+            Some(Literal {
+                location: Span::new(left.location.start, right.location.end),
+                content: value.to_string(),
+            })
+        }
+        // a folded grouping no longer needs its parentheses
+        Expression::Grouping {
+            left_paren,
+            expression,
+            right_paren,
+        } => match expression.as_ref() {
+            Expression::NumberLiteral(literal) => Some(Literal {
+                location: Span::new(left_paren.location.start, right_paren.location.end),
+                content: literal.content.clone(),
+            }),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// only int literals are folded, decimal arithmetic depends on the precision
+// and scale sql server picks for the result so it is left for the server to do.
+// that includes whole numbers past the int range, sql server types those as
+// decimal rather than bigint
+fn integer(literal: &Literal) -> Option<i32> {
+    literal.content.parse().ok()
+}
+
+fn evaluate(operator: ArithmeticOperatorKind, left: i32, right: i32) -> Option<i32> {
+    // int arithmetic overflows in sql server, so checked arithmetic keeps the
+    // original node on overflow and division by zero and the error still
+    // surfaces when the query runs
+    match operator {
+        ArithmeticOperatorKind::Plus => left.checked_add(right),
+        ArithmeticOperatorKind::Minus => left.checked_sub(right),
+        ArithmeticOperatorKind::Multiply => left.checked_mul(right),
+        ArithmeticOperatorKind::Divide => left.checked_div(right),
+        ArithmeticOperatorKind::Modulus => left.checked_rem(right),
+    }
+}
@@ -1,14 +1,17 @@
 use lexer::Span;
 
 use crate::ast::{
-    ArithmeticOperator, ArithmeticOperatorKind, CaseCondition, CommonTableExpression,
-    CommonTableExpressionStatement, ComparisonOperator, ComparisonOperatorKind, DataType,
-    DataTypeSize, Expression, ExpressionList, FetchArg, FunctionName, GroupByClause, HavingClause,
-    InsertStatement, Join, JoinCondition, JoinType, Keyword, KeywordKind, Literal, LocalVariable,
-    NextOrFirst, NumericSize, OffsetArg, OffsetFetchClause, OrderByArg, OrderByClause, OverClause,
-    ProcedureParameter, ProcedureParameterName, Query, RowOrRows, RowsOrRange, SelectItem,
-    SelectStatement, Statement, Symbol, SymbolKind, TableArg, TableSource, Top, UnaryOperator,
-    UnaryOperatorKind, Union, WhereClause, WindowFrame, WindowFrameBound,
+    AlterIndexAction, ArithmeticOperator, ArithmeticOperatorKind, CaseCondition, ColumnConstraint,
+    ColumnDef, CommonTableExpression, CommonTableExpressionStatement, ComparisonOperator,
+    ComparisonOperatorKind, DataType, DataTypeLength, DataTypeSize, DeleteStatement, Expression,
+    ExpressionList, FetchArg, FunctionName, GroupByClause, HavingClause, IdentitySeedIncrement,
+    InsertStatement, IntoArg, Join, JoinCondition, JoinType, Keyword, KeywordKind, LimitClause,
+    Literal, LocalVariable, MergeAction, MergeClause, MergeStatement, NextOrFirst, NumericSize,
+    OffsetArg, OffsetFetchClause, OrderByArg, OrderByClause, OutputClause, OverClause,
+    PartitionRange, ProcedureParameter, ProcedureParameterName, Query, RowOrRows, RowsOrRange,
+    SelectItem, SelectStatement, Statement, Symbol, SymbolKind, TableArg, TableSource, Top,
+    TruncatePartitions, UnaryOperator, UnaryOperatorKind, UpdateStatement, WhereClause,
+    WindowFrame, WindowFrameBound,
 };
 
 pub trait Visitor: Sized {
@@ -26,8 +29,29 @@ pub trait Visitor: Sized {
     fn visit_insert_statement(&mut self, stmt: &InsertStatement) -> Self::Result {
         walk_insert_statement(self, stmt)
     }
-    fn visit_union(&mut self, union: &Union) -> Self::Result {
-        walk_union(self, union)
+    fn visit_update_statement(&mut self, stmt: &UpdateStatement) -> Self::Result {
+        walk_update_statement(self, stmt)
+    }
+    fn visit_delete_statement(&mut self, stmt: &DeleteStatement) -> Self::Result {
+        walk_delete_statement(self, stmt)
+    }
+    fn visit_merge_statement(&mut self, stmt: &MergeStatement) -> Self::Result {
+        walk_merge_statement(self, stmt)
+    }
+    fn visit_merge_clause(&mut self, clause: &MergeClause) -> Self::Result {
+        walk_merge_clause(self, clause)
+    }
+    fn visit_output_clause(&mut self, output: &OutputClause) -> Self::Result {
+        walk_output_clause(self, output)
+    }
+    fn visit_set_operation(
+        &mut self,
+        left: &Statement,
+        op: &Keyword,
+        all: &Option<Keyword>,
+        right: &Statement,
+    ) -> Self::Result {
+        walk_set_operation(self, left, op, all, right)
     }
     fn visit_select_statement(&mut self, stmt: &SelectStatement) -> Self::Result {
         walk_select_statement(self, stmt)
@@ -86,6 +110,9 @@ pub trait Visitor: Sized {
     }
     fn visit_data_type_size(&mut self, data_type_size: &DataTypeSize) -> Self::Result {
         walk_symbol(self, &data_type_size.left_paren);
+        if let DataTypeLength::Max(k) = &data_type_size.size {
+            self.visit_keyword(k);
+        }
         walk_symbol(self, &data_type_size.right_paren);
         Self::Result::output()
     }
@@ -100,6 +127,9 @@ pub trait Visitor: Sized {
     fn visit_select_item(&mut self, select_item: &SelectItem) -> Self::Result {
         walk_select_item(self, select_item)
     }
+    fn visit_into_arg(&mut self, into_arg: &IntoArg) -> Self::Result {
+        walk_into_arg(self, into_arg)
+    }
     fn visit_table_clause(&mut self, table_clause: &TableArg) -> Self::Result {
         walk_table_clause(self, table_clause)
     }
@@ -127,6 +157,9 @@ pub trait Visitor: Sized {
     fn visit_table_join_type(&mut self, _: &JoinType) -> Self::Result {
         Self::Result::output()
     }
+    fn visit_limit_clause(&mut self, limit_clause: &LimitClause) -> Self::Result {
+        walk_limit_clause(self, limit_clause)
+    }
     fn visit_order_by_arg(&mut self, order_by_arg: &OrderByArg) -> Self::Result {
         walk_order_by_arg(self, order_by_arg)
     }
@@ -200,6 +233,27 @@ pub trait Visitor: Sized {
     fn visit_local_variable(&mut self, local_variable: &LocalVariable) -> Self::Result {
         walk_local_variable(self, local_variable)
     }
+    fn visit_column_def(&mut self, column_def: &ColumnDef) -> Self::Result {
+        walk_column_def(self, column_def)
+    }
+    fn visit_column_constraint(&mut self, constraint: &ColumnConstraint) -> Self::Result {
+        walk_column_constraint(self, constraint)
+    }
+    fn visit_identity_seed_increment(
+        &mut self,
+        seed_increment: &IdentitySeedIncrement,
+    ) -> Self::Result {
+        walk_identity_seed_increment(self, seed_increment)
+    }
+    fn visit_truncate_partitions(&mut self, partitions: &TruncatePartitions) -> Self::Result {
+        walk_truncate_partitions(self, partitions)
+    }
+    fn visit_partition_range(&mut self, partition: &PartitionRange) -> Self::Result {
+        walk_partition_range(self, partition)
+    }
+    fn visit_alter_index_action(&mut self, action: &AlterIndexAction) -> Self::Result {
+        walk_alter_index_action(self, action)
+    }
 }
 
 pub trait VisitorResult {
@@ -307,12 +361,14 @@ pub fn walk_expression<V: Visitor>(visitor: &mut V, expression: &Expression) ->
         Expression::Function {
             name,
             left_paren,
+            set_quantifier,
             args,
             right_paren,
             over,
         } => {
             visitor.visit_function_name(name);
             visitor.visit_symbol(left_paren);
+            walk_opt!(visitor, visit_keyword, set_quantifier);
             walk_opt_list!(visitor, visit_expression, args);
             visitor.visit_symbol(right_paren);
             walk_opt!(visitor, visit_function_over_clause, over);
@@ -364,13 +420,33 @@ pub fn walk_expression<V: Visitor>(visitor: &mut V, expression: &Expression) ->
         }
         Expression::Subquery {
             left_paren,
-            select_statement,
+            statement,
+            right_paren,
+        } => {
+            visitor.visit_symbol(left_paren);
+            visitor.visit_statement(statement);
+            visitor.visit_symbol(right_paren)
+        }
+        Expression::Grouping {
+            left_paren,
+            expression,
             right_paren,
         } => {
             visitor.visit_symbol(left_paren);
-            visitor.visit_select_statement(select_statement);
+            visitor.visit_expression(expression);
             visitor.visit_symbol(right_paren)
         }
+        Expression::Subscript {
+            base,
+            left_bracket,
+            index,
+            right_bracket,
+        } => {
+            visitor.visit_expression(base);
+            visitor.visit_symbol(left_bracket);
+            visitor.visit_expression(index);
+            visitor.visit_symbol(right_bracket)
+        }
         Expression::Between {
             test_expression,
             not_kw,
@@ -435,6 +511,7 @@ pub fn walk_expression<V: Visitor>(visitor: &mut V, expression: &Expression) ->
             not_kw,
             like_kw,
             pattern,
+            ..
         } => {
             visitor.visit_expression(match_expression);
             walk_opt!(visitor, visit_keyword, not_kw);
@@ -464,18 +541,26 @@ pub fn walk_expression<V: Visitor>(visitor: &mut V, expression: &Expression) ->
     }
 }
 
-pub fn walk_union<V: Visitor>(visitor: &mut V, union: &Union) -> V::Result {
-    visitor.visit_keyword(&union.union_kw);
-    walk_opt!(visitor, visit_keyword, &union.all_kw);
-    visitor.visit_select_statement(&union.select)
+pub fn walk_set_operation<V: Visitor>(
+    visitor: &mut V,
+    left: &Statement,
+    op: &Keyword,
+    all: &Option<Keyword>,
+    right: &Statement,
+) -> V::Result {
+    visitor.visit_statement(left);
+    visitor.visit_keyword(op);
+    walk_opt!(visitor, visit_keyword, all);
+    visitor.visit_statement(right)
 }
 
 pub fn walk_statement<V: Visitor>(visitor: &mut V, stmt: &Statement) -> V::Result {
     match stmt {
         Statement::Select(s) => visitor.visit_select_statement(s),
         Statement::Insert(i) => visitor.visit_insert_statement(i),
-        Statement::Update(_) => V::Result::output(),
-        Statement::Delete(_) => V::Result::output(),
+        Statement::Update(update) => visitor.visit_update_statement(update),
+        Statement::Delete(delete) => visitor.visit_delete_statement(delete),
+        Statement::Merge(merge) => visitor.visit_merge_statement(merge),
         Statement::CTE {
             with_kw,
             ctes,
@@ -504,6 +589,17 @@ pub fn walk_statement<V: Visitor>(visitor: &mut V, stmt: &Statement) -> V::Resul
             visitor.visit_symbol(equal_sign);
             visitor.visit_expression(value)
         }
+        Statement::SetIsolationLevel {
+            set_kw,
+            transaction_isolation_level_kws,
+            level_kws,
+            ..
+        } => {
+            visitor.visit_keyword(set_kw);
+            walk_list!(visitor, visit_keyword, transaction_isolation_level_kws);
+            walk_list!(visitor, visit_keyword, level_kws);
+            V::Result::output()
+        }
         Statement::Execute {
             exec_kw,
             procedure_name,
@@ -518,18 +614,148 @@ pub fn walk_statement<V: Visitor>(visitor: &mut V, stmt: &Statement) -> V::Resul
             );
             V::Result::output()
         }
-        Statement::Union { select, unions } => {
-            visitor.visit_select_statement(select);
-            walk_list!(visitor, visit_union, unions);
+        Statement::SetOperation {
+            left,
+            op,
+            all,
+            right,
+        } => visitor.visit_set_operation(left, op, all, right),
+        Statement::CreateTable {
+            create_kw,
+            table_kw,
+            name,
+            left_paren,
+            columns,
+            right_paren,
+        } => {
+            visitor.visit_keyword(create_kw);
+            visitor.visit_keyword(table_kw);
+            visitor.visit_expression(name);
+            visitor.visit_symbol(left_paren);
+            walk_list!(visitor, visit_column_def, columns);
+            visitor.visit_symbol(right_paren)
+        }
+        Statement::Truncate {
+            truncate_kw,
+            table_kw,
+            table,
+            partitions,
+        } => {
+            visitor.visit_keyword(truncate_kw);
+            visitor.visit_keyword(table_kw);
+            visitor.visit_expression(table);
+            walk_opt!(visitor, visit_truncate_partitions, partitions);
             V::Result::output()
         }
+        Statement::AlterIndex {
+            alter_kw,
+            index_kw,
+            name,
+            on_kw,
+            table,
+            action,
+        } => {
+            visitor.visit_keyword(alter_kw);
+            visitor.visit_keyword(index_kw);
+            visitor.visit_expression(name);
+            visitor.visit_keyword(on_kw);
+            visitor.visit_expression(table);
+            visitor.visit_alter_index_action(action)
+        }
+        Statement::BatchSeparator { go_kw, count } => {
+            visitor.visit_keyword(go_kw);
+            walk_opt!(visitor, visit_expression, count);
+            V::Result::output()
+        }
+    }
+}
+
+pub fn walk_update_statement<V: Visitor>(visitor: &mut V, stmt: &UpdateStatement) -> V::Result {
+    visitor.visit_keyword(&stmt.update_kw);
+    walk_opt!(visitor, visit_top_clause, &stmt.top);
+    visitor.visit_expression(&stmt.table);
+    visitor.visit_keyword(&stmt.set_kw);
+    walk_list!(visitor, visit_expression, &stmt.update_columns);
+    walk_opt!(visitor, visit_output_clause, &stmt.output);
+    walk_opt!(visitor, visit_table_clause, &stmt.from);
+    walk_opt!(visitor, visit_where_clause, &stmt.where_clause);
+    V::Result::output()
+}
+
+pub fn walk_delete_statement<V: Visitor>(visitor: &mut V, stmt: &DeleteStatement) -> V::Result {
+    visitor.visit_keyword(&stmt.delete_kw);
+    walk_opt!(visitor, visit_top_clause, &stmt.top);
+    visitor.visit_table_clause(&stmt.table);
+    walk_opt!(visitor, visit_output_clause, &stmt.output);
+    walk_opt!(visitor, visit_where_clause, &stmt.where_clause);
+    V::Result::output()
+}
+
+pub fn walk_merge_statement<V: Visitor>(visitor: &mut V, stmt: &MergeStatement) -> V::Result {
+    visitor.visit_keyword(&stmt.merge_kw);
+    walk_opt!(visitor, visit_top_clause, &stmt.top);
+    walk_opt!(visitor, visit_keyword, &stmt.into_kw);
+    visitor.visit_table_source(&stmt.target);
+    visitor.visit_keyword(&stmt.using_kw);
+    visitor.visit_table_source(&stmt.source);
+    visitor.visit_keyword(&stmt.on_kw);
+    visitor.visit_expression(&stmt.condition);
+    walk_list!(visitor, visit_merge_clause, &stmt.clauses);
+    walk_opt!(visitor, visit_output_clause, &stmt.output);
+    V::Result::output()
+}
+
+pub fn walk_merge_clause<V: Visitor>(visitor: &mut V, clause: &MergeClause) -> V::Result {
+    walk_list!(visitor, visit_keyword, &clause.when_kws);
+    if let Some((and_kw, condition)) = &clause.condition {
+        visitor.visit_keyword(and_kw);
+        visitor.visit_expression(condition);
+    }
+    visitor.visit_keyword(&clause.then_kw);
+    match &clause.action {
+        MergeAction::Update {
+            update_kw,
+            set_kw,
+            update_columns,
+        } => {
+            visitor.visit_keyword(update_kw);
+            visitor.visit_keyword(set_kw);
+            walk_list!(visitor, visit_expression, update_columns);
+        }
+        MergeAction::Delete(delete_kw) => {
+            visitor.visit_keyword(delete_kw);
+        }
+        MergeAction::Insert {
+            insert_kw,
+            columns,
+            values_kw,
+            values,
+        } => {
+            visitor.visit_keyword(insert_kw);
+            walk_opt!(visitor, visit_expression_list, columns);
+            visitor.visit_keyword(values_kw);
+            visitor.visit_expression_list(values);
+        }
     }
+    V::Result::output()
+}
+
+pub fn walk_output_clause<V: Visitor>(visitor: &mut V, output: &OutputClause) -> V::Result {
+    visitor.visit_keyword(&output.output_kw);
+    walk_list!(visitor, visit_select_item, &output.columns);
+    if let Some(into_table) = &output.into_table {
+        visitor.visit_keyword(&into_table.into_kw);
+        visitor.visit_expression(&into_table.table);
+        walk_opt!(visitor, visit_expression_list, &into_table.columns);
+    }
+    V::Result::output()
 }
 
 pub fn walk_insert_statement<V: Visitor>(visitor: &mut V, stmt: &InsertStatement) -> V::Result {
     match stmt {
         InsertStatement::Values {
             insert_kw,
+            insert_top,
             into_kw,
             object,
             columns,
@@ -537,6 +763,7 @@ pub fn walk_insert_statement<V: Visitor>(visitor: &mut V, stmt: &InsertStatement
             values,
         } => {
             visitor.visit_keyword(insert_kw);
+            walk_opt!(visitor, visit_top_clause, insert_top);
             walk_opt!(visitor, visit_keyword, into_kw);
             visitor.visit_expression(object);
             walk_opt!(visitor, visit_expression_list, columns);
@@ -546,6 +773,7 @@ pub fn walk_insert_statement<V: Visitor>(visitor: &mut V, stmt: &InsertStatement
         }
         InsertStatement::Table {
             insert_kw,
+            insert_top,
             into_kw,
             object,
             select_kw,
@@ -555,6 +783,7 @@ pub fn walk_insert_statement<V: Visitor>(visitor: &mut V, stmt: &InsertStatement
             where_clause,
         } => {
             visitor.visit_keyword(insert_kw);
+            walk_opt!(visitor, visit_top_clause, insert_top);
             walk_opt!(visitor, visit_keyword, into_kw);
             visitor.visit_expression(object);
             visitor.visit_keyword(select_kw);
@@ -570,14 +799,30 @@ pub fn walk_insert_statement<V: Visitor>(visitor: &mut V, stmt: &InsertStatement
 pub fn walk_select_statement<V: Visitor>(visitor: &mut V, stmt: &SelectStatement) -> V::Result {
     visitor.visit_keyword(&stmt.select);
     walk_opt!(visitor, visit_keyword, &stmt.distinct);
+    if let Some(distinct_on) = &stmt.distinct_on {
+        visitor.visit_keyword(&distinct_on.0);
+        visitor.visit_expression_list(&distinct_on.1);
+    }
     walk_opt!(visitor, visit_keyword, &stmt.all);
     walk_opt!(visitor, visit_top_clause, &stmt.top);
     walk_list!(visitor, visit_select_item, &stmt.columns);
+    walk_opt!(visitor, visit_into_arg, &stmt.into_table);
     walk_opt!(visitor, visit_table_clause, &stmt.table);
     walk_opt!(visitor, visit_where_clause, &stmt.where_clause);
     walk_opt!(visitor, visit_group_by_clause, &stmt.group_by);
     walk_opt!(visitor, visit_having_clause, &stmt.having);
     walk_opt!(visitor, visit_order_by_clause, &stmt.order_by);
+    walk_opt!(visitor, visit_limit_clause, &stmt.limit);
+    V::Result::output()
+}
+
+pub fn walk_into_arg<V: Visitor>(visitor: &mut V, into_arg: &IntoArg) -> V::Result {
+    visitor.visit_keyword(&into_arg.into_kw);
+    visitor.visit_expression(&into_arg.table);
+    if let Some((on_kw, file_group)) = &into_arg.file_group {
+        visitor.visit_keyword(on_kw);
+        visitor.visit_expression(file_group);
+    }
     V::Result::output()
 }
 
@@ -612,7 +857,6 @@ pub fn walk_arithmetic_operator<V: Visitor>(visitor: &mut V, op: &ArithmeticOper
 }
 
 pub fn walk_unary_operator<V: Visitor>(visitor: &mut V, op: &UnaryOperator) -> V::Result {
-    visitor.visit_unary_operator(op);
     visitor.visit_span(&op.location);
     visitor.visit_unary_operator_kind(op.kind)
 }
@@ -638,7 +882,11 @@ pub fn walk_data_type<V: Visitor>(visitor: &mut V, data_type: &DataType) -> V::R
             walk_opt!(visitor, visit_data_type_numeric_size, &ns);
             V::Result::output()
         }
-        DataType::Float(k, s) | DataType::Varchar(k, s) => {
+        DataType::Float(k, s)
+        | DataType::Varchar(k, s)
+        | DataType::Nvarchar(k, s)
+        | DataType::Char(k, s)
+        | DataType::Nchar(k, s) => {
             visitor.visit_keyword(&k);
             walk_opt!(visitor, visit_data_type_size, s);
             V::Result::output()
@@ -659,7 +907,7 @@ pub fn walk_select_item<V: Visitor>(visitor: &mut V, select_item: &SelectItem) -
             visitor.visit_symbol(s);
             visitor.visit_select_item_wild_card()
         }
-        SelectItem::Unnamed(e) => visitor.visit_expression(e),
+        SelectItem::QualifiedWildcard(e) | SelectItem::Unnamed(e) => visitor.visit_expression(e),
         SelectItem::WithAlias {
             expression,
             as_kw,
@@ -683,7 +931,9 @@ pub fn walk_select_item<V: Visitor>(visitor: &mut V, select_item: &SelectItem) -
 
 pub fn walk_table_clause<V: Visitor>(visitor: &mut V, table_clause: &TableArg) -> V::Result {
     visitor.visit_keyword(&table_clause.from);
-    visitor.visit_table_source(&table_clause.table)
+    visitor.visit_table_source(&table_clause.table);
+    walk_list!(visitor, visit_table_join, &table_clause.joins);
+    V::Result::output()
 }
 
 pub fn walk_where_clause<V: Visitor>(visitor: &mut V, where_clause: &WhereClause) -> V::Result {
@@ -701,7 +951,6 @@ pub fn walk_group_by_clause<V: Visitor>(
 }
 
 pub fn walk_having_clause<V: Visitor>(visitor: &mut V, having_clause: &HavingClause) -> V::Result {
-    visitor.visit_having_clause(having_clause);
     visitor.visit_keyword(&having_clause.having_kw);
     visitor.visit_expression(&having_clause.expression)
 }
@@ -721,20 +970,47 @@ pub fn walk_order_by_clause<V: Visitor>(
     V::Result::output()
 }
 
+pub fn walk_limit_clause<V: Visitor>(visitor: &mut V, limit_clause: &LimitClause) -> V::Result {
+    visitor.visit_keyword(&limit_clause.limit_kw);
+    visitor.visit_expression(&limit_clause.value);
+    if let Some(offset) = &limit_clause.offset {
+        visitor.visit_keyword(&offset.0);
+        visitor.visit_expression(&offset.1);
+    }
+
+    V::Result::output()
+}
+
 pub fn walk_table_source<V: Visitor>(visitor: &mut V, table_source: &TableSource) -> V::Result {
     match table_source {
         TableSource::Table { name, alias } => {
             visitor.visit_expression(name);
             walk_opt!(visitor, visit_expression, alias);
         }
-        TableSource::Derived { query, alias } => {
+        TableSource::Derived {
+            query,
+            as_kw,
+            alias,
+        } => {
             visitor.visit_expression(query);
+            walk_opt!(visitor, visit_keyword, as_kw);
             visitor.visit_expression(alias);
         }
         TableSource::TableValuedFunction { function, alias } => {
             visitor.visit_expression(function);
             walk_opt!(visitor, visit_expression, alias);
         }
+        TableSource::JoinGroup {
+            left_paren,
+            table,
+            joins,
+            right_paren,
+        } => {
+            visitor.visit_symbol(left_paren);
+            visitor.visit_table_source(table);
+            walk_list!(visitor, visit_table_join, joins);
+            visitor.visit_symbol(right_paren);
+        }
     }
 
     V::Result::output()
@@ -762,6 +1038,7 @@ pub fn walk_table_join_condition<V: Visitor>(
 pub fn walk_order_by_arg<V: Visitor>(visitor: &mut V, order_by_arg: &OrderByArg) -> V::Result {
     visitor.visit_expression(&order_by_arg.column);
     walk_opt!(visitor, visit_keyword, &order_by_arg.order_kw);
+    walk_opt_list!(visitor, visit_keyword, &order_by_arg.nulls_kws);
 
     V::Result::output()
 }
@@ -919,3 +1196,85 @@ pub fn walk_local_variable<V: Visitor>(
 
     V::Result::output()
 }
+
+pub fn walk_column_def<V: Visitor>(visitor: &mut V, column_def: &ColumnDef) -> V::Result {
+    visitor.visit_expression(&column_def.name);
+    visitor.visit_data_type(&column_def.data_type);
+    walk_list!(visitor, visit_column_constraint, &column_def.constraints);
+
+    V::Result::output()
+}
+
+pub fn walk_column_constraint<V: Visitor>(
+    visitor: &mut V,
+    constraint: &ColumnConstraint,
+) -> V::Result {
+    match constraint {
+        ColumnConstraint::Null(null_kw) => visitor.visit_keyword(null_kw),
+        ColumnConstraint::NotNull { not_kw, null_kw } => {
+            visitor.visit_keyword(not_kw);
+            visitor.visit_keyword(null_kw)
+        }
+        ColumnConstraint::PrimaryKey { primary_kw, key_kw } => {
+            visitor.visit_keyword(primary_kw);
+            visitor.visit_keyword(key_kw)
+        }
+        ColumnConstraint::Identity {
+            identity_kw,
+            seed_increment,
+        } => {
+            visitor.visit_keyword(identity_kw);
+            walk_opt!(visitor, visit_identity_seed_increment, seed_increment);
+            V::Result::output()
+        }
+        ColumnConstraint::Default { default_kw, value } => {
+            visitor.visit_keyword(default_kw);
+            visitor.visit_expression(value)
+        }
+    }
+}
+
+pub fn walk_identity_seed_increment<V: Visitor>(
+    visitor: &mut V,
+    seed_increment: &IdentitySeedIncrement,
+) -> V::Result {
+    visitor.visit_symbol(&seed_increment.left_paren);
+    visitor.visit_expression(&seed_increment.seed);
+    visitor.visit_expression(&seed_increment.increment);
+    visitor.visit_symbol(&seed_increment.right_paren)
+}
+
+pub fn walk_truncate_partitions<V: Visitor>(
+    visitor: &mut V,
+    partitions: &TruncatePartitions,
+) -> V::Result {
+    visitor.visit_keyword(&partitions.with_kw);
+    visitor.visit_symbol(&partitions.left_paren);
+    visitor.visit_keyword(&partitions.partitions_kw);
+    visitor.visit_symbol(&partitions.partitions_left_paren);
+    walk_list!(visitor, visit_partition_range, &partitions.partitions);
+    visitor.visit_symbol(&partitions.partitions_right_paren);
+    visitor.visit_symbol(&partitions.right_paren)
+}
+
+pub fn walk_partition_range<V: Visitor>(visitor: &mut V, partition: &PartitionRange) -> V::Result {
+    match partition {
+        PartitionRange::Single(partition) => visitor.visit_expression(partition),
+        PartitionRange::Range { start, to_kw, end } => {
+            visitor.visit_expression(start);
+            visitor.visit_keyword(to_kw);
+            visitor.visit_expression(end)
+        }
+    }
+}
+
+pub fn walk_alter_index_action<V: Visitor>(
+    visitor: &mut V,
+    action: &AlterIndexAction,
+) -> V::Result {
+    match action {
+        AlterIndexAction::Rebuild(kw)
+        | AlterIndexAction::Reorganize(kw)
+        | AlterIndexAction::Disable(kw) => visitor.visit_keyword(kw),
+    }
+}
@@ -0,0 +1,1353 @@
+use lexer::Span;
+
+use crate::ast::{
+    AlterIndexAction, ArithmeticOperator, ArithmeticOperatorKind, CaseCondition, ColumnConstraint,
+    ColumnDef, CommonTableExpression, CommonTableExpressionStatement, ComparisonOperator,
+    ComparisonOperatorKind, DataType, DataTypeLength, DataTypeSize, DeleteStatement, Expression,
+    ExpressionList, FetchArg, FunctionName, GroupByClause, HavingClause, IdentitySeedIncrement,
+    InsertStatement, IntoArg, Join, JoinCondition, JoinType, Keyword, KeywordKind, LimitClause,
+    Literal, LocalVariable, MergeAction, MergeClause, MergeStatement, NextOrFirst, NumericSize,
+    OffsetArg, OffsetFetchClause, OrderByArg, OrderByClause, OutputClause, OverClause,
+    PartitionRange, ProcedureParameter, ProcedureParameterName, Query, RowOrRows, RowsOrRange,
+    SelectItem, SelectStatement, Statement, Symbol, SymbolKind, TableArg, TableSource, Top,
+    TruncatePartitions, UnaryOperator, UnaryOperatorKind, UpdateStatement, WhereClause,
+    WindowFrame, WindowFrameBound,
+};
+use crate::visitor::VisitorResult;
+
+/// The mutable counterpart of [`Visitor`](crate::visitor::Visitor) for passes
+/// that rewrite the ast in place.
+pub trait VisitorMut: Sized {
+    type Result: VisitorResult;
+
+    fn visit_query(&mut self, query: &mut Query) -> Self::Result {
+        walk_query_mut(self, query)
+    }
+    fn visit_expression(&mut self, expr: &mut Expression) -> Self::Result {
+        walk_expression_mut(self, expr)
+    }
+    fn visit_statement(&mut self, stmt: &mut Statement) -> Self::Result {
+        walk_statement_mut(self, stmt)
+    }
+    fn visit_insert_statement(&mut self, stmt: &mut InsertStatement) -> Self::Result {
+        walk_insert_statement_mut(self, stmt)
+    }
+    fn visit_update_statement(&mut self, stmt: &mut UpdateStatement) -> Self::Result {
+        walk_update_statement_mut(self, stmt)
+    }
+    fn visit_delete_statement(&mut self, stmt: &mut DeleteStatement) -> Self::Result {
+        walk_delete_statement_mut(self, stmt)
+    }
+    fn visit_merge_statement(&mut self, stmt: &mut MergeStatement) -> Self::Result {
+        walk_merge_statement_mut(self, stmt)
+    }
+    fn visit_merge_clause(&mut self, clause: &mut MergeClause) -> Self::Result {
+        walk_merge_clause_mut(self, clause)
+    }
+    fn visit_output_clause(&mut self, output: &mut OutputClause) -> Self::Result {
+        walk_output_clause_mut(self, output)
+    }
+    fn visit_set_operation(
+        &mut self,
+        left: &mut Statement,
+        op: &mut Keyword,
+        all: &mut Option<Keyword>,
+        right: &mut Statement,
+    ) -> Self::Result {
+        walk_set_operation_mut(self, left, op, all, right)
+    }
+    fn visit_select_statement(&mut self, stmt: &mut SelectStatement) -> Self::Result {
+        walk_select_statement_mut(self, stmt)
+    }
+    fn visit_common_table_expression_statement(
+        &mut self,
+        stmt: &mut CommonTableExpressionStatement,
+    ) -> Self::Result {
+        walk_common_table_expression_statement_mut(self, stmt)
+    }
+
+    fn visit_symbol(&mut self, symbol: &mut Symbol) -> Self::Result {
+        walk_symbol_mut(self, symbol)
+    }
+    fn visit_symbol_kind(&mut self, _: &mut SymbolKind) -> Self::Result {
+        Self::Result::output()
+    }
+    fn visit_select_item_wild_card(&mut self) -> Self::Result {
+        Self::Result::output()
+    }
+    fn visit_asterisk(&mut self) -> Self::Result {
+        Self::Result::output()
+    }
+    fn visit_span(&mut self, _: &mut Span) -> Self::Result {
+        Self::Result::output()
+    }
+    fn visit_literal(&mut self, literal: &mut Literal) -> Self::Result {
+        walk_literal_mut(self, literal)
+    }
+    fn visit_comparison_operator(&mut self, op: &mut ComparisonOperator) -> Self::Result {
+        walk_comparison_operator_mut(self, op)
+    }
+    fn visit_comparison_operator_kind(&mut self, _: &mut ComparisonOperatorKind) -> Self::Result {
+        Self::Result::output()
+    }
+    fn visit_arithmetic_operator(&mut self, op: &mut ArithmeticOperator) -> Self::Result {
+        walk_arithmetic_operator_mut(self, op)
+    }
+    fn visit_arithmetic_operator_kind(&mut self, _: &mut ArithmeticOperatorKind) -> Self::Result {
+        Self::Result::output()
+    }
+    fn visit_unary_operator(&mut self, op: &mut UnaryOperator) -> Self::Result {
+        walk_unary_operator_mut(self, op)
+    }
+    fn visit_unary_operator_kind(&mut self, _: &mut UnaryOperatorKind) -> Self::Result {
+        Self::Result::output()
+    }
+    fn visit_keyword(&mut self, keyword: &mut Keyword) -> Self::Result {
+        walk_keyword_mut(self, keyword)
+    }
+    fn visit_keyword_kind(&mut self, _: &mut KeywordKind) -> Self::Result {
+        Self::Result::output()
+    }
+    fn visit_data_type(&mut self, data_type: &mut DataType) -> Self::Result {
+        walk_data_type_mut(self, data_type)
+    }
+    fn visit_data_type_size(&mut self, data_type_size: &mut DataTypeSize) -> Self::Result {
+        walk_symbol_mut(self, &mut data_type_size.left_paren);
+        if let DataTypeLength::Max(k) = &mut data_type_size.size {
+            self.visit_keyword(k);
+        }
+        walk_symbol_mut(self, &mut data_type_size.right_paren);
+        Self::Result::output()
+    }
+    fn visit_data_type_numeric_size(&mut self, ns: &mut NumericSize) -> Self::Result {
+        walk_symbol_mut(self, &mut ns.left_paren);
+        walk_symbol_mut(self, &mut ns.right_paren);
+        Self::Result::output()
+    }
+    fn visit_top_clause(&mut self, top_clause: &mut Top) -> Self::Result {
+        walk_top_clause_mut(self, top_clause)
+    }
+    fn visit_select_item(&mut self, select_item: &mut SelectItem) -> Self::Result {
+        walk_select_item_mut(self, select_item)
+    }
+    fn visit_into_arg(&mut self, into_arg: &mut IntoArg) -> Self::Result {
+        walk_into_arg_mut(self, into_arg)
+    }
+    fn visit_table_clause(&mut self, table_clause: &mut TableArg) -> Self::Result {
+        walk_table_clause_mut(self, table_clause)
+    }
+    fn visit_where_clause(&mut self, where_clause: &mut WhereClause) -> Self::Result {
+        walk_where_clause_mut(self, where_clause)
+    }
+    fn visit_group_by_clause(&mut self, group_by_clause: &mut GroupByClause) -> Self::Result {
+        walk_group_by_clause_mut(self, group_by_clause)
+    }
+    fn visit_having_clause(&mut self, having_clause: &mut HavingClause) -> Self::Result {
+        walk_having_clause_mut(self, having_clause)
+    }
+    fn visit_order_by_clause(&mut self, order_by_clause: &mut OrderByClause) -> Self::Result {
+        walk_order_by_clause_mut(self, order_by_clause)
+    }
+    fn visit_table_source(&mut self, table_source: &mut TableSource) -> Self::Result {
+        walk_table_source_mut(self, table_source)
+    }
+    fn visit_table_join(&mut self, table_join: &mut Join) -> Self::Result {
+        walk_table_join_mut(self, table_join)
+    }
+    fn visit_table_join_condition(
+        &mut self,
+        table_join_condition: &mut JoinCondition,
+    ) -> Self::Result {
+        walk_table_join_condition_mut(self, table_join_condition)
+    }
+    fn visit_table_join_type(&mut self, _: &mut JoinType) -> Self::Result {
+        Self::Result::output()
+    }
+    fn visit_limit_clause(&mut self, limit_clause: &mut LimitClause) -> Self::Result {
+        walk_limit_clause_mut(self, limit_clause)
+    }
+    fn visit_order_by_arg(&mut self, order_by_arg: &mut OrderByArg) -> Self::Result {
+        walk_order_by_arg_mut(self, order_by_arg)
+    }
+    fn visit_order_by_offset_fetch_clause(
+        &mut self,
+        offset_fetch_clause: &mut OffsetFetchClause,
+    ) -> Self::Result {
+        walk_order_by_offset_fetch_clause_mut(self, offset_fetch_clause)
+    }
+    fn visit_order_by_offset_arg(&mut self, offset_arg: &mut OffsetArg) -> Self::Result {
+        walk_order_by_offset_arg_mut(self, offset_arg)
+    }
+    fn visit_order_by_fetch_arg(&mut self, fetch_arg: &mut FetchArg) -> Self::Result {
+        walk_order_by_fetch_arg_mut(self, fetch_arg)
+    }
+    fn visit_row_or_rows(&mut self, _: &mut RowOrRows) -> Self::Result {
+        Self::Result::output()
+    }
+    fn visit_rows_or_range(&mut self, _: &mut RowsOrRange) -> Self::Result {
+        Self::Result::output()
+    }
+    fn visit_first_or_next(&mut self, _: &mut NextOrFirst) -> Self::Result {
+        Self::Result::output()
+    }
+    fn visit_function_name(&mut self, fn_name: &mut FunctionName) -> Self::Result {
+        walk_function_name_mut(self, fn_name)
+    }
+    fn visit_function_over_clause(&mut self, over_clause: &mut OverClause) -> Self::Result {
+        walk_function_over_clause_mut(self, over_clause)
+    }
+    fn visit_function_over_clause_window_frame(
+        &mut self,
+        window_frame: &mut WindowFrame,
+    ) -> Self::Result {
+        walk_function_over_clause_window_frame_mut(self, window_frame)
+    }
+    fn visit_function_over_clause_window_frame_bound(
+        &mut self,
+        window_frame_bound: &mut WindowFrameBound,
+    ) -> Self::Result {
+        match window_frame_bound {
+            WindowFrameBound::CurrentRow
+            | WindowFrameBound::UnboundedPreceding
+            | WindowFrameBound::UnboundedFollowing => Self::Result::output(),
+            WindowFrameBound::Preceding(e) | WindowFrameBound::Following(e) => {
+                self.visit_expression(e)
+            }
+        }
+    }
+    fn visit_case_condition(&mut self, case_condition: &mut CaseCondition) -> Self::Result {
+        walk_case_condition_mut(self, case_condition)
+    }
+    fn visit_common_table_expression(&mut self, cte: &mut CommonTableExpression) -> Self::Result {
+        walk_common_table_expression_mut(self, cte)
+    }
+    fn visit_expression_list(&mut self, list: &mut ExpressionList) -> Self::Result {
+        walk_expression_list_mut(self, list)
+    }
+    fn visit_execute_statement_procedure_parameter(
+        &mut self,
+        param: &mut ProcedureParameter,
+    ) -> Self::Result {
+        walk_execute_statement_procedure_parameter_mut(self, param)
+    }
+    fn visit_execute_statement_procedure_parameter_name(
+        &mut self,
+        name: &mut ProcedureParameterName,
+    ) -> Self::Result {
+        walk_execute_statement_procedure_parameter_name_mut(self, name)
+    }
+    fn visit_local_variable(&mut self, local_variable: &mut LocalVariable) -> Self::Result {
+        walk_local_variable_mut(self, local_variable)
+    }
+    fn visit_column_def(&mut self, column_def: &mut ColumnDef) -> Self::Result {
+        walk_column_def_mut(self, column_def)
+    }
+    fn visit_column_constraint(&mut self, constraint: &mut ColumnConstraint) -> Self::Result {
+        walk_column_constraint_mut(self, constraint)
+    }
+    fn visit_identity_seed_increment(
+        &mut self,
+        seed_increment: &mut IdentitySeedIncrement,
+    ) -> Self::Result {
+        walk_identity_seed_increment_mut(self, seed_increment)
+    }
+    fn visit_truncate_partitions(&mut self, partitions: &mut TruncatePartitions) -> Self::Result {
+        walk_truncate_partitions_mut(self, partitions)
+    }
+    fn visit_partition_range(&mut self, partition: &mut PartitionRange) -> Self::Result {
+        walk_partition_range_mut(self, partition)
+    }
+    fn visit_alter_index_action(&mut self, action: &mut AlterIndexAction) -> Self::Result {
+        walk_alter_index_action_mut(self, action)
+    }
+}
+
+macro_rules! walk_list_mut {
+    ($visitor: expr, $method: ident, $list: expr) => {
+        for element in $list.iter_mut() {
+            $visitor.$method(element);
+        }
+    };
+}
+
+macro_rules! walk_opt_list_mut {
+    ($visitor: expr, $method: ident, $opt: expr) => {
+        if let Some(o) = $opt {
+            for element in o.iter_mut() {
+                $visitor.$method(element);
+            }
+        }
+    };
+}
+
+macro_rules! walk_opt_mut {
+    ($visitor: expr, $method: ident, $opt: expr) => {
+        if let Some(o) = $opt {
+            $visitor.$method(o);
+        }
+    };
+}
+
+pub fn walk_query_mut<V: VisitorMut>(visitor: &mut V, query: &mut Query) -> V::Result {
+    walk_list_mut!(visitor, visit_statement, query.statements);
+    V::Result::output()
+}
+
+pub fn walk_expression_mut<V: VisitorMut>(
+    visitor: &mut V,
+    expression: &mut Expression,
+) -> V::Result {
+    match expression {
+        Expression::Asterisk(s) => {
+            visitor.visit_symbol(s);
+            visitor.visit_asterisk()
+        }
+        Expression::Identifier(l)
+        | Expression::QuotedIdentifier(l)
+        | Expression::StringLiteral(l)
+        | Expression::NumberLiteral(l)
+        | Expression::LocalVariable(l) => visitor.visit_literal(l),
+        Expression::Keyword(k) => visitor.visit_keyword(k),
+        Expression::Compound(e) => {
+            walk_list_mut!(visitor, visit_expression, e);
+            V::Result::output()
+        }
+        Expression::Arithmetic {
+            operator,
+            left,
+            right,
+        } => {
+            visitor.visit_arithmetic_operator(operator);
+            visitor.visit_expression(left);
+            visitor.visit_expression(right)
+        }
+        Expression::And {
+            and_kw,
+            left,
+            right,
+        } => {
+            visitor.visit_keyword(and_kw);
+            visitor.visit_expression(left);
+            visitor.visit_expression(right)
+        }
+        Expression::Or { or_kw, left, right } => {
+            visitor.visit_keyword(or_kw);
+            visitor.visit_expression(left);
+            visitor.visit_expression(right)
+        }
+        Expression::Comparison {
+            operator,
+            left,
+            right,
+        } => {
+            visitor.visit_comparison_operator(operator);
+            visitor.visit_expression(left);
+            visitor.visit_expression(right)
+        }
+        Expression::Unary { operator, right } => {
+            visitor.visit_unary_operator(operator);
+            visitor.visit_expression(right)
+        }
+        Expression::Function {
+            name,
+            left_paren,
+            set_quantifier,
+            args,
+            right_paren,
+            over,
+        } => {
+            visitor.visit_function_name(name);
+            visitor.visit_symbol(left_paren);
+            walk_opt_mut!(visitor, visit_keyword, set_quantifier);
+            walk_opt_list_mut!(visitor, visit_expression, args);
+            visitor.visit_symbol(right_paren);
+            walk_opt_mut!(visitor, visit_function_over_clause, over);
+
+            V::Result::output()
+        }
+        Expression::Cast {
+            cast_kw,
+            left_paren,
+            expression,
+            as_kw,
+            data_type,
+            right_paren,
+        } => {
+            visitor.visit_keyword(cast_kw);
+            visitor.visit_symbol(left_paren);
+            visitor.visit_expression(expression);
+            visitor.visit_keyword(as_kw);
+            visitor.visit_data_type(data_type);
+            visitor.visit_symbol(right_paren)
+        }
+        Expression::InExpressionList {
+            test_expression,
+            in_kw,
+            not_kw,
+            left_paren,
+            list,
+            right_paren,
+        } => {
+            visitor.visit_expression(test_expression);
+            visitor.visit_keyword(in_kw);
+            walk_opt_mut!(visitor, visit_keyword, not_kw);
+            visitor.visit_symbol(left_paren);
+            walk_list_mut!(visitor, visit_expression, list);
+            visitor.visit_symbol(right_paren);
+
+            V::Result::output()
+        }
+        Expression::InSubquery {
+            test_expression,
+            in_kw,
+            not_kw,
+            subquery,
+        } => {
+            visitor.visit_expression(test_expression);
+            visitor.visit_keyword(in_kw);
+            walk_opt_mut!(visitor, visit_keyword, not_kw);
+            visitor.visit_expression(subquery)
+        }
+        Expression::Subquery {
+            left_paren,
+            statement,
+            right_paren,
+        } => {
+            visitor.visit_symbol(left_paren);
+            visitor.visit_statement(statement);
+            visitor.visit_symbol(right_paren)
+        }
+        Expression::Grouping {
+            left_paren,
+            expression,
+            right_paren,
+        } => {
+            visitor.visit_symbol(left_paren);
+            visitor.visit_expression(expression);
+            visitor.visit_symbol(right_paren)
+        }
+        Expression::Subscript {
+            base,
+            left_bracket,
+            index,
+            right_bracket,
+        } => {
+            visitor.visit_expression(base);
+            visitor.visit_symbol(left_bracket);
+            visitor.visit_expression(index);
+            visitor.visit_symbol(right_bracket)
+        }
+        Expression::Between {
+            test_expression,
+            not_kw,
+            between_kw,
+            begin,
+            and_kw,
+            end,
+        } => {
+            visitor.visit_expression(test_expression);
+            walk_opt_mut!(visitor, visit_keyword, not_kw);
+            visitor.visit_keyword(between_kw);
+            visitor.visit_expression(begin);
+            visitor.visit_keyword(and_kw);
+            visitor.visit_expression(end)
+        }
+        Expression::Not { not_kw, expression } => {
+            visitor.visit_keyword(not_kw);
+            visitor.visit_expression(expression)
+        }
+        Expression::Exists {
+            exists_kw,
+            subquery,
+        } => {
+            visitor.visit_keyword(exists_kw);
+            visitor.visit_expression(subquery)
+        }
+        Expression::All {
+            all_kw,
+            scalar_expression,
+            comparison_op,
+            subquery,
+        } => {
+            visitor.visit_keyword(all_kw);
+            visitor.visit_expression(scalar_expression);
+            visitor.visit_comparison_operator(comparison_op);
+            visitor.visit_expression(subquery)
+        }
+        Expression::Some {
+            some_kw,
+            scalar_expression,
+            comparison_op,
+            subquery,
+        } => {
+            visitor.visit_keyword(some_kw);
+            visitor.visit_expression(scalar_expression);
+            visitor.visit_comparison_operator(comparison_op);
+            visitor.visit_expression(subquery)
+        }
+        Expression::Any {
+            any_kw,
+            scalar_expression,
+            comparison_op,
+            subquery,
+        } => {
+            visitor.visit_keyword(any_kw);
+            visitor.visit_expression(scalar_expression);
+            visitor.visit_comparison_operator(comparison_op);
+            visitor.visit_expression(subquery)
+        }
+        Expression::Like {
+            match_expression,
+            not_kw,
+            like_kw,
+            pattern,
+            ..
+        } => {
+            visitor.visit_expression(match_expression);
+            walk_opt_mut!(visitor, visit_keyword, not_kw);
+            visitor.visit_keyword(like_kw);
+            visitor.visit_expression(pattern)
+        }
+        Expression::SimpleCase {
+            case_kw,
+            input_expression,
+            conditions,
+            end_kw,
+        } => {
+            visitor.visit_keyword(case_kw);
+            visitor.visit_expression(input_expression);
+            walk_list_mut!(visitor, visit_case_condition, conditions);
+            visitor.visit_keyword(end_kw)
+        }
+        Expression::SearchedCase {
+            case_kw,
+            conditions,
+            end_kw,
+        } => {
+            visitor.visit_keyword(case_kw);
+            walk_list_mut!(visitor, visit_case_condition, conditions);
+            visitor.visit_keyword(end_kw)
+        }
+    }
+}
+
+pub fn walk_set_operation_mut<V: VisitorMut>(
+    visitor: &mut V,
+    left: &mut Statement,
+    op: &mut Keyword,
+    all: &mut Option<Keyword>,
+    right: &mut Statement,
+) -> V::Result {
+    visitor.visit_statement(left);
+    visitor.visit_keyword(op);
+    walk_opt_mut!(visitor, visit_keyword, all);
+    visitor.visit_statement(right)
+}
+
+pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, stmt: &mut Statement) -> V::Result {
+    match stmt {
+        Statement::Select(s) => visitor.visit_select_statement(s),
+        Statement::Insert(i) => visitor.visit_insert_statement(i),
+        Statement::Update(update) => visitor.visit_update_statement(update),
+        Statement::Delete(delete) => visitor.visit_delete_statement(delete),
+        Statement::Merge(merge) => visitor.visit_merge_statement(merge),
+        Statement::CTE {
+            with_kw,
+            ctes,
+            statement,
+        } => {
+            visitor.visit_keyword(with_kw);
+            walk_list_mut!(visitor, visit_common_table_expression, ctes);
+            visitor.visit_common_table_expression_statement(statement)
+        }
+        Statement::Declare {
+            declare_kw,
+            variables,
+        } => {
+            visitor.visit_keyword(declare_kw);
+            walk_list_mut!(visitor, visit_local_variable, variables);
+            V::Result::output()
+        }
+        Statement::SetLocalVariable {
+            set_kw,
+            name,
+            equal_sign,
+            value,
+        } => {
+            visitor.visit_keyword(set_kw);
+            visitor.visit_expression(name);
+            visitor.visit_symbol(equal_sign);
+            visitor.visit_expression(value)
+        }
+        Statement::SetIsolationLevel {
+            set_kw,
+            transaction_isolation_level_kws,
+            level_kws,
+            ..
+        } => {
+            visitor.visit_keyword(set_kw);
+            walk_list_mut!(visitor, visit_keyword, transaction_isolation_level_kws);
+            walk_list_mut!(visitor, visit_keyword, level_kws);
+            V::Result::output()
+        }
+        Statement::Execute {
+            exec_kw,
+            procedure_name,
+            parameters,
+        } => {
+            visitor.visit_keyword(exec_kw);
+            visitor.visit_expression(procedure_name);
+            walk_list_mut!(
+                visitor,
+                visit_execute_statement_procedure_parameter,
+                parameters
+            );
+            V::Result::output()
+        }
+        Statement::SetOperation {
+            left,
+            op,
+            all,
+            right,
+        } => visitor.visit_set_operation(left, op, all, right),
+        Statement::CreateTable {
+            create_kw,
+            table_kw,
+            name,
+            left_paren,
+            columns,
+            right_paren,
+        } => {
+            visitor.visit_keyword(create_kw);
+            visitor.visit_keyword(table_kw);
+            visitor.visit_expression(name);
+            visitor.visit_symbol(left_paren);
+            walk_list_mut!(visitor, visit_column_def, columns);
+            visitor.visit_symbol(right_paren)
+        }
+        Statement::Truncate {
+            truncate_kw,
+            table_kw,
+            table,
+            partitions,
+        } => {
+            visitor.visit_keyword(truncate_kw);
+            visitor.visit_keyword(table_kw);
+            visitor.visit_expression(table);
+            walk_opt_mut!(visitor, visit_truncate_partitions, partitions);
+            V::Result::output()
+        }
+        Statement::AlterIndex {
+            alter_kw,
+            index_kw,
+            name,
+            on_kw,
+            table,
+            action,
+        } => {
+            visitor.visit_keyword(alter_kw);
+            visitor.visit_keyword(index_kw);
+            visitor.visit_expression(name);
+            visitor.visit_keyword(on_kw);
+            visitor.visit_expression(table);
+            visitor.visit_alter_index_action(action)
+        }
+        Statement::BatchSeparator { go_kw, count } => {
+            visitor.visit_keyword(go_kw);
+            walk_opt_mut!(visitor, visit_expression, count);
+            V::Result::output()
+        }
+    }
+}
+
+pub fn walk_update_statement_mut<V: VisitorMut>(
+    visitor: &mut V,
+    stmt: &mut UpdateStatement,
+) -> V::Result {
+    visitor.visit_keyword(&mut stmt.update_kw);
+    walk_opt_mut!(visitor, visit_top_clause, &mut stmt.top);
+    visitor.visit_expression(&mut stmt.table);
+    visitor.visit_keyword(&mut stmt.set_kw);
+    walk_list_mut!(visitor, visit_expression, &mut stmt.update_columns);
+    walk_opt_mut!(visitor, visit_output_clause, &mut stmt.output);
+    walk_opt_mut!(visitor, visit_table_clause, &mut stmt.from);
+    walk_opt_mut!(visitor, visit_where_clause, &mut stmt.where_clause);
+    V::Result::output()
+}
+
+pub fn walk_delete_statement_mut<V: VisitorMut>(
+    visitor: &mut V,
+    stmt: &mut DeleteStatement,
+) -> V::Result {
+    visitor.visit_keyword(&mut stmt.delete_kw);
+    walk_opt_mut!(visitor, visit_top_clause, &mut stmt.top);
+    visitor.visit_table_clause(&mut stmt.table);
+    walk_opt_mut!(visitor, visit_output_clause, &mut stmt.output);
+    walk_opt_mut!(visitor, visit_where_clause, &mut stmt.where_clause);
+    V::Result::output()
+}
+
+pub fn walk_merge_statement_mut<V: VisitorMut>(
+    visitor: &mut V,
+    stmt: &mut MergeStatement,
+) -> V::Result {
+    visitor.visit_keyword(&mut stmt.merge_kw);
+    walk_opt_mut!(visitor, visit_top_clause, &mut stmt.top);
+    walk_opt_mut!(visitor, visit_keyword, &mut stmt.into_kw);
+    visitor.visit_table_source(&mut stmt.target);
+    visitor.visit_keyword(&mut stmt.using_kw);
+    visitor.visit_table_source(&mut stmt.source);
+    visitor.visit_keyword(&mut stmt.on_kw);
+    visitor.visit_expression(&mut stmt.condition);
+    walk_list_mut!(visitor, visit_merge_clause, &mut stmt.clauses);
+    walk_opt_mut!(visitor, visit_output_clause, &mut stmt.output);
+    V::Result::output()
+}
+
+pub fn walk_merge_clause_mut<V: VisitorMut>(
+    visitor: &mut V,
+    clause: &mut MergeClause,
+) -> V::Result {
+    walk_list_mut!(visitor, visit_keyword, &mut clause.when_kws);
+    if let Some((and_kw, condition)) = &mut clause.condition {
+        visitor.visit_keyword(and_kw);
+        visitor.visit_expression(condition);
+    }
+    visitor.visit_keyword(&mut clause.then_kw);
+    match &mut clause.action {
+        MergeAction::Update {
+            update_kw,
+            set_kw,
+            update_columns,
+        } => {
+            visitor.visit_keyword(update_kw);
+            visitor.visit_keyword(set_kw);
+            walk_list_mut!(visitor, visit_expression, update_columns);
+        }
+        MergeAction::Delete(delete_kw) => {
+            visitor.visit_keyword(delete_kw);
+        }
+        MergeAction::Insert {
+            insert_kw,
+            columns,
+            values_kw,
+            values,
+        } => {
+            visitor.visit_keyword(insert_kw);
+            walk_opt_mut!(visitor, visit_expression_list, columns);
+            visitor.visit_keyword(values_kw);
+            visitor.visit_expression_list(values);
+        }
+    }
+    V::Result::output()
+}
+
+pub fn walk_output_clause_mut<V: VisitorMut>(
+    visitor: &mut V,
+    output: &mut OutputClause,
+) -> V::Result {
+    visitor.visit_keyword(&mut output.output_kw);
+    walk_list_mut!(visitor, visit_select_item, &mut output.columns);
+    if let Some(into_table) = &mut output.into_table {
+        visitor.visit_keyword(&mut into_table.into_kw);
+        visitor.visit_expression(&mut into_table.table);
+        walk_opt_mut!(visitor, visit_expression_list, &mut into_table.columns);
+    }
+    V::Result::output()
+}
+
+pub fn walk_insert_statement_mut<V: VisitorMut>(
+    visitor: &mut V,
+    stmt: &mut InsertStatement,
+) -> V::Result {
+    match stmt {
+        InsertStatement::Values {
+            insert_kw,
+            insert_top,
+            into_kw,
+            object,
+            columns,
+            values_kw,
+            values,
+        } => {
+            visitor.visit_keyword(insert_kw);
+            walk_opt_mut!(visitor, visit_top_clause, insert_top);
+            walk_opt_mut!(visitor, visit_keyword, into_kw);
+            visitor.visit_expression(object);
+            walk_opt_mut!(visitor, visit_expression_list, columns);
+            visitor.visit_keyword(values_kw);
+            visitor.visit_expression_list(values);
+            V::Result::output()
+        }
+        InsertStatement::Table {
+            insert_kw,
+            insert_top,
+            into_kw,
+            object,
+            select_kw,
+            top,
+            columns,
+            table,
+            where_clause,
+        } => {
+            visitor.visit_keyword(insert_kw);
+            walk_opt_mut!(visitor, visit_top_clause, insert_top);
+            walk_opt_mut!(visitor, visit_keyword, into_kw);
+            visitor.visit_expression(object);
+            visitor.visit_keyword(select_kw);
+            walk_opt_mut!(visitor, visit_top_clause, top);
+            walk_list_mut!(visitor, visit_expression, columns);
+            visitor.visit_table_clause(table);
+            walk_opt_mut!(visitor, visit_where_clause, where_clause);
+            V::Result::output()
+        }
+    }
+}
+
+pub fn walk_select_statement_mut<V: VisitorMut>(
+    visitor: &mut V,
+    stmt: &mut SelectStatement,
+) -> V::Result {
+    visitor.visit_keyword(&mut stmt.select);
+    walk_opt_mut!(visitor, visit_keyword, &mut stmt.distinct);
+    if let Some(distinct_on) = &mut stmt.distinct_on {
+        visitor.visit_keyword(&mut distinct_on.0);
+        visitor.visit_expression_list(&mut distinct_on.1);
+    }
+    walk_opt_mut!(visitor, visit_keyword, &mut stmt.all);
+    walk_opt_mut!(visitor, visit_top_clause, &mut stmt.top);
+    walk_list_mut!(visitor, visit_select_item, &mut stmt.columns);
+    walk_opt_mut!(visitor, visit_into_arg, &mut stmt.into_table);
+    walk_opt_mut!(visitor, visit_table_clause, &mut stmt.table);
+    walk_opt_mut!(visitor, visit_where_clause, &mut stmt.where_clause);
+    walk_opt_mut!(visitor, visit_group_by_clause, &mut stmt.group_by);
+    walk_opt_mut!(visitor, visit_having_clause, &mut stmt.having);
+    walk_opt_mut!(visitor, visit_order_by_clause, &mut stmt.order_by);
+    walk_opt_mut!(visitor, visit_limit_clause, &mut stmt.limit);
+    V::Result::output()
+}
+
+pub fn walk_into_arg_mut<V: VisitorMut>(visitor: &mut V, into_arg: &mut IntoArg) -> V::Result {
+    visitor.visit_keyword(&mut into_arg.into_kw);
+    visitor.visit_expression(&mut into_arg.table);
+    if let Some((on_kw, file_group)) = &mut into_arg.file_group {
+        visitor.visit_keyword(on_kw);
+        visitor.visit_expression(file_group);
+    }
+    V::Result::output()
+}
+
+pub fn walk_common_table_expression_statement_mut<V: VisitorMut>(
+    visitor: &mut V,
+    stmt: &mut CommonTableExpressionStatement,
+) -> V::Result {
+    match stmt {
+        CommonTableExpressionStatement::Select(s) => visitor.visit_select_statement(s),
+        CommonTableExpressionStatement::Insert(i) => visitor.visit_insert_statement(i),
+    }
+}
+
+pub fn walk_symbol_mut<V: VisitorMut>(visitor: &mut V, symbol: &mut Symbol) -> V::Result {
+    visitor.visit_span(&mut symbol.location)
+}
+
+pub fn walk_literal_mut<V: VisitorMut>(visitor: &mut V, literal: &mut Literal) -> V::Result {
+    visitor.visit_span(&mut literal.location)
+}
+
+pub fn walk_comparison_operator_mut<V: VisitorMut>(
+    visitor: &mut V,
+    op: &mut ComparisonOperator,
+) -> V::Result {
+    visitor.visit_span(&mut op.location);
+    visitor.visit_comparison_operator_kind(&mut op.kind)
+}
+
+pub fn walk_arithmetic_operator_mut<V: VisitorMut>(
+    visitor: &mut V,
+    op: &mut ArithmeticOperator,
+) -> V::Result {
+    visitor.visit_span(&mut op.location);
+    visitor.visit_arithmetic_operator_kind(&mut op.kind)
+}
+
+pub fn walk_unary_operator_mut<V: VisitorMut>(
+    visitor: &mut V,
+    op: &mut UnaryOperator,
+) -> V::Result {
+    visitor.visit_span(&mut op.location);
+    visitor.visit_unary_operator_kind(&mut op.kind)
+}
+
+pub fn walk_keyword_mut<V: VisitorMut>(visitor: &mut V, keyword: &mut Keyword) -> V::Result {
+    visitor.visit_span(&mut keyword.location);
+    visitor.visit_keyword_kind(&mut keyword.kind)
+}
+
+pub fn walk_data_type_mut<V: VisitorMut>(visitor: &mut V, data_type: &mut DataType) -> V::Result {
+    match data_type {
+        DataType::Int(k)
+        | DataType::BigInt(k)
+        | DataType::TinyInt(k)
+        | DataType::SmallInt(k)
+        | DataType::Datetime(k)
+        | DataType::Time(k)
+        | DataType::Real(k)
+        | DataType::Date(k)
+        | DataType::Bit(k) => visitor.visit_keyword(k),
+        DataType::Decimal(k, ns) | DataType::Numeric(k, ns) => {
+            visitor.visit_keyword(k);
+            walk_opt_mut!(visitor, visit_data_type_numeric_size, ns);
+            V::Result::output()
+        }
+        DataType::Float(k, s)
+        | DataType::Varchar(k, s)
+        | DataType::Nvarchar(k, s)
+        | DataType::Char(k, s)
+        | DataType::Nchar(k, s) => {
+            visitor.visit_keyword(k);
+            walk_opt_mut!(visitor, visit_data_type_size, s);
+            V::Result::output()
+        }
+    }
+}
+
+pub fn walk_top_clause_mut<V: VisitorMut>(visitor: &mut V, top_clause: &mut Top) -> V::Result {
+    visitor.visit_keyword(&mut top_clause.top);
+    walk_opt_list_mut!(visitor, visit_keyword, &mut top_clause.with_ties);
+    walk_opt_mut!(visitor, visit_keyword, &mut top_clause.percent);
+    visitor.visit_expression(&mut top_clause.quantity)
+}
+
+pub fn walk_select_item_mut<V: VisitorMut>(
+    visitor: &mut V,
+    select_item: &mut SelectItem,
+) -> V::Result {
+    match select_item {
+        SelectItem::Wildcard(s) => {
+            visitor.visit_symbol(s);
+            visitor.visit_select_item_wild_card()
+        }
+        SelectItem::QualifiedWildcard(e) | SelectItem::Unnamed(e) => visitor.visit_expression(e),
+        SelectItem::WithAlias {
+            expression,
+            as_kw,
+            alias,
+        }
+        | SelectItem::WildcardWithAlias {
+            expression,
+            as_kw,
+            alias,
+        } => {
+            visitor.visit_expression(expression);
+            walk_opt_mut!(visitor, visit_keyword, as_kw);
+            visitor.visit_expression(alias)
+        }
+        SelectItem::ReverseAliasAssign { alias, expression } => {
+            visitor.visit_expression(alias);
+            visitor.visit_expression(expression)
+        }
+    }
+}
+
+pub fn walk_table_clause_mut<V: VisitorMut>(
+    visitor: &mut V,
+    table_clause: &mut TableArg,
+) -> V::Result {
+    visitor.visit_keyword(&mut table_clause.from);
+    visitor.visit_table_source(&mut table_clause.table);
+    walk_list_mut!(visitor, visit_table_join, &mut table_clause.joins);
+    V::Result::output()
+}
+
+pub fn walk_where_clause_mut<V: VisitorMut>(
+    visitor: &mut V,
+    where_clause: &mut WhereClause,
+) -> V::Result {
+    visitor.visit_keyword(&mut where_clause.where_kw);
+    visitor.visit_expression(&mut where_clause.expression)
+}
+
+pub fn walk_group_by_clause_mut<V: VisitorMut>(
+    visitor: &mut V,
+    group_by_clause: &mut GroupByClause,
+) -> V::Result {
+    walk_list_mut!(visitor, visit_keyword, &mut group_by_clause.group_by_kws);
+    walk_list_mut!(visitor, visit_expression, &mut group_by_clause.expressions);
+    V::Result::output()
+}
+
+pub fn walk_having_clause_mut<V: VisitorMut>(
+    visitor: &mut V,
+    having_clause: &mut HavingClause,
+) -> V::Result {
+    visitor.visit_keyword(&mut having_clause.having_kw);
+    visitor.visit_expression(&mut having_clause.expression)
+}
+
+pub fn walk_order_by_clause_mut<V: VisitorMut>(
+    visitor: &mut V,
+    order_by_clause: &mut OrderByClause,
+) -> V::Result {
+    walk_list_mut!(visitor, visit_keyword, &mut order_by_clause.order_by_kws);
+    walk_list_mut!(
+        visitor,
+        visit_order_by_arg,
+        &mut order_by_clause.expressions
+    );
+    walk_opt_mut!(
+        visitor,
+        visit_order_by_offset_fetch_clause,
+        &mut order_by_clause.offset_fetch_clause
+    );
+
+    V::Result::output()
+}
+
+pub fn walk_limit_clause_mut<V: VisitorMut>(
+    visitor: &mut V,
+    limit_clause: &mut LimitClause,
+) -> V::Result {
+    visitor.visit_keyword(&mut limit_clause.limit_kw);
+    visitor.visit_expression(&mut limit_clause.value);
+    if let Some(offset) = &mut limit_clause.offset {
+        visitor.visit_keyword(&mut offset.0);
+        visitor.visit_expression(&mut offset.1);
+    }
+
+    V::Result::output()
+}
+
+pub fn walk_table_source_mut<V: VisitorMut>(
+    visitor: &mut V,
+    table_source: &mut TableSource,
+) -> V::Result {
+    match table_source {
+        TableSource::Table { name, alias } => {
+            visitor.visit_expression(name);
+            walk_opt_mut!(visitor, visit_expression, alias);
+        }
+        TableSource::Derived {
+            query,
+            as_kw,
+            alias,
+        } => {
+            visitor.visit_expression(query);
+            walk_opt_mut!(visitor, visit_keyword, as_kw);
+            visitor.visit_expression(alias);
+        }
+        TableSource::TableValuedFunction { function, alias } => {
+            visitor.visit_expression(function);
+            walk_opt_mut!(visitor, visit_expression, alias);
+        }
+        TableSource::JoinGroup {
+            left_paren,
+            table,
+            joins,
+            right_paren,
+        } => {
+            visitor.visit_symbol(left_paren);
+            visitor.visit_table_source(table);
+            walk_list_mut!(visitor, visit_table_join, joins);
+            visitor.visit_symbol(right_paren);
+        }
+    }
+
+    V::Result::output()
+}
+
+pub fn walk_table_join_mut<V: VisitorMut>(visitor: &mut V, table_join: &mut Join) -> V::Result {
+    walk_list_mut!(visitor, visit_keyword, &mut table_join.join);
+    visitor.visit_table_join_type(&mut table_join.join_type);
+    visitor.visit_table_source(&mut table_join.table);
+    walk_opt_mut!(
+        visitor,
+        visit_table_join_condition,
+        &mut table_join.condition
+    );
+
+    V::Result::output()
+}
+
+pub fn walk_table_join_condition_mut<V: VisitorMut>(
+    visitor: &mut V,
+    table_join_condition: &mut JoinCondition,
+) -> V::Result {
+    visitor.visit_keyword(&mut table_join_condition.on_kw);
+    visitor.visit_expression(&mut table_join_condition.condition);
+
+    V::Result::output()
+}
+
+pub fn walk_order_by_arg_mut<V: VisitorMut>(
+    visitor: &mut V,
+    order_by_arg: &mut OrderByArg,
+) -> V::Result {
+    visitor.visit_expression(&mut order_by_arg.column);
+    walk_opt_mut!(visitor, visit_keyword, &mut order_by_arg.order_kw);
+    walk_opt_list_mut!(visitor, visit_keyword, &mut order_by_arg.nulls_kws);
+
+    V::Result::output()
+}
+
+pub fn walk_order_by_offset_fetch_clause_mut<V: VisitorMut>(
+    visitor: &mut V,
+    offset_fetch_clause: &mut OffsetFetchClause,
+) -> V::Result {
+    visitor.visit_order_by_offset_arg(&mut offset_fetch_clause.offset);
+    walk_opt_mut!(
+        visitor,
+        visit_order_by_fetch_arg,
+        &mut offset_fetch_clause.fetch
+    );
+
+    V::Result::output()
+}
+
+pub fn walk_order_by_offset_arg_mut<V: VisitorMut>(
+    visitor: &mut V,
+    offset_arg: &mut OffsetArg,
+) -> V::Result {
+    visitor.visit_keyword(&mut offset_arg.offset_kw);
+    visitor.visit_expression(&mut offset_arg.value);
+    visitor.visit_keyword(&mut offset_arg.row_or_rows_kw);
+    visitor.visit_row_or_rows(&mut offset_arg.row)
+}
+
+pub fn walk_order_by_fetch_arg_mut<V: VisitorMut>(
+    visitor: &mut V,
+    fetch_arg: &mut FetchArg,
+) -> V::Result {
+    visitor.visit_keyword(&mut fetch_arg.fetch_kw);
+    visitor.visit_expression(&mut fetch_arg.value);
+    visitor.visit_keyword(&mut fetch_arg.first_or_next_kw);
+    visitor.visit_first_or_next(&mut fetch_arg.first);
+    visitor.visit_keyword(&mut fetch_arg.row_or_rows_kw);
+    visitor.visit_row_or_rows(&mut fetch_arg.row);
+    visitor.visit_keyword(&mut fetch_arg.only_kw)
+}
+
+pub fn walk_function_name_mut<V: VisitorMut>(
+    visitor: &mut V,
+    fn_name: &mut FunctionName,
+) -> V::Result {
+    match fn_name {
+        FunctionName::Builtin(k) => visitor.visit_keyword(k),
+        FunctionName::User(e) => visitor.visit_expression(e),
+    }
+}
+
+pub fn walk_function_over_clause_mut<V: VisitorMut>(
+    visitor: &mut V,
+    over_clause: &mut OverClause,
+) -> V::Result {
+    visitor.visit_keyword(&mut over_clause.over_kw);
+    visitor.visit_symbol(&mut over_clause.left_paren);
+    walk_opt_list_mut!(visitor, visit_keyword, &mut over_clause.partition_by_kws);
+    walk_list_mut!(visitor, visit_expression, &mut over_clause.partition_by);
+    walk_opt_list_mut!(visitor, visit_keyword, &mut over_clause.order_by_kws);
+    walk_list_mut!(visitor, visit_order_by_arg, &mut over_clause.order_by);
+    walk_opt_mut!(
+        visitor,
+        visit_function_over_clause_window_frame,
+        &mut over_clause.window_frame
+    );
+    visitor.visit_symbol(&mut over_clause.right_paren);
+
+    V::Result::output()
+}
+
+pub fn walk_function_over_clause_window_frame_mut<V: VisitorMut>(
+    visitor: &mut V,
+    window_frame: &mut WindowFrame,
+) -> V::Result {
+    visitor.visit_rows_or_range(&mut window_frame.rows_or_range);
+    visitor.visit_keyword(&mut window_frame.rows_or_range_kw);
+    walk_list_mut!(
+        visitor,
+        visit_keyword,
+        &mut window_frame.start_bound_keywords
+    );
+    visitor.visit_function_over_clause_window_frame_bound(&mut window_frame.start);
+    walk_opt_mut!(visitor, visit_keyword, &mut window_frame.between_kw);
+    walk_opt_mut!(visitor, visit_keyword, &mut window_frame.and_kw);
+    walk_opt_list_mut!(visitor, visit_keyword, &mut window_frame.end_bound_keywords);
+    walk_opt_mut!(
+        visitor,
+        visit_function_over_clause_window_frame_bound,
+        &mut window_frame.end
+    );
+
+    V::Result::output()
+}
+
+pub fn walk_case_condition_mut<V: VisitorMut>(
+    visitor: &mut V,
+    case_condition: &mut CaseCondition,
+) -> V::Result {
+    match case_condition {
+        CaseCondition::WhenCondition {
+            when_kw,
+            when_expression,
+            then_kw,
+            result_expression,
+        } => {
+            visitor.visit_keyword(when_kw);
+            visitor.visit_expression(when_expression);
+            visitor.visit_keyword(then_kw);
+            visitor.visit_expression(result_expression);
+        }
+        CaseCondition::ElseCondition {
+            else_kw,
+            result_expression,
+        } => {
+            visitor.visit_keyword(else_kw);
+            visitor.visit_expression(result_expression);
+        }
+    };
+
+    V::Result::output()
+}
+
+pub fn walk_common_table_expression_mut<V: VisitorMut>(
+    visitor: &mut V,
+    cte: &mut CommonTableExpression,
+) -> V::Result {
+    visitor.visit_expression(&mut cte.name);
+    walk_opt_mut!(visitor, visit_expression_list, &mut cte.columns);
+    visitor.visit_keyword(&mut cte.as_kw);
+    visitor.visit_select_statement(&mut cte.query)
+}
+
+pub fn walk_expression_list_mut<V: VisitorMut>(
+    visitor: &mut V,
+    list: &mut ExpressionList,
+) -> V::Result {
+    visitor.visit_symbol(&mut list.left_paren);
+    walk_list_mut!(visitor, visit_expression, &mut list.items);
+    visitor.visit_symbol(&mut list.right_paren)
+}
+
+pub fn walk_execute_statement_procedure_parameter_mut<V: VisitorMut>(
+    visitor: &mut V,
+    param: &mut ProcedureParameter,
+) -> V::Result {
+    if let Some(name) = &mut param.name {
+        visitor.visit_execute_statement_procedure_parameter_name(&mut name.0);
+        visitor.visit_symbol(&mut name.1);
+    }
+    visitor.visit_expression(&mut param.value)
+}
+
+pub fn walk_execute_statement_procedure_parameter_name_mut<V: VisitorMut>(
+    visitor: &mut V,
+    name: &mut ProcedureParameterName,
+) -> V::Result {
+    visitor.visit_span(&mut name.location)
+}
+
+pub fn walk_local_variable_mut<V: VisitorMut>(
+    visitor: &mut V,
+    local_variable: &mut LocalVariable,
+) -> V::Result {
+    visitor.visit_expression(&mut local_variable.name);
+    visitor.visit_data_type(&mut local_variable.data_type);
+    if let Some(value) = &mut local_variable.value {
+        visitor.visit_symbol(&mut value.0);
+        visitor.visit_expression(&mut value.1);
+    }
+
+    V::Result::output()
+}
+
+pub fn walk_column_def_mut<V: VisitorMut>(
+    visitor: &mut V,
+    column_def: &mut ColumnDef,
+) -> V::Result {
+    visitor.visit_expression(&mut column_def.name);
+    visitor.visit_data_type(&mut column_def.data_type);
+    walk_list_mut!(
+        visitor,
+        visit_column_constraint,
+        &mut column_def.constraints
+    );
+
+    V::Result::output()
+}
+
+pub fn walk_column_constraint_mut<V: VisitorMut>(
+    visitor: &mut V,
+    constraint: &mut ColumnConstraint,
+) -> V::Result {
+    match constraint {
+        ColumnConstraint::Null(null_kw) => visitor.visit_keyword(null_kw),
+        ColumnConstraint::NotNull { not_kw, null_kw } => {
+            visitor.visit_keyword(not_kw);
+            visitor.visit_keyword(null_kw)
+        }
+        ColumnConstraint::PrimaryKey { primary_kw, key_kw } => {
+            visitor.visit_keyword(primary_kw);
+            visitor.visit_keyword(key_kw)
+        }
+        ColumnConstraint::Identity {
+            identity_kw,
+            seed_increment,
+        } => {
+            visitor.visit_keyword(identity_kw);
+            walk_opt_mut!(visitor, visit_identity_seed_increment, seed_increment);
+            V::Result::output()
+        }
+        ColumnConstraint::Default { default_kw, value } => {
+            visitor.visit_keyword(default_kw);
+            visitor.visit_expression(value)
+        }
+    }
+}
+
+pub fn walk_identity_seed_increment_mut<V: VisitorMut>(
+    visitor: &mut V,
+    seed_increment: &mut IdentitySeedIncrement,
+) -> V::Result {
+    visitor.visit_symbol(&mut seed_increment.left_paren);
+    visitor.visit_expression(&mut seed_increment.seed);
+    visitor.visit_expression(&mut seed_increment.increment);
+    visitor.visit_symbol(&mut seed_increment.right_paren)
+}
+
+pub fn walk_truncate_partitions_mut<V: VisitorMut>(
+    visitor: &mut V,
+    partitions: &mut TruncatePartitions,
+) -> V::Result {
+    visitor.visit_keyword(&mut partitions.with_kw);
+    visitor.visit_symbol(&mut partitions.left_paren);
+    visitor.visit_keyword(&mut partitions.partitions_kw);
+    visitor.visit_symbol(&mut partitions.partitions_left_paren);
+    walk_list_mut!(visitor, visit_partition_range, &mut partitions.partitions);
+    visitor.visit_symbol(&mut partitions.partitions_right_paren);
+    visitor.visit_symbol(&mut partitions.right_paren)
+}
+
+pub fn walk_partition_range_mut<V: VisitorMut>(
+    visitor: &mut V,
+    partition: &mut PartitionRange,
+) -> V::Result {
+    match partition {
+        PartitionRange::Single(partition) => visitor.visit_expression(partition),
+        PartitionRange::Range { start, to_kw, end } => {
+            visitor.visit_expression(start);
+            visitor.visit_keyword(to_kw);
+            visitor.visit_expression(end)
+        }
+    }
+}
+
+pub fn walk_alter_index_action_mut<V: VisitorMut>(
+    visitor: &mut V,
+    action: &mut AlterIndexAction,
+) -> V::Result {
+    match action {
+        AlterIndexAction::Rebuild(kw)
+        | AlterIndexAction::Reorganize(kw)
+        | AlterIndexAction::Disable(kw) => visitor.visit_keyword(kw),
+    }
+}
@@ -0,0 +1,72 @@
+use lexer::{Lexer, Span};
+use parser::ast::{self, AlterIndexAction};
+use parser::error::ParseErrorType;
+use parser::Parser;
+
+#[test]
+fn alter_index_rebuild() {
+    let input = r"ALTER INDEX IX_MarketData_Symbol ON dbo.MarketData REBUILD";
+    let expected_query = "alter index IX_MarketData_Symbol on dbo.MarketData rebuild";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty(), "{:?}", parser.errors());
+    assert_eq!(expected_query, query.to_string());
+
+    let Some(ast::Statement::AlterIndex { action, .. }) = query.statements.first() else {
+        panic!("expected an alter index statement");
+    };
+    assert!(matches!(action, AlterIndexAction::Rebuild(_)));
+}
+
+#[test]
+fn alter_index_reorganize() {
+    let input = r"ALTER INDEX ALL ON MarketData REORGANIZE";
+    let expected_query = "alter index all on MarketData reorganize";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty(), "{:?}", parser.errors());
+    assert_eq!(expected_query, query.to_string());
+
+    let Some(ast::Statement::AlterIndex { action, .. }) = query.statements.first() else {
+        panic!("expected an alter index statement");
+    };
+    assert!(matches!(action, AlterIndexAction::Reorganize(_)));
+}
+
+#[test]
+fn alter_index_disable() {
+    let input = r"ALTER INDEX [IX Symbol] ON MarketData DISABLE";
+    let expected_query = "alter index [IX Symbol] on MarketData disable";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty(), "{:?}", parser.errors());
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn alter_index_errors() {
+    let lexer = Lexer::new(r"ALTER INDEX ON MarketData REBUILD");
+    let mut parser = Parser::new(lexer);
+    parser.parse();
+
+    let error = parser.errors().first().expect("expected an error");
+    assert_eq!(ParseErrorType::ExpectedIndexName, error.error);
+    assert_eq!(Span::new(12, 13), error.span);
+
+    let lexer = Lexer::new(r"ALTER INDEX IX ON MarketData DROP");
+    let mut parser = Parser::new(lexer);
+    parser.parse();
+
+    let error = parser.errors().first().expect("expected an error");
+    let ParseErrorType::UnexpectedToken { expected, .. } = &error.error else {
+        panic!("expected an unexpected token error, got {:?}", error);
+    };
+    assert_eq!(&vec!["rebuild", "reorganize", "disable"], expected);
+    assert_eq!(Span::new(29, 32), error.span);
+}
@@ -0,0 +1,30 @@
+use lexer::Lexer;
+use parser::Parser;
+
+#[test]
+fn create_table_statement() {
+    let input =
+        r"CREATE TABLE dbo.Cities (Name VARCHAR(50) NOT NULL, Population DECIMAL(10,2) NULL)";
+    let mut expected_query = String::from("create table dbo.Cities (Name varchar(50) not null,");
+    expected_query += " Population decimal(10, 2) null)";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn create_table_statement_with_identity_primary_key() {
+    let input = r"create table Customers (Id int identity(1,1) primary key,
+    Region varchar(10) default 'west')";
+    let mut expected_query = String::from("create table Customers (Id int identity(1, 1)");
+    expected_query += " primary key, Region varchar(10) default 'west')";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
@@ -0,0 +1,39 @@
+use lexer::Lexer;
+use parser::Parser;
+
+#[test]
+fn delete_statement() {
+    let input = r"DELETE FROM MarketData WHERE Symbol = 'MSFT'";
+    let expected_query = "delete from MarketData where Symbol = 'MSFT'";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn delete_statement_with_top() {
+    let input = r"DELETE TOP (100) FROM MarketData WHERE Volume = 0";
+    let expected_query = "delete top (100) from MarketData where Volume = 0";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn delete_statement_with_output() {
+    let input = r"DELETE FROM MarketData OUTPUT deleted.* INTO MarketArchive WHERE Volume = 0";
+    let expected_query =
+        "delete from MarketData output deleted.* into MarketArchive where Volume = 0";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
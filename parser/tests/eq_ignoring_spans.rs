@@ -0,0 +1,42 @@
+use lexer::Lexer;
+use parser::ast::{self, SelectItem, SelectStatement};
+use parser::Parser;
+
+fn parse_select(input: &str) -> SelectStatement {
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty(), "{input}: {:?}", parser.errors());
+    let Some(ast::Statement::Select(select)) = query.statements.into_iter().next() else {
+        panic!("expected a select statement");
+    };
+    select
+}
+
+#[test]
+fn expressions_at_different_positions() {
+    let select = parse_select("SELECT a + b, x, a  +  b, a - b FROM t");
+    let [SelectItem::Unnamed(first), _, SelectItem::Unnamed(second), SelectItem::Unnamed(third)] =
+        select.columns.as_slice()
+    else {
+        panic!("expected four unnamed select items");
+    };
+
+    assert_ne!(first, second);
+    assert!(first.eq_ignoring_spans(second));
+    assert!(second.eq_ignoring_spans(first));
+    assert!(!first.eq_ignoring_spans(third));
+}
+
+#[test]
+fn select_statements_at_different_positions() {
+    let first = parse_select("SELECT a + b FROM t WHERE a IN (1, 2)");
+    let second = parse_select("  select a+b\n  from t\n  where a in (1,2)");
+
+    assert_ne!(first, second);
+    assert!(first.eq_ignoring_spans(&second));
+
+    let other = parse_select("SELECT a + b FROM u WHERE a IN (1, 2)");
+    assert!(!first.eq_ignoring_spans(&other));
+}
@@ -0,0 +1,85 @@
+use lexer::Lexer;
+use parser::error::ParseError;
+use parser::Parser;
+
+fn first_error(input: &str) -> ParseError<'_> {
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let _ = parser.parse();
+
+    parser.errors().first().cloned().expect("expected an error")
+}
+
+#[test]
+fn error_render_underlines_the_token() {
+    let input = "SELECT a\nFROM WHERE\nORDER BY a";
+    let expected = "FROM WHERE\n     ^^^^^";
+
+    assert_eq!(expected, first_error(input).render(input));
+}
+
+#[test]
+fn error_render_token_at_line_start() {
+    let input = "SELECT a FROM t;\nFORM t";
+    let expected = "FORM t\n^^^^";
+
+    assert_eq!(expected, first_error(input).render(input));
+}
+
+#[test]
+fn error_render_end_of_last_line() {
+    let input = "SELECT a\nFROM t WHERE";
+    let expected = "FROM t WHERE\n            ^";
+
+    assert_eq!(expected, first_error(input).render(input));
+}
+
+#[test]
+fn error_render_keeps_tabs() {
+    let input = "SELECT a\r\n\tFROM WHERE";
+    let expected = "\tFROM WHERE\n\t     ^^^^^";
+
+    assert_eq!(expected, first_error(input).render(input));
+}
+
+#[test]
+fn error_details_name_the_unexpected_keyword() {
+    let input = "SELECT a FROM WHERE";
+    let expected = "I was not expecting the keyword WHERE here, expected one of:
+- identifier
+- quoted identifier
+- local variable
+- (";
+
+    assert_eq!(expected, first_error(input).details());
+}
+
+#[test]
+fn error_details_with_a_single_expected_item() {
+    let input = "SELECT a FROM t WHERE a = ,";
+
+    assert_eq!(
+        "I expected an expression but found `,`",
+        first_error(input).details()
+    );
+}
+
+#[test]
+fn error_details_at_the_end_of_the_input() {
+    let input = "SELECT a FROM t ORDER";
+
+    assert_eq!(
+        "I expected the keyword BY but the input ended here",
+        first_error(input).details()
+    );
+}
+
+#[test]
+fn error_details_with_a_single_expected_symbol() {
+    let input = "SELECT (1 FROM t";
+
+    assert_eq!(
+        "I expected `)` but found the keyword FROM",
+        first_error(input).details()
+    );
+}
@@ -0,0 +1,88 @@
+use lexer::Lexer;
+use parser::ast::{self, Expression};
+use parser::eval::{eval_scalar, EvalError, Value};
+use parser::Parser;
+
+fn parse_expression(expression: &str) -> Expression {
+    let input = format!("SELECT a FROM t WHERE {expression}");
+    let lexer = Lexer::new(&input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(
+        parser.errors().is_empty(),
+        "{expression}: {:?}",
+        parser.errors()
+    );
+    let Some(ast::Statement::Select(select)) = query.statements.into_iter().next() else {
+        panic!("expected a select statement");
+    };
+    select
+        .where_clause
+        .expect("expected a where clause")
+        .expression
+}
+
+fn eval(expression: &str) -> Result<Value, EvalError> {
+    eval_scalar(&parse_expression(expression))
+}
+
+#[test]
+fn eval_arithmetic() {
+    let tests = [
+        ("1 + 2 * 3", 7.0),
+        ("(1 + 2) * 3", 9.0),
+        ("7 / 2", 3.5),
+        ("7 % 4", 3.0),
+        ("-2 * 3", -6.0),
+        ("1.5 + 1", 2.5),
+    ];
+
+    for (input, expected) in tests {
+        assert_eq!(Ok(Value::Number(expected)), eval(input), "input: {}", input);
+    }
+
+    assert_eq!(Ok(Value::Str("ab".to_string())), eval("'a' + 'b'"));
+    assert_eq!(Err(EvalError::DivisionByZero), eval("1 / 0"));
+    assert_eq!(Err(EvalError::TypeMismatch), eval("1 + 'a'"));
+}
+
+#[test]
+fn eval_boolean_expressions() {
+    let tests = [
+        ("5 BETWEEN 1 AND 10", true),
+        ("5 NOT BETWEEN 1 AND 10", false),
+        ("11 BETWEEN 1 AND 10", false),
+        ("2 IN (1, 2, 3)", true),
+        ("4 NOT IN (1, 2, 3)", true),
+        ("1 < 2 AND 2 < 3", true),
+        ("1 > 2 OR 'a' = 'a'", true),
+        ("'a' = 'b'", false),
+        ("'a' <> 'b'", true),
+        ("1 + 1 >= 2", true),
+    ];
+
+    for (input, expected) in tests {
+        assert_eq!(Ok(Value::Bool(expected)), eval(input), "input: {}", input);
+    }
+
+    assert_eq!(Err(EvalError::TypeMismatch), eval("1 = 'a'"));
+}
+
+#[test]
+fn eval_column_reference() {
+    assert_eq!(Err(EvalError::ColumnReference), eval("a + 1"));
+    assert_eq!(Err(EvalError::ColumnReference), eval("1 IN (t.a, 2)"));
+}
+
+#[test]
+fn eval_null_propagation() {
+    assert_eq!(Ok(Value::Null), eval("NULL = 1"));
+    assert_eq!(Ok(Value::Null), eval("NULL + 1"));
+    // 1 IN (2, NULL) is unknown but 1 IN (1, NULL) is true
+    assert_eq!(Ok(Value::Null), eval("1 IN (2, NULL)"));
+    assert_eq!(Ok(Value::Bool(true)), eval("1 IN (1, NULL)"));
+    assert_eq!(Ok(Value::Null), eval("NULL AND TRUE"));
+    assert_eq!(Ok(Value::Bool(false)), eval("NULL AND FALSE"));
+    assert_eq!(Ok(Value::Bool(true)), eval("NULL OR TRUE"));
+}
@@ -0,0 +1,44 @@
+use lexer::Lexer;
+use parser::error::ParseErrorType;
+use parser::{Parser, DEFAULT_MAX_EXPRESSION_DEPTH};
+
+#[test]
+fn deeply_nested_expression_reports_error() {
+    let input = format!("SELECT {}1{}", "(".repeat(10_000), ")".repeat(10_000));
+    let lexer = Lexer::new(&input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(query.statements.is_empty());
+    assert_eq!(
+        Some(&ParseErrorType::ExpressionTooDeeplyNested {
+            max_depth: DEFAULT_MAX_EXPRESSION_DEPTH
+        }),
+        parser.errors().first().map(|e| &e.error)
+    );
+}
+
+#[test]
+fn nested_expression_within_max_depth() {
+    let depth = DEFAULT_MAX_EXPRESSION_DEPTH - 2;
+    let input = format!("SELECT {}1{}", "(".repeat(depth), ")".repeat(depth));
+    let lexer = Lexer::new(&input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(input.to_lowercase(), query.to_string());
+}
+
+#[test]
+fn nested_expression_with_configured_max_depth() {
+    let input = r"SELECT ((1 + 2) * 3), -(-(-1))";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer).with_max_expression_depth(3);
+    parser.parse();
+
+    assert_eq!(
+        Some(&ParseErrorType::ExpressionTooDeeplyNested { max_depth: 3 }),
+        parser.errors().first().map(|e| &e.error)
+    );
+}
@@ -0,0 +1,46 @@
+use lexer::{Lexer, Span, Token, TokenKind};
+use parser::{ast, Parser};
+
+#[test]
+fn parse_manually_built_tokens() {
+    // select name, id from users
+    let tokens = vec![
+        Token::new(TokenKind::Select, Span::new(0, 5)),
+        Token::new(TokenKind::Identifier("name"), Span::new(7, 10)),
+        Token::new(TokenKind::Comma, Span::new(11, 11)),
+        Token::new(TokenKind::Identifier("id"), Span::new(13, 14)),
+        Token::new(TokenKind::From, Span::new(16, 19)),
+        Token::new(TokenKind::Identifier("users"), Span::new(21, 25)),
+    ];
+    let mut parser = Parser::from_tokens(tokens);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty(), "{:?}", parser.errors());
+    assert_eq!("select name, id from users", query.to_string());
+
+    let Some(ast::Statement::Select(select)) = query.statements.first() else {
+        panic!("expected a select statement");
+    };
+    assert_eq!(2, select.columns.len());
+}
+
+#[test]
+fn parse_tokens_from_a_rewritten_stream() {
+    let input = r"select a from t where a = 1 -- only active";
+    let tokens = Lexer::new(input)
+        .map(|token| token.expect("expected the input to lex"))
+        .take_while(|token| token.kind() != TokenKind::Eof)
+        .map(|token| match token.kind_as_ref() {
+            TokenKind::Identifier("t") => {
+                Token::new(TokenKind::Identifier("users"), token.location())
+            }
+            _ => token,
+        })
+        .collect();
+    let mut parser = Parser::from_tokens(tokens);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty(), "{:?}", parser.errors());
+    assert_eq!("select a from users where a = 1", query.to_string());
+    assert_eq!(1, parser.comments().len());
+}
@@ -25,3 +25,16 @@ fn insert_from_table_statement() {
 
     assert_eq!(expected_query, query.to_string());
 }
+
+#[test]
+fn insert_top_into_statement() {
+    let input = r"INSERT TOP (10) INTO dbo.TestTable SELECT c.* FROM Customer c WHERE c.speed > 32";
+    let mut expected_query = String::from("insert top (10) into dbo.TestTable select c.* from");
+    expected_query += " Customer c where c.speed > 32";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
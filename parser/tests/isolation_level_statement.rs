@@ -0,0 +1,60 @@
+use lexer::Lexer;
+use parser::ast::{IsolationLevel, Statement};
+use parser::Parser;
+
+fn parse_isolation_level(input: &str) -> (IsolationLevel, String) {
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    match query.statements.first() {
+        Some(Statement::SetIsolationLevel { level, .. }) => (*level, query.to_string()),
+        _ => panic!("expected a set isolation level statement"),
+    }
+}
+
+#[test]
+fn set_isolation_level_read_uncommitted() {
+    let (level, query) = parse_isolation_level(r"SET TRANSACTION ISOLATION LEVEL READ UNCOMMITTED");
+    assert_eq!(IsolationLevel::ReadUncommitted, level);
+    assert_eq!("set transaction isolation level read uncommitted", query);
+}
+
+#[test]
+fn set_isolation_level_read_committed() {
+    let (level, query) = parse_isolation_level(r"set transaction isolation level read committed");
+    assert_eq!(IsolationLevel::ReadCommitted, level);
+    assert_eq!("set transaction isolation level read committed", query);
+}
+
+#[test]
+fn set_isolation_level_repeatable_read() {
+    let (level, query) = parse_isolation_level(r"SET TRANSACTION ISOLATION LEVEL REPEATABLE READ");
+    assert_eq!(IsolationLevel::RepeatableRead, level);
+    assert_eq!("set transaction isolation level repeatable read", query);
+}
+
+#[test]
+fn set_isolation_level_snapshot() {
+    let (level, query) = parse_isolation_level(r"SET TRANSACTION ISOLATION LEVEL SNAPSHOT");
+    assert_eq!(IsolationLevel::Snapshot, level);
+    assert_eq!("set transaction isolation level snapshot", query);
+}
+
+#[test]
+fn set_isolation_level_serializable() {
+    let (level, query) = parse_isolation_level(r"SET TRANSACTION ISOLATION LEVEL SERIALIZABLE;");
+    assert_eq!(IsolationLevel::Serializable, level);
+    assert_eq!("set transaction isolation level serializable", query);
+}
+
+#[test]
+fn set_isolation_level_invalid_level() {
+    let input = r"SET TRANSACTION ISOLATION LEVEL READ";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let _ = parser.parse();
+
+    assert!(!parser.errors().is_empty());
+}
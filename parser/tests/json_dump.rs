@@ -0,0 +1,22 @@
+use lexer::Lexer;
+use parser::json::JsonVisitor;
+use parser::Parser;
+
+#[test]
+fn json_dump_select_statement() {
+    let input = r#"SELECT Symbol, [Say "hi"] from MarketData where LastPrice > 20"#;
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+    let json = JsonVisitor::dump(&query);
+
+    assert!(parser.errors().is_empty());
+    assert!(json.starts_with(r#"{"type":"Query","children":[{"type":"SelectStatement""#));
+    assert!(json.contains(r#"{"type":"Keyword","kind":"select","span":{"start":0,"end":5}}"#));
+    assert!(json
+        .contains(r#"{"type":"Identifier","content":"MarketData","span":{"start":31,"end":40}}"#));
+    assert!(json.contains(r#""content":"Say \"hi\"""#));
+    assert!(json.contains(r#"{"type":"ComparisonOperator","kind":"GreaterThan""#));
+    assert!(json.contains(r#"{"type":"WhereClause","children":["#));
+    assert!(json.ends_with("]}]}"));
+}
@@ -0,0 +1,54 @@
+use lexer::Lexer;
+use parser::ast::Expression;
+use parser::visitor::{walk_expression, Visitor};
+use parser::Parser;
+
+// collects the names of every local variable expression in a query
+struct LocalVariables(Vec<String>);
+
+impl Visitor for LocalVariables {
+    type Result = ();
+
+    fn visit_expression(&mut self, expr: &Expression) -> Self::Result {
+        if let Expression::LocalVariable(name) = expr {
+            self.0.push(name.content.clone());
+        }
+        walk_expression(self, expr)
+    }
+}
+
+fn parse_local_variables(input: &str) -> Vec<String> {
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty(), "{input}: {:?}", parser.errors());
+    let mut local_variables = LocalVariables(vec![]);
+    local_variables.visit_query(&query);
+    local_variables.0
+}
+
+#[test]
+fn local_variables_in_every_expression_position() {
+    let cases = [
+        ("SELECT @a, @b + 1 AS x FROM t", vec!["a", "b"]),
+        ("SELECT a FROM t WHERE a = @a AND @b > 1", vec!["a", "b"]),
+        ("SELECT f(@a), ABS(@b) FROM t", vec!["a", "b"]),
+        ("INSERT INTO t (a, b) VALUES (@a, @b)", vec!["a", "b"]),
+        (
+            "SELECT a FROM t ORDER BY a OFFSET @skip ROWS FETCH NEXT @take ROWS ONLY",
+            vec!["skip", "take"],
+        ),
+        ("SELECT TOP (@n) a FROM t", vec!["n"]),
+        ("UPDATE t SET a = @a WHERE b = @b", vec!["a", "b"]),
+        (
+            "SELECT a FROM t WHERE a IN (@a, @b) AND c BETWEEN @c AND @d",
+            vec!["a", "b", "c", "d"],
+        ),
+        ("SELECT a FROM t ORDER BY @a", vec!["a"]),
+    ];
+
+    for (input, expected) in cases {
+        assert_eq!(expected, parse_local_variables(input), "{input}");
+    }
+}
@@ -1,4 +1,6 @@
-use lexer::Lexer;
+use lexer::{Lexer, Span};
+use parser::ast;
+use parser::error::ParseErrorType;
 use parser::Parser;
 
 #[test]
@@ -13,6 +15,115 @@ fn declare_local_variable_statement() {
     assert_eq!(expected_query, query.to_string());
 }
 
+#[test]
+fn declare_single_local_variable_statement() {
+    let input = r"DECLARE @Symbol VARCHAR(10)";
+    let expected_query = String::from("declare @Symbol varchar(10)");
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn declare_multiple_local_variables_statement() {
+    let input = r"DECLARE @x INT = 5, @y VARCHAR(10), @z decimal(10, 2) = @x * 2";
+    let mut expected_query = String::from("declare @x int = 5, @y varchar(10),");
+    expected_query += " @z decimal(10, 2) = @x * 2";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn declare_local_variables_with_sized_data_types() {
+    let input = r"DECLARE @a INT, @b VARCHAR(MAX), @c NVARCHAR(50), @d CHAR(1), @e DECIMAL(18,4)";
+    let mut expected_query = String::from("declare @a int, @b varchar(max), @c nvarchar(50),");
+    expected_query += " @d char(1), @e decimal(18, 4)";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty(), "{:?}", parser.errors());
+    assert_eq!(expected_query, query.to_string());
+
+    let Some(ast::Statement::Declare { variables, .. }) = query.statements.first() else {
+        panic!("expected a declare statement");
+    };
+    assert_eq!(
+        ast::DataType::Varchar(
+            ast::Keyword::new(Span::new(19, 25), ast::KeywordKind::Varchar),
+            Some(ast::DataTypeSize {
+                left_paren: ast::Symbol {
+                    kind: ast::SymbolKind::LeftParen,
+                    location: Span::new(26, 26),
+                },
+                size: ast::DataTypeLength::Max(ast::Keyword::new(
+                    Span::new(27, 29),
+                    ast::KeywordKind::Max
+                )),
+                right_paren: ast::Symbol {
+                    kind: ast::SymbolKind::RightParen,
+                    location: Span::new(30, 30),
+                },
+            })
+        ),
+        variables[1].data_type
+    );
+}
+
+#[test]
+fn declare_local_variable_with_invalid_data_type() {
+    let tests = [
+        (
+            "DECLARE @a MONEY",
+            ParseErrorType::ExpectedDataType,
+            Span::new(11, 15),
+        ),
+        (
+            "DECLARE @a VARCHAR()",
+            ParseErrorType::ExpectedDataTypeSize,
+            Span::new(19, 19),
+        ),
+        (
+            "DECLARE @a DECIMAL()",
+            ParseErrorType::ExpectedDataTypeSize,
+            Span::new(19, 19),
+        ),
+        (
+            "DECLARE @a DECIMAL(18,)",
+            ParseErrorType::ExpectedDataTypeSize,
+            Span::new(22, 22),
+        ),
+        (
+            "DECLARE @a CHAR(MAX)",
+            ParseErrorType::ExpectedDataTypeSize,
+            Span::new(16, 18),
+        ),
+        (
+            "DECLARE @a VARCHAR(x)",
+            ParseErrorType::ExpectedDataTypeSize,
+            Span::new(19, 19),
+        ),
+    ];
+
+    for (input, error, span) in tests {
+        let lexer = Lexer::new(input);
+        let mut parser = Parser::new(lexer);
+        parser.parse();
+
+        let errors = parser.errors();
+        assert_eq!(1, errors.len(), "{input}: {errors:?}");
+        assert_eq!(error, errors[0].error, "{input}");
+        assert_eq!(span, errors[0].span, "{input}");
+    }
+}
+
 #[test]
 fn set_local_variable_statement() {
     let input = r"SET @MyCounter = 0";
@@ -23,3 +134,85 @@ fn set_local_variable_statement() {
 
     assert_eq!(expected_query, query.to_string());
 }
+
+#[test]
+fn set_local_variable_statement_with_expression() {
+    let input = r"SET @counter = @counter + 1";
+    let expected_query = String::from("set @counter = @counter + 1");
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn set_local_variable_statement_with_compound_assignment() {
+    let input = r"SET @counter += 1; set @total *= @rate";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!("set @counter += 1", query.statements[0].to_string());
+    assert_eq!("set @total *= @rate", query.statements[1].to_string());
+}
+
+#[test]
+fn set_statement_without_local_variable() {
+    let input = r"SET counter = 1";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let _ = parser.parse();
+
+    assert_eq!(
+        Some(&ParseErrorType::ExpectedLocalVariable),
+        parser.errors().first().map(|e| &e.error)
+    );
+}
+
+#[test]
+fn set_statement_with_multiple_local_variables() {
+    let input = r"SET @a = 1, @b = 2";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let _ = parser.parse();
+
+    let error = parser.errors().first().expect("expected an error");
+    assert_eq!(ParseErrorType::MultipleVariablesInSet, error.error);
+    assert_eq!(Span::new(10, 10), error.span);
+}
+
+#[test]
+fn select_statement_assigning_multiple_local_variables() {
+    let input = r"SELECT @a = 1, @b = 2; SET @c = 3";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty(), "{:?}", parser.errors());
+    assert_eq!("select @a = 1, @b = 2", query.statements[0].to_string());
+    assert_eq!("set @c = 3", query.statements[1].to_string());
+
+    let ast::Statement::Select(select) = &query.statements[0] else {
+        panic!("expected a select statement");
+    };
+    for column in &select.columns {
+        assert!(
+            matches!(
+                column,
+                ast::SelectItem::ReverseAliasAssign {
+                    alias: ast::Expression::LocalVariable(_),
+                    ..
+                }
+            ),
+            "expected a variable assignment, got {:?}",
+            column
+        );
+    }
+    assert!(matches!(
+        query.statements[1],
+        ast::Statement::SetLocalVariable { .. }
+    ));
+}
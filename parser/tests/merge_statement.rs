@@ -0,0 +1,84 @@
+use lexer::{Lexer, Span};
+use parser::ast::{MergeMatchKind, Statement};
+use parser::error::ParseErrorType;
+use parser::Parser;
+
+#[test]
+fn merge_statement() {
+    let input = r"MERGE INTO MarketArchive t USING MarketData s ON t.Symbol = s.Symbol
+    WHEN MATCHED AND s.Volume > 0 THEN UPDATE SET LastPrice = s.LastPrice
+    WHEN NOT MATCHED THEN INSERT (Symbol, LastPrice) VALUES (s.Symbol, s.LastPrice)
+    WHEN NOT MATCHED BY SOURCE THEN DELETE
+    OUTPUT deleted.Symbol, inserted.Symbol";
+    let mut expected_query =
+        String::from("merge into MarketArchive t using MarketData s on t.Symbol = s.Symbol");
+    expected_query += " when matched and s.Volume > 0 then update set LastPrice = s.LastPrice";
+    expected_query += " when not matched then insert (Symbol, LastPrice)";
+    expected_query += " values (s.Symbol, s.LastPrice)";
+    expected_query += " when not matched by source then delete";
+    expected_query += " output deleted.Symbol, inserted.Symbol";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+    let Some(Statement::Merge(merge)) = query.statements.first() else {
+        panic!("expected a merge statement");
+    };
+    assert_eq!(
+        vec![
+            MergeMatchKind::Matched,
+            MergeMatchKind::NotMatchedByTarget,
+            MergeMatchKind::NotMatchedBySource,
+        ],
+        merge
+            .clauses
+            .iter()
+            .map(|clause| clause.match_kind)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn merge_statement_target_and_source_as_names() {
+    let input = r"MERGE target USING source ON target.Id = source.Id
+    WHEN NOT MATCHED BY TARGET THEN INSERT VALUES (source.Id)";
+    let mut expected_query = String::from("merge target using source on target.Id = source.Id");
+    expected_query += " when not matched by target then insert values (source.Id)";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn merge_statement_without_when() {
+    let input = r"MERGE MarketArchive USING MarketData ON 1 = 1";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let _ = parser.parse();
+
+    let error = parser.errors().first().expect("expected an error");
+    assert!(matches!(
+        error.error,
+        ParseErrorType::UnrecognizedEof { .. }
+    ));
+}
+
+#[test]
+fn merge_statement_matched_by() {
+    let input = r"MERGE MarketArchive USING MarketData ON 1 = 1 WHEN MATCHED BY SOURCE THEN DELETE";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let _ = parser.parse();
+
+    let error = parser.errors().first().expect("expected an error");
+    assert!(matches!(
+        error.error,
+        ParseErrorType::UnexpectedToken { .. }
+    ));
+    assert_eq!(Span::new(59, 60), error.span);
+}
@@ -0,0 +1,90 @@
+use lexer::{Lexer, Span};
+use parser::ast::{self, Expression, SelectItem};
+use parser::optimize::fold_constants;
+use parser::Parser;
+
+fn parse_select_expression(expression: &str) -> Expression {
+    let input = format!("SELECT {expression}");
+    let lexer = Lexer::new(&input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(
+        parser.errors().is_empty(),
+        "{expression}: {:?}",
+        parser.errors()
+    );
+    let Some(ast::Statement::Select(select)) = query.statements.into_iter().next() else {
+        panic!("expected a select statement");
+    };
+    let Some(SelectItem::Unnamed(expression)) = select.columns.into_iter().next() else {
+        panic!("expected an unnamed select item");
+    };
+    expression
+}
+
+fn fold(expression: &str) -> String {
+    fold_constants(parse_select_expression(expression)).to_string()
+}
+
+#[test]
+fn fold_constant_arithmetic() {
+    let tests = [
+        ("1+2", "3"),
+        ("2*3+4", "10"),
+        ("2 + 3 * 4", "14"),
+        ("(1 + 2) * 3", "9"),
+        ("7 / 2", "3"),
+        ("7 % 4", "3"),
+        ("1 - 5", "-4"),
+        ("-5 + 3", "-2"),
+        ("2 * -3", "-6"),
+    ];
+
+    for (input, expected) in tests {
+        assert_eq!(expected, fold(input), "input: {}", input);
+    }
+}
+
+#[test]
+fn fold_only_the_constant_part() {
+    let tests = [
+        ("x + (2*5)", "x + 10"),
+        ("x + 2 * 5", "x + 10"),
+        // (x + 2) + 5 has no constant subtree of its own
+        ("x + 2 + 5", "x + 2 + 5"),
+        ("(x + 1) * (2 + 3)", "(x + 1) * 5"),
+        ("'a' + 'b'", "'a' + 'b'"),
+        ("1.5 + 2", "1.5 + 2"),
+    ];
+
+    for (input, expected) in tests {
+        assert_eq!(expected, fold(input), "input: {}", input);
+    }
+}
+
+#[test]
+fn keep_division_by_zero() {
+    assert_eq!("1 / 0", fold("1 / 0"));
+    assert_eq!("5 % 0", fold("5 % 0"));
+    assert_eq!("x + 1 / 0", fold("x + 1 / 0"));
+}
+
+#[test]
+fn keep_int_overflow() {
+    assert_eq!("2147483647 + 1", fold("2147483647 + 1"));
+    assert_eq!("-2147483648 - 1", fold("-2147483648 - 1"));
+    assert_eq!("65536 * 65536", fold("65536 * 65536"));
+    assert_eq!("2147483647", fold("2147483646 + 1"));
+    // literals past the int range are decimals and are never folded
+    assert_eq!("3000000000 - 1", fold("3000000000 - 1"));
+}
+
+#[test]
+fn folded_literal_spans_its_operands() {
+    let Expression::NumberLiteral(folded) = fold_constants(parse_select_expression("2 + 3 * 4"))
+    else {
+        panic!("expected a number literal");
+    };
+    assert_eq!(Span::new(7, 15), folded.location);
+}
@@ -0,0 +1,230 @@
+use lexer::{Lexer, Span};
+use parser::ast::{self, Expression};
+use parser::Parser;
+
+// renders the nesting of an expression so every operator shows its operands
+fn nesting(expression: &Expression) -> String {
+    match expression {
+        Expression::Arithmetic {
+            operator,
+            left,
+            right,
+        } => format!("({} {} {})", operator, nesting(left), nesting(right)),
+        Expression::Comparison {
+            operator,
+            left,
+            right,
+        } => format!("({} {} {})", operator, nesting(left), nesting(right)),
+        Expression::And { left, right, .. } => {
+            format!("(and {} {})", nesting(left), nesting(right))
+        }
+        Expression::Or { left, right, .. } => format!("(or {} {})", nesting(left), nesting(right)),
+        Expression::Not { expression, .. } => format!("(not {})", nesting(expression)),
+        Expression::Unary { operator, right } => format!("({} {})", operator, nesting(right)),
+        Expression::Between {
+            test_expression,
+            not_kw,
+            begin,
+            end,
+            ..
+        } => format!(
+            "({}between {} {} {})",
+            not_kw.map_or("", |_| "not "),
+            nesting(test_expression),
+            nesting(begin),
+            nesting(end)
+        ),
+        Expression::InExpressionList {
+            test_expression,
+            not_kw,
+            list,
+            ..
+        } => format!(
+            "({}in {} {})",
+            not_kw.map_or("", |_| "not "),
+            nesting(test_expression),
+            list.iter().map(nesting).collect::<Vec<_>>().join(" ")
+        ),
+        Expression::Grouping { expression, .. } => nesting(expression),
+        _ => expression.to_string(),
+    }
+}
+
+fn parse_where_expression(condition: &str) -> String {
+    let input = format!("SELECT a FROM t WHERE {condition}");
+    let lexer = Lexer::new(&input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(
+        parser.errors().is_empty(),
+        "{condition}: {:?}",
+        parser.errors()
+    );
+    let Some(ast::Statement::Select(select)) = query.statements.first() else {
+        panic!("expected a select statement");
+    };
+    let where_clause = select
+        .where_clause
+        .as_ref()
+        .expect("expected a where clause");
+    nesting(&where_clause.expression)
+}
+
+#[test]
+fn arithmetic_precedence() {
+    let cases = [
+        ("a + b * c = 1", "(= (+ a (* b c)) 1)"),
+        ("a * b + c = 1", "(= (+ (* a b) c) 1)"),
+        ("a - b - c = 1", "(= (- (- a b) c) 1)"),
+        ("a / b * c = 1", "(= (* (/ a b) c) 1)"),
+        ("a + b % c = 1", "(= (+ a (% b c)) 1)"),
+        ("a % b * c = 1", "(= (* (% a b) c) 1)"),
+        ("a + b = c * d", "(= (+ a b) (* c d))"),
+        ("(a + b) * c = 1", "(= (* (+ a b) c) 1)"),
+    ];
+
+    for (condition, expected) in cases {
+        assert_eq!(expected, parse_where_expression(condition), "{condition}");
+    }
+}
+
+#[test]
+fn logical_precedence() {
+    let cases = [
+        (
+            "a = b AND c = d OR e = f",
+            "(or (and (= a b) (= c d)) (= e f))",
+        ),
+        (
+            "a = b OR c = d AND e = f",
+            "(or (= a b) (and (= c d) (= e f)))",
+        ),
+        (
+            "a = b AND c = d AND e = f",
+            "(and (and (= a b) (= c d)) (= e f))",
+        ),
+        ("NOT a = b", "(not (= a b))"),
+        ("NOT a = b AND c = d", "(and (not (= a b)) (= c d))"),
+        ("NOT a = b OR NOT c = d", "(or (not (= a b)) (not (= c d)))"),
+    ];
+
+    for (condition, expected) in cases {
+        assert_eq!(expected, parse_where_expression(condition), "{condition}");
+    }
+}
+
+#[test]
+fn comparison_keyword_precedence() {
+    let cases = [
+        (
+            "a + 1 BETWEEN b AND c * 2 AND d = e",
+            "(and (between (+ a 1) b (* c 2)) (= d e))",
+        ),
+        ("a NOT IN (1, 2) OR b = c", "(or (not in a 1 2) (= b c))"),
+        ("NOT a IN (1, 2)", "(not (in a 1 2))"),
+        ("NOT a NOT BETWEEN 1 AND 2", "(not (not between a 1 2))"),
+    ];
+
+    for (condition, expected) in cases {
+        assert_eq!(expected, parse_where_expression(condition), "{condition}");
+    }
+}
+
+#[test]
+fn between_bounds_stop_before_logical_operators() {
+    let cases = [
+        (
+            "a BETWEEN 1 AND 2 AND b = 3",
+            "(and (between a 1 2) (= b 3))",
+        ),
+        (
+            "a NOT BETWEEN 1 AND 2 OR b = 3",
+            "(or (not between a 1 2) (= b 3))",
+        ),
+        (
+            "a BETWEEN 1 AND 2 AND b BETWEEN 3 AND 4",
+            "(and (between a 1 2) (between b 3 4))",
+        ),
+        (
+            "a BETWEEN b - 1 AND b + 1 AND c = d",
+            "(and (between a (- b 1) (+ b 1)) (= c d))",
+        ),
+    ];
+
+    for (condition, expected) in cases {
+        assert_eq!(expected, parse_where_expression(condition), "{condition}");
+    }
+}
+
+#[test]
+fn function_calls_as_comparison_operands() {
+    let cases = [
+        (
+            "ISNUMERIC(x) = 1 AND LEN(name) > 0",
+            "(and (= ISNUMERIC(x) 1) (> LEN(name) 0))",
+        ),
+        (
+            "LEN(name) + LEN(title) > 10 OR ISNUMERIC(x) = 0",
+            "(or (> (+ LEN(name) LEN(title)) 10) (= ISNUMERIC(x) 0))",
+        ),
+        (
+            "NOT ISNUMERIC(x) = 1 AND UPPER(name) = 'A'",
+            "(and (not (= ISNUMERIC(x) 1)) (= upper(name) 'A'))",
+        ),
+    ];
+    for (condition, expected) in cases {
+        assert_eq!(expected, parse_where_expression(condition), "{condition}");
+    }
+}
+
+#[test]
+fn string_concatenation_is_left_associative() {
+    let cases = [
+        ("'a' + b + 'c' = d", "(= (+ (+ 'a' b) 'c') d)"),
+        ("'a' + b + 'c' + e = d", "(= (+ (+ (+ 'a' b) 'c') e) d)"),
+        ("'a' + (b + 'c') = d", "(= (+ 'a' (+ b 'c')) d)"),
+    ];
+
+    for (condition, expected) in cases {
+        assert_eq!(expected, parse_where_expression(condition), "{condition}");
+    }
+}
+
+#[test]
+fn minus_against_a_number_is_a_negative_literal() {
+    let cases = [
+        ("a = -5", "(= a -5)"),
+        ("a = - 5", "(= a (- 5))"),
+        ("a = -x", "(= a (- x))"),
+        ("a = -(a + b)", "(= a (- (+ a b)))"),
+        ("a = -x * 2", "(= a (* (- x) 2))"),
+        ("a = +5", "(= a (+ 5))"),
+        ("a -5 = 1", "(= (- a 5) 1)"),
+        ("a - -5 = 1", "(= (- a -5) 1)"),
+        ("a = -.5", "(= a -.5)"),
+    ];
+
+    for (condition, expected) in cases {
+        assert_eq!(expected, parse_where_expression(condition), "{condition}");
+    }
+
+    let input = "SELECT -5 FROM t";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty(), "{:?}", parser.errors());
+    let Some(ast::Statement::Select(select)) = query.statements.first() else {
+        panic!("expected a select statement");
+    };
+    assert_eq!(
+        vec![ast::SelectItem::Unnamed(Expression::NumberLiteral(
+            ast::Literal {
+                location: Span::new(7, 8),
+                content: "-5".to_string(),
+            }
+        ))],
+        select.columns
+    );
+}
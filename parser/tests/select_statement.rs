@@ -1,5 +1,7 @@
-use lexer::{Lexer, Span};
+use lexer::{Lexer, Span, TokenKind};
 use parser::ast::{self, Keyword, KeywordKind, Symbol, SymbolKind};
+use parser::dialect::Dialect;
+use parser::error::{ParseError, ParseErrorType, ParseWarning, ParseWarningType};
 use parser::Parser;
 
 #[test]
@@ -42,6 +44,7 @@ fn basic_select_statement_new() {
                 kind: SymbolKind::LeftParen,
                 location: Span::new(61, 61),
             },
+            set_quantifier: None,
             args: Some(vec![ast::Expression::Identifier(ast::Literal {
                 location: Span::new(62, 65),
                 content: "bruh".to_string(),
@@ -144,6 +147,368 @@ fn select_statement_with_builtin_fn_three() {
     assert_eq!(expected_query, query.to_string());
 }
 
+#[test]
+fn select_statement_with_row_number_over() {
+    let input = r"SELECT name, ROW_NUMBER() OVER (PARTITION BY dept ORDER BY salary DESC) from emp";
+    let mut expected_query = String::from("select name, row_number() over(partition by dept");
+    expected_query += " order by salary desc) from emp";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn select_statement_with_multiple_over_clauses() {
+    let input = r"SELECT ROW_NUMBER() OVER (PARTITION BY dept ORDER BY salary DESC),
+    RANK() OVER (ORDER BY hired), sum(salary) over () from emp";
+    let mut expected_query = String::from("select row_number() over(partition by dept");
+    expected_query += " order by salary desc), rank() over(order by hired),";
+    expected_query += " sum(salary) over() from emp";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+    let Some(ast::Statement::Select(select)) = query.statements.first() else {
+        panic!("expected a select statement");
+    };
+    let windows: Vec<_> = select
+        .columns
+        .iter()
+        .map(|column| match column {
+            ast::SelectItem::Unnamed(ast::Expression::Function {
+                over: Some(over), ..
+            }) => (
+                over.partition_by
+                    .iter()
+                    .map(|e| e.to_string())
+                    .collect::<Vec<_>>(),
+                over.order_by
+                    .iter()
+                    .map(|o| o.to_string())
+                    .collect::<Vec<_>>(),
+            ),
+            _ => panic!("expected a windowed function"),
+        })
+        .collect();
+    assert_eq!(
+        vec![
+            (vec!["dept".to_string()], vec!["salary desc".to_string()]),
+            (vec![], vec!["hired".to_string()]),
+            (vec![], vec![]),
+        ],
+        windows
+    );
+}
+
+#[test]
+fn select_statement_with_empty_over_clause() {
+    let input = r"SELECT name, SUM(salary) OVER () from emp";
+    let expected_query = "select name, sum(salary) over() from emp";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+    let Some(ast::Statement::Select(select)) = query.statements.first() else {
+        panic!("expected a select statement");
+    };
+    let ast::SelectItem::Unnamed(ast::Expression::Function {
+        over: Some(over), ..
+    }) = &select.columns[1]
+    else {
+        panic!("expected a windowed function");
+    };
+    assert!(over.partition_by_kws.is_none() && over.partition_by.is_empty());
+    assert!(over.order_by_kws.is_none() && over.order_by.is_empty());
+    assert!(over.window_frame.is_none());
+}
+
+#[test]
+fn select_statement_with_window_frame_bounds() {
+    let input = r"SELECT sum(salary) OVER (ORDER BY hired ROWS BETWEEN UNBOUNDED PRECEDING
+    AND CURRENT ROW), sum(salary) over (order by hired rows between current row and 3 following)
+    from emp";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    let Some(ast::Statement::Select(select)) = query.statements.first() else {
+        panic!("expected a select statement");
+    };
+    let frames: Vec<_> = select
+        .columns
+        .iter()
+        .map(|column| match column {
+            ast::SelectItem::Unnamed(ast::Expression::Function {
+                over: Some(over), ..
+            }) => over.window_frame.as_ref().expect("expected a window frame"),
+            _ => panic!("expected a windowed function"),
+        })
+        .collect();
+
+    assert_eq!(ast::WindowFrameBound::UnboundedPreceding, frames[0].start);
+    assert_eq!(Some(ast::WindowFrameBound::CurrentRow), frames[0].end);
+    assert_eq!(ast::WindowFrameBound::CurrentRow, frames[1].start);
+    assert!(matches!(
+        frames[1].end,
+        Some(ast::WindowFrameBound::Following(_))
+    ));
+}
+
+#[test]
+fn select_statement_with_window_frame_missing_rows_or_range() {
+    let input = r"SELECT sum(salary) OVER (ORDER BY hired UNBOUNDED PRECEDING) from emp";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let _ = parser.parse();
+
+    assert_eq!(
+        Some(&ParseErrorType::MissingRowsOrRangeInWindowFrameClause),
+        parser.errors().first().map(|e| &e.error)
+    );
+}
+
+#[test]
+fn select_statement_with_niladic_builtin_fns() {
+    let input = r"SELECT NEWID(), newsequentialid(), RAND(42), rand()";
+    let expected_query = "select newid(), newsequentialid(), rand(42), rand()";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+    let Some(ast::Statement::Select(select)) = query.statements.first() else {
+        panic!("expected a select statement");
+    };
+    let args: Vec<_> = select
+        .columns
+        .iter()
+        .map(|column| match column {
+            ast::SelectItem::Unnamed(ast::Expression::Function { name, args, .. }) => {
+                assert!(matches!(**name, ast::FunctionName::Builtin(_)));
+                args.as_ref().map(|args| args.len())
+            }
+            _ => panic!("expected a builtin function"),
+        })
+        .collect();
+    assert_eq!(vec![None, None, Some(1), None], args);
+}
+
+#[test]
+fn select_statement_with_math_builtin_fns() {
+    let input = r"SELECT ABS(-1), POWER(2, 10), log(8, 2), Sin(angle), ceiling(LastPrice)";
+    let expected_query = "select abs(-1), power(2, 10), log(8, 2), sin(angle), ceiling(LastPrice)";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn select_statement_with_builtin_fn_wrong_arity() {
+    let input = r"SELECT ABS(1, 2)";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let _ = parser.parse();
+
+    assert_eq!(
+        Some(&ParseErrorType::WrongNumberOfFunctionArgs {
+            function: "ABS".to_string(),
+            min: 1,
+            max: 1,
+            found: 2,
+        }),
+        parser.errors().first().map(|e| &e.error)
+    );
+    assert_eq!(
+        Some(Span::new(7, 9)),
+        parser.errors().first().map(|e| e.span)
+    );
+}
+
+#[test]
+fn select_statement_with_compound_identifiers() {
+    let input = r"SELECT a.b, dbo.users.name, dbo.[My Table].col, t.* from t";
+    let expected_query = "select a.b, dbo.users.name, dbo.[My Table].col, t.* from t";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+    let Some(ast::Statement::Select(select)) = query.statements.first() else {
+        panic!("expected a select statement");
+    };
+    let compounds: Vec<_> = select
+        .columns
+        .iter()
+        .map(|column| match column {
+            ast::SelectItem::Unnamed(ast::Expression::Compound(parts))
+            | ast::SelectItem::QualifiedWildcard(ast::Expression::Compound(parts)) => parts,
+            _ => panic!("expected a compound identifier"),
+        })
+        .collect();
+    assert_eq!(
+        vec![2, 3, 3, 2],
+        compounds
+            .iter()
+            .map(|parts| parts.len())
+            .collect::<Vec<_>>()
+    );
+    assert!(matches!(
+        compounds[2][1],
+        ast::Expression::QuotedIdentifier(_)
+    ));
+    assert!(matches!(compounds[3][1], ast::Expression::Asterisk(_)));
+}
+
+#[test]
+fn select_statement_with_invalid_compound_identifier() {
+    let input = r"SELECT t.1 from t";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let _ = parser.parse();
+
+    assert!(matches!(
+        parser.errors().first().map(|e| &e.error),
+        Some(ParseErrorType::UnexpectedToken { .. })
+    ));
+}
+
+#[test]
+fn select_statement_with_round() {
+    let input = r"SELECT ROUND(LastPrice, 2), round(LastPrice, 0, 1) from MarketData";
+    let expected_query = "select round(LastPrice, 2), round(LastPrice, 0, 1) from MarketData";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn select_statement_with_round_missing_length() {
+    let input = r"SELECT ROUND(LastPrice) from MarketData";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let _ = parser.parse();
+
+    assert_eq!(
+        Some(&ParseErrorType::WrongNumberOfFunctionArgs {
+            function: "ROUND".to_string(),
+            min: 2,
+            max: 3,
+            found: 1,
+        }),
+        parser.errors().first().map(|e| &e.error)
+    );
+}
+
+#[test]
+fn select_statement_with_qualified_wildcards() {
+    let input = r"SELECT t.*, u.name, dbo.users.* FROM t inner join dbo.users u on t.id = u.id";
+    let mut expected_query = String::from("select t.*, u.name, dbo.users.* from t");
+    expected_query += " inner join dbo.users u on t.id = u.id";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+    let Some(ast::Statement::Select(select)) = query.statements.first() else {
+        panic!("expected a select statement");
+    };
+    assert!(matches!(
+        select.columns[..],
+        [
+            ast::SelectItem::QualifiedWildcard(_),
+            ast::SelectItem::Unnamed(_),
+            ast::SelectItem::QualifiedWildcard(_)
+        ]
+    ));
+}
+
+#[test]
+fn select_statement_with_aliased_qualified_wildcard() {
+    let input = r"SELECT t.* AS x FROM t";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let _ = parser.parse();
+
+    assert_eq!(
+        Some(&ParseErrorType::AliasOnQualifiedWildcard),
+        parser.errors().first().map(|e| &e.error)
+    );
+}
+
+#[test]
+fn select_statement_with_asterisk_as_wildcard_or_multiply() {
+    let tests = [
+        (r"SELECT a * b FROM t", "select a * b from t"),
+        (r"SELECT * FROM t", "select * from t"),
+        (r"SELECT t.* FROM t", "select t.* from t"),
+        (r"SELECT *, a*b, t.* FROM t", "select *, a * b, t.* from t"),
+    ];
+
+    let mut items = vec![];
+    for (input, expected_query) in tests {
+        let lexer = Lexer::new(input);
+        let mut parser = Parser::new(lexer);
+        let query = parser.parse();
+
+        assert!(parser.errors().is_empty(), "{input}");
+        assert_eq!(expected_query, query.to_string());
+        let Some(ast::Statement::Select(select)) = query.statements.first() else {
+            panic!("expected a select statement");
+        };
+        items.extend(select.columns.clone());
+    }
+
+    assert!(matches!(
+        items[..],
+        [
+            ast::SelectItem::Unnamed(ast::Expression::Arithmetic { .. }),
+            ast::SelectItem::Wildcard(_),
+            ast::SelectItem::QualifiedWildcard(_),
+            ast::SelectItem::Wildcard(_),
+            ast::SelectItem::Unnamed(ast::Expression::Arithmetic { .. }),
+            ast::SelectItem::QualifiedWildcard(_),
+        ]
+    ));
+}
+
+#[test]
+fn select_statement_with_wildcard_as_operand() {
+    let tests = [
+        (r"SELECT a + * FROM t", Span::new(11, 11)),
+        (r"SELECT t.* * 2 FROM t", Span::new(9, 9)),
+        (r"SELECT 2 * t.* FROM t", Span::new(13, 13)),
+    ];
+
+    for (input, span) in tests {
+        let lexer = Lexer::new(input);
+        let mut parser = Parser::new(lexer);
+        let _ = parser.parse();
+
+        assert_eq!(
+            Some(span),
+            parser.errors().first().map(|e| e.span),
+            "{input}"
+        );
+    }
+}
+
 #[test]
 fn select_statement_with_top() {
     let input = r"SELECT distinct top 50 percent with ties  name, yes from testtable";
@@ -180,6 +545,86 @@ fn select_statement_with_top_three() {
     assert_eq!(expected_query, query.to_string());
 }
 
+#[test]
+fn select_statement_with_top_percent_out_of_range() {
+    let input = r"SELECT TOP 150 PERCENT name, yes from testtable";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let _ = parser.parse();
+
+    assert_eq!(
+        vec![ParseError {
+            error: ParseErrorType::TopPercentOutOfRange,
+            span: Span::new(11, 13),
+        }],
+        parser.errors()
+    );
+}
+
+#[test]
+fn select_statement_with_top_percent_in_range() {
+    let input = r"SELECT TOP 50 PERCENT name, yes from testtable";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let _ = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert!(parser.warnings().is_empty());
+}
+
+#[test]
+fn select_statement_with_invalid_top_quantity() {
+    let tests = [
+        (
+            "SELECT TOP -5 name FROM t",
+            ParseErrorType::NegativeTopQuantity,
+            Span::new(11, 12),
+        ),
+        (
+            "SELECT TOP - 5 name FROM t",
+            ParseErrorType::NegativeTopQuantity,
+            Span::new(11, 13),
+        ),
+        (
+            "SELECT TOP (1 - 6) name FROM t",
+            ParseErrorType::NegativeTopQuantity,
+            Span::new(11, 17),
+        ),
+        (
+            "SELECT TOP (150) PERCENT name FROM t",
+            ParseErrorType::TopPercentOutOfRange,
+            Span::new(11, 15),
+        ),
+    ];
+
+    for (input, error, span) in tests {
+        let lexer = Lexer::new(input);
+        let mut parser = Parser::new(lexer);
+        let _ = parser.parse();
+
+        assert_eq!(vec![ParseError { error, span }], parser.errors(), "{input}");
+    }
+}
+
+#[test]
+fn select_statement_with_variable_top_quantity() {
+    let tests = [
+        "select top (@n) name from t",
+        "select top (@n * 2) percent name from t",
+        "select top (select count(*) from u) name from t",
+        "select top 0 name from t",
+    ];
+
+    for input in tests {
+        let lexer = Lexer::new(input);
+        let mut parser = Parser::new(lexer);
+        let query = parser.parse();
+
+        assert!(parser.errors().is_empty(), "{input}: {:?}", parser.errors());
+        assert_eq!(input, query.to_string());
+    }
+}
+
 #[test]
 fn select_statement_with_where_clause() {
     let input = r"SELECT Symbol, LastPrice, PC 'PercentChange' from MarketData where LastPrice
@@ -363,193 +808,1783 @@ fn select_statement_with_where_and_order_by_three() {
 }
 
 #[test]
-fn select_statement_with_subquery() {
-    let input = r"SELECT Symbol, LastPrice, PercentChange, (select Top 1 Exchange from
-    MarketIndices mi where mi.Symbol = m.Symbol) 'TopExchange', OpenPrice from Market m";
-    let mut expected_query = String::from("select Symbol, LastPrice, PercentChange, (select ");
-    expected_query += "top 1 Exchange from MarketIndices mi where mi.Symbol = m.Symbol) ";
-    expected_query += "'TopExchange', OpenPrice from Market m";
+fn select_statement_with_nulls_ordering_generic() {
+    let input = r"SELECT Symbol, LastPrice from MarketData order by LastPrice desc nulls last,
+    Symbol nulls first";
+    let mut expected_query = String::from("select Symbol, LastPrice from MarketData");
+    expected_query += " order by LastPrice desc nulls last, Symbol nulls first";
     let lexer = Lexer::new(input);
-    let mut parser = Parser::new(lexer);
+    let mut parser = Parser::with_dialect(lexer, Dialect::Generic);
     let query = parser.parse();
 
+    assert!(parser.errors().is_empty());
     assert_eq!(expected_query, query.to_string());
 }
 
 #[test]
-fn select_statement_with_where_and_in_subquery() {
-    let input = r"SELECT Symbol, LastPrice, PercentChange, (select Top 1 Exchange from
-    MarketIndices mi where mi.Symbol = m.Symbol) 'TopExchange', OpenPrice from Market m
-    where Symbol in (select Symbol from MarketData where QuoteDate = cast('1-3-24' as date))";
-    let mut expected_query = String::from("select Symbol, LastPrice, PercentChange, (select ");
-    expected_query += "top 1 Exchange from MarketIndices mi where mi.Symbol = m.Symbol) ";
-    expected_query += "'TopExchange', OpenPrice from Market m where Symbol in (select Symbol ";
-    expected_query += "from MarketData where QuoteDate = cast('1-3-24' as date))";
+fn select_statement_with_nulls_ordering_tsql() {
+    let input = r"SELECT Symbol, LastPrice from MarketData order by LastPrice desc nulls last";
     let lexer = Lexer::new(input);
     let mut parser = Parser::new(lexer);
-    let query = parser.parse();
+    let _ = parser.parse();
 
-    assert_eq!(expected_query, query.to_string());
+    assert_eq!(
+        Some(&ParseErrorType::NotSupportedInDialect {
+            feature: "NULLS FIRST/LAST ordering".to_string(),
+            dialect: Dialect::TSql,
+        }),
+        parser.errors().first().map(|e| &e.error)
+    );
 }
 
 #[test]
-fn select_statement_with_where_and_between() {
-    let input = r"SELECT Symbol, LastPrice, PercentChange, (select Top 1 Exchange from
-    MarketIndices mi where mi.Symbol = m.Symbol) 'TopExchange', OpenPrice from Market m
-    where Symbol in (select Symbol from MarketData where QuoteDate = cast('1-3-24' as date))
-    and [PercentChange] between 0.56 and 2.4";
-    let mut expected_query = String::from("select Symbol, LastPrice, PercentChange, (select ");
-    expected_query += "top 1 Exchange from MarketIndices mi where mi.Symbol = m.Symbol) ";
-    expected_query += "'TopExchange', OpenPrice from Market m where Symbol in (select Symbol ";
-    expected_query += "from MarketData where QuoteDate = cast('1-3-24' as date)) and ";
-    expected_query += "[PercentChange] between 0.56 and 2.4";
+fn select_statement_with_union_all() {
+    let input = r"SELECT 1 UNION ALL SELECT 2";
     let lexer = Lexer::new(input);
     let mut parser = Parser::new(lexer);
     let query = parser.parse();
 
-    assert_eq!(expected_query, query.to_string());
+    assert!(parser.errors().is_empty());
+    assert_eq!("select 1 union all select 2", query.to_string());
 }
 
 #[test]
-fn select_statement_with_where_and_not_expression() {
-    let input = r"SELECT Symbol, LastPrice, PercentChange from Market m
-    where nOT [PercentChange] > 0.56";
-    let mut expected_query = String::from("select Symbol, LastPrice, PercentChange ");
-    expected_query += "from Market m where not [PercentChange] > 0.56";
+fn select_statement_with_into() {
+    let input = r"SELECT Symbol, LastPrice INTO PriceSnapshot from MarketData where LastPrice > 20";
+    let mut expected_query = String::from("select Symbol, LastPrice into PriceSnapshot");
+    expected_query += " from MarketData where LastPrice > 20";
     let lexer = Lexer::new(input);
     let mut parser = Parser::new(lexer);
     let query = parser.parse();
 
+    assert!(parser.errors().is_empty());
     assert_eq!(expected_query, query.to_string());
 }
 
 #[test]
-fn select_statement_with_where_and_exists_expression() {
-    let input = r"SELECT Symbol, LastPrice, PercentChange from Market m
-    where nOT exists (select Symbol from MarketData)";
-    let mut expected_query = String::from("select Symbol, LastPrice, PercentChange ");
-    expected_query += "from Market m where not exists (select Symbol from MarketData)";
+fn select_statement_with_into_and_union() {
+    let input =
+        r"SELECT Symbol INTO SymbolSnapshot from MarketData UNION SELECT Symbol from Delisted";
     let lexer = Lexer::new(input);
     let mut parser = Parser::new(lexer);
-    let query = parser.parse();
-
-    assert_eq!(expected_query, query.to_string());
+    let _ = parser.parse();
+
+    assert_eq!(
+        Some(&ParseErrorType::IntoWithSetOperation),
+        parser.errors().first().map(|e| &e.error)
+    );
+    assert_eq!(
+        Some(Span::new(14, 17)),
+        parser.errors().first().map(|e| e.span)
+    );
 }
 
 #[test]
-fn select_statement_with_where_and_all_expression() {
-    let input = r"SELECT Symbol, LastPrice, PercentChange from Market m
-    where ManufactureDays >= all (select ManufactureDays from MarketData)";
-    let mut expected_query = String::from("select Symbol, LastPrice, PercentChange ");
-    expected_query +=
-        "from Market m where ManufactureDays >= all (select ManufactureDays from MarketData)";
+fn select_statement_with_union_and_into() {
+    let input =
+        r"SELECT Symbol from MarketData UNION SELECT Symbol INTO SymbolSnapshot from Delisted";
     let lexer = Lexer::new(input);
     let mut parser = Parser::new(lexer);
-    let query = parser.parse();
-
-    assert_eq!(expected_query, query.to_string());
+    let _ = parser.parse();
+
+    assert_eq!(
+        Some(&ParseErrorType::IntoWithSetOperation),
+        parser.errors().first().map(|e| &e.error)
+    );
+    assert_eq!(
+        Some(Span::new(50, 53)),
+        parser.errors().first().map(|e| e.span)
+    );
 }
 
 #[test]
-fn select_statement_with_where_and_some_expression() {
-    let input = r"SELECT Symbol, LastPrice, PercentChange from Market m
-    where ManufactureDays >= some (select ManufactureDays from MarketData)";
-    let mut expected_query = String::from("select Symbol, LastPrice, PercentChange ");
-    expected_query +=
-        "from Market m where ManufactureDays >= some (select ManufactureDays from MarketData)";
+fn select_statement_with_chained_set_operations() {
+    let input = r"SELECT Symbol from MarketData except select Symbol from Delisted
+    intersect select Symbol from Watchlist";
+    let mut expected_query = String::from("select Symbol from MarketData except");
+    expected_query += " select Symbol from Delisted intersect select Symbol from Watchlist";
     let lexer = Lexer::new(input);
     let mut parser = Parser::new(lexer);
     let query = parser.parse();
 
+    assert!(parser.errors().is_empty());
     assert_eq!(expected_query, query.to_string());
+
+    let ast::Statement::SetOperation {
+        left, op, right, ..
+    } = &query.statements[0]
+    else {
+        panic!("expected a set operation");
+    };
+    assert_eq!(KeywordKind::Except, op.kind);
+    assert!(matches!(left.as_ref(), ast::Statement::Select(_)));
+    assert!(matches!(
+        right.as_ref(),
+        ast::Statement::SetOperation {
+            op: Keyword {
+                kind: KeywordKind::Intersect,
+                ..
+            },
+            ..
+        }
+    ));
 }
 
 #[test]
-fn select_statement_with_where_and_any_expression() {
-    let input = r"SELECT Symbol, LastPrice, PercentChange from Market m
-    where ManufactureDays >= any (select ManufactureDays from MarketData)";
-    let mut expected_query = String::from("select Symbol, LastPrice, PercentChange ");
-    expected_query +=
-        "from Market m where ManufactureDays >= any (select ManufactureDays from MarketData)";
+fn select_statement_intersect_binds_tighter_than_union() {
+    let input = r"SELECT 1 UNION SELECT 2 INTERSECT SELECT 3";
     let lexer = Lexer::new(input);
     let mut parser = Parser::new(lexer);
     let query = parser.parse();
 
-    assert_eq!(expected_query, query.to_string());
+    assert!(parser.errors().is_empty());
+    let ast::Statement::SetOperation {
+        left, op, right, ..
+    } = &query.statements[0]
+    else {
+        panic!("expected a set operation");
+    };
+    assert_eq!(KeywordKind::Union, op.kind);
+    assert_eq!("select 1", left.to_string());
+    let ast::Statement::SetOperation {
+        left, op, right, ..
+    } = right.as_ref()
+    else {
+        panic!("expected the intersect to be the right side of the union");
+    };
+    assert_eq!(KeywordKind::Intersect, op.kind);
+    assert_eq!("select 2", left.to_string());
+    assert_eq!("select 3", right.to_string());
 }
 
 #[test]
-fn select_statement_with_where_and_like_expression() {
-    let input = r"SELECT Symbol, LastPrice, PercentChange from Market m
-    where Symbol like 'AM%' and LastPrice > 32";
-    let mut expected_query = String::from("select Symbol, LastPrice, PercentChange ");
-    expected_query += "from Market m where Symbol like 'AM%' and LastPrice > 32";
+fn select_statement_set_operations_are_left_associative() {
+    let input = r"SELECT 1 UNION SELECT 2 EXCEPT SELECT 3 INTERSECT SELECT 4 INTERSECT SELECT 5";
     let lexer = Lexer::new(input);
     let mut parser = Parser::new(lexer);
     let query = parser.parse();
 
-    assert_eq!(expected_query, query.to_string());
+    assert!(parser.errors().is_empty());
+    let ast::Statement::SetOperation {
+        left, op, right, ..
+    } = &query.statements[0]
+    else {
+        panic!("expected a set operation");
+    };
+    assert_eq!(KeywordKind::Except, op.kind);
+    assert_eq!("select 1 union select 2", left.to_string());
+    let ast::Statement::SetOperation {
+        left, op, right, ..
+    } = right.as_ref()
+    else {
+        panic!("expected the intersect chain to be the right side of the except");
+    };
+    assert_eq!(KeywordKind::Intersect, op.kind);
+    assert_eq!("select 3 intersect select 4", left.to_string());
+    assert_eq!("select 5", right.to_string());
 }
 
 #[test]
-fn select_statement_with_where_and_like_expression_two() {
-    let input = r"SELECT Symbol, LastPrice, PercentChange from Market m
-    where Symbol not like @TestPattern and LastPrice > 32";
-    let mut expected_query = String::from("select Symbol, LastPrice, PercentChange ");
-    expected_query += "from Market m where Symbol not like @TestPattern and LastPrice > 32";
+fn select_statement_with_ilike_generic() {
+    let input = r"SELECT Symbol from MarketData where Symbol ilike 'ms%' and Name not ilike 'a%'";
+    let mut expected_query = String::from("select Symbol from MarketData");
+    expected_query += " where Symbol ilike 'ms%' and Name not ilike 'a%'";
     let lexer = Lexer::new(input);
-    let mut parser = Parser::new(lexer);
+    let mut parser = Parser::with_dialect(lexer, Dialect::Generic);
     let query = parser.parse();
 
+    assert!(parser.errors().is_empty());
     assert_eq!(expected_query, query.to_string());
 }
 
 #[test]
-fn select_statement_with_case_statement() {
-    let input = r"SELECT Symbol, LastPrice, PercentChange, case Exchange when 'nsdq' then 'Nasdaq'
-    when 'dji' then 'Dow Jones' else 'SP500' end from Market m where Symbol not like
-    @TestPattern and LastPrice > 32";
-    let mut expected_query = String::from("select Symbol, LastPrice, PercentChange, case Exchange");
-    expected_query += " when 'nsdq' then 'Nasdaq' when 'dji' then 'Dow Jones' else 'SP500' end";
-    expected_query += " from Market m where Symbol not like @TestPattern and LastPrice > 32";
+fn select_statement_with_ilike_tsql() {
+    let input = r"SELECT Symbol from MarketData where Symbol ilike 'ms%'";
     let lexer = Lexer::new(input);
     let mut parser = Parser::new(lexer);
-    let query = parser.parse();
+    let _ = parser.parse();
 
-    assert_eq!(expected_query, query.to_string());
+    assert_eq!(
+        Some(&ParseErrorType::NotSupportedInDialect {
+            feature: "ILIKE".to_string(),
+            dialect: Dialect::TSql,
+        }),
+        parser.errors().first().map(|e| &e.error)
+    );
 }
 
 #[test]
-fn select_statement_with_cte() {
-    let input = r"with testcte as (select * from MarketLake) SELECT Symbol, LastPrice, 
-    PercentChange from Market m inner join testcte tc on tc.Symbol = m.Symbol where Symbol not like
-    @TestPattern and LastPrice > 32";
-    let mut expected_query = String::from("with testcte as (select * from MarketLake)");
-    expected_query += " select Symbol, LastPrice, PercentChange from Market m";
-    expected_query += " inner join testcte tc on tc.Symbol = m.Symbol";
-    expected_query += " where Symbol not like @TestPattern and LastPrice > 32";
+fn select_statement_with_ilike_as_name_tsql() {
+    let input = r"SELECT ilike, rlike, t.ilike FROM t WHERE ilike = 1";
+    let expected_query = "select ilike, rlike, t.ilike from t where ilike = 1";
     let lexer = Lexer::new(input);
     let mut parser = Parser::new(lexer);
     let query = parser.parse();
 
+    assert!(parser.errors().is_empty());
     assert_eq!(expected_query, query.to_string());
 }
 
 #[test]
-fn select_statement_with_cte_two() {
-    let input = r"with testcte (testcol, poolc)as (select * from MarketLake), potato as (select
-    Symbol,Title, Author from News) SELECT Symbol, LastPrice, PercentChange from Market m inner
-    join testcte tc on tc.Symbol = m.Symbol inner join potato p on m.Symbol = p.Symbol where 
-    Symbol not like @TestPattern and LastPrice > 32";
-    let mut expected_query = String::from("with testcte (testcol, poolc) as (select * from");
-    expected_query += " MarketLake), potato as (select Symbol, Title, Author from News)";
-    expected_query += " select Symbol, LastPrice, PercentChange from Market m";
-    expected_query += " inner join testcte tc on tc.Symbol = m.Symbol";
-    expected_query += " inner join potato p on m.Symbol = p.Symbol";
-    expected_query += " where Symbol not like @TestPattern and LastPrice > 32";
+fn select_statement_with_subscript_generic() {
+    let input = r"SELECT Payload['symbol'], Prices[0] + 1 from [MarketData] where Tags[@i] = 'a'";
+    let mut expected_query = String::from("select Payload['symbol'], Prices[0] + 1");
+    expected_query += " from [MarketData] where Tags[@i] = 'a'";
     let lexer = Lexer::new(input);
-    let mut parser = Parser::new(lexer);
+    let mut parser = Parser::with_dialect(lexer, Dialect::Generic);
     let query = parser.parse();
 
+    assert!(parser.errors().is_empty());
     assert_eq!(expected_query, query.to_string());
 }
+
+#[test]
+fn select_statement_with_identifier_subscript_generic() {
+    let input = r"SELECT arr[i], arr[i + 1], [Values] [v] from t";
+    let expected_query = "select arr[i], arr[i + 1], [Values] [v] from t";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::with_dialect(lexer, Dialect::Generic);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn select_statement_with_subscript_tsql() {
+    let input = r"SELECT Payload['symbol'] from MarketData";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let _ = parser.parse();
+
+    assert_eq!(
+        Some(&ParseErrorType::NotSupportedInDialect {
+            feature: "subscript access".to_string(),
+            dialect: Dialect::TSql,
+        }),
+        parser.errors().first().map(|e| &e.error)
+    );
+}
+
+#[test]
+fn select_statement_with_distinct_on_generic() {
+    let input = r"SELECT DISTINCT ON (Symbol, Exchange) Symbol, LastPrice from MarketData";
+    let mut expected_query = String::from("select distinct on (Symbol, Exchange)");
+    expected_query += " Symbol, LastPrice from MarketData";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::with_dialect(lexer, Dialect::Generic);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn select_statement_with_distinct_on_tsql() {
+    let input = r"SELECT DISTINCT ON (Symbol) Symbol, LastPrice from MarketData";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let _ = parser.parse();
+
+    assert_eq!(
+        Some(&ParseErrorType::NotSupportedInDialect {
+            feature: "DISTINCT ON".to_string(),
+            dialect: Dialect::TSql,
+        }),
+        parser.errors().first().map(|e| &e.error)
+    );
+}
+
+#[test]
+fn select_statement_with_natural_join_generic() {
+    let input = r"SELECT * FROM Market m NATURAL JOIN MarketData md natural left join Quote q";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::with_dialect(lexer, Dialect::Generic);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(
+        "select * from Market m natural join MarketData md natural left join Quote q",
+        query.to_string()
+    );
+    let Some(ast::Statement::Select(select)) = query.statements.first() else {
+        panic!("expected a select statement");
+    };
+    let joins = &select.table.as_ref().expect("expected a from clause").joins;
+    assert_eq!(ast::JoinType::Natural, joins[0].join_type);
+    assert_eq!(ast::JoinType::NaturalLeft, joins[1].join_type);
+    assert!(joins.iter().all(|j| j.condition.is_none()));
+}
+
+#[test]
+fn select_statement_with_natural_join_condition() {
+    let input = r"SELECT * FROM Market m NATURAL JOIN MarketData md ON m.Symbol = md.Symbol";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::with_dialect(lexer, Dialect::Generic);
+    let _ = parser.parse();
+
+    assert_eq!(
+        Some(&ParseErrorType::NaturalJoinWithCondition),
+        parser.errors().first().map(|e| &e.error)
+    );
+}
+
+#[test]
+fn select_statement_with_natural_join_tsql() {
+    let input = r"SELECT * FROM Market m NATURAL JOIN MarketData md";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let _ = parser.parse();
+
+    assert_eq!(
+        Some(&ParseErrorType::NotSupportedInDialect {
+            feature: "NATURAL JOIN".to_string(),
+            dialect: Dialect::TSql,
+        }),
+        parser.errors().first().map(|e| &e.error)
+    );
+    assert_eq!(
+        Some(Span::new(23, 29)),
+        parser.errors().first().map(|e| e.span)
+    );
+}
+
+#[test]
+fn select_statement_join_group_expected_joins_tsql() {
+    let input = r"SELECT a FROM (t WHERE a = 1)";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let _ = parser.parse();
+
+    let Some(ParseErrorType::UnexpectedToken { token, expected }) =
+        parser.errors().first().map(|e| &e.error)
+    else {
+        panic!("expected an unexpected token error");
+    };
+    assert_eq!(&TokenKind::Where, token);
+    assert!(expected.iter().any(|e| e == "join"));
+    assert!(!expected.iter().any(|e| e == "natural"));
+}
+
+#[test]
+fn select_statement_join_group_expected_joins_generic() {
+    let input = r"SELECT a FROM (t WHERE a = 1)";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::with_dialect(lexer, Dialect::Generic);
+    let _ = parser.parse();
+
+    let Some(ParseErrorType::UnexpectedToken { expected, .. }) =
+        parser.errors().first().map(|e| &e.error)
+    else {
+        panic!("expected an unexpected token error");
+    };
+    assert!(expected.iter().any(|e| e == "natural"));
+}
+
+#[test]
+fn select_statement_with_limit_generic() {
+    let input = r"SELECT * FROM MarketData LIMIT 10";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::with_dialect(lexer, Dialect::Generic);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!("select * from MarketData limit 10", query.to_string());
+}
+
+#[test]
+fn select_statement_with_limit_offset_generic() {
+    let input = r"SELECT * FROM MarketData LIMIT 10 OFFSET 5";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::with_dialect(lexer, Dialect::Generic);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(
+        "select * from MarketData limit 10 offset 5",
+        query.to_string()
+    );
+}
+
+#[test]
+fn select_statement_with_limit_tsql() {
+    let input = r"SELECT * FROM MarketData LIMIT 10";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let _ = parser.parse();
+
+    assert_eq!(
+        Some(&ParseErrorType::NotSupportedInDialect {
+            feature: "LIMIT".to_string(),
+            dialect: Dialect::TSql,
+        }),
+        parser.errors().first().map(|e| &e.error)
+    );
+}
+
+#[test]
+fn select_statement_with_parenthesized_offset_fetch() {
+    let input = r"SELECT Symbol, LastPrice from MarketData order by QuoteTime
+    OFFSET (@p * 10) ROWS FETCH NEXT (@q) ROWS ONLY";
+    let mut expected_query = String::from("select Symbol, LastPrice from MarketData");
+    expected_query += " order by QuoteTime offset (@p * 10) rows fetch next (@q) rows only";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn select_statement_with_offset_requires_order_by() {
+    let input = r"SELECT x FROM t OFFSET 10 ROWS";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let _ = parser.parse();
+
+    assert_eq!(
+        vec![ParseError {
+            error: ParseErrorType::OffsetRequiresOrderBy,
+            span: Span::new(16, 21),
+        }],
+        parser.errors()
+    );
+
+    let input = r"SELECT x FROM t WHERE a = 1 ORDER BY x OFFSET 10 ROWS FETCH NEXT 5 ROWS ONLY";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty(), "{:?}", parser.errors());
+    assert_eq!(
+        "select x from t where a = 1 order by x offset 10 rows fetch next 5 rows only",
+        query.to_string()
+    );
+}
+
+#[test]
+fn select_statement_with_subquery() {
+    let input = r"SELECT Symbol, LastPrice, PercentChange, (select Top 1 Exchange from
+    MarketIndices mi where mi.Symbol = m.Symbol) 'TopExchange', OpenPrice from Market m";
+    let mut expected_query = String::from("select Symbol, LastPrice, PercentChange, (select ");
+    expected_query += "top 1 Exchange from MarketIndices mi where mi.Symbol = m.Symbol) ";
+    expected_query += "'TopExchange', OpenPrice from Market m";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn select_statement_with_union_in_derived_table() {
+    let input = r"SELECT u.Symbol from (select Symbol from MarketData union select Symbol
+    from Watchlist) u";
+    let mut expected_query = String::from("select u.Symbol from (select Symbol from MarketData");
+    expected_query += " union select Symbol from Watchlist) u";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn select_statement_with_derived_table_join() {
+    let input = r"SELECT m.Symbol, t.MaxPrice from Market m inner join (select Symbol,
+    max(LastPrice) MaxPrice from MarketData group by Symbol) as t on m.Symbol = t.Symbol";
+    let mut expected_query = String::from("select m.Symbol, t.MaxPrice from Market m inner join");
+    expected_query += " (select Symbol, max(LastPrice) MaxPrice from MarketData group by Symbol)";
+    expected_query += " as t on m.Symbol = t.Symbol";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn select_statement_with_join_group() {
+    let input = r"SELECT a.id FROM a JOIN (b JOIN c ON b.id = c.id) ON a.id = b.id";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(
+        "select a.id from a join (b join c on b.id = c.id) on a.id = b.id",
+        query.to_string()
+    );
+    let Some(ast::Statement::Select(select)) = query.statements.first() else {
+        panic!("expected a select statement");
+    };
+    let table = select.table.as_ref().expect("expected a from clause");
+    assert_eq!(1, table.joins.len());
+    // the group is joined to a as a whole, b and c are joined inside of it
+    let ast::TableSource::JoinGroup { table, joins, .. } = &table.joins[0].table else {
+        panic!("expected a join group");
+    };
+    assert_eq!("b", table.to_string());
+    assert_eq!(1, joins.len());
+    assert_eq!("join c on b.id = c.id", joins[0].to_string());
+}
+
+#[test]
+fn select_statement_with_double_parenthesized_join_group() {
+    let input = r"select x from ((t1 inner join t2 on 1=1))";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(
+        "select x from ((t1 inner join t2 on 1 = 1))",
+        query.to_string()
+    );
+    let Some(ast::Statement::Select(select)) = query.statements.first() else {
+        panic!("expected a select statement");
+    };
+    let table = select.table.as_ref().expect("expected a from clause");
+    // the outer parentheses wrap the inner group without joining anything
+    let ast::TableSource::JoinGroup { table, joins, .. } = &table.table else {
+        panic!("expected a join group");
+    };
+    assert!(joins.is_empty());
+    let ast::TableSource::JoinGroup { table, joins, .. } = table.as_ref() else {
+        panic!("expected a join group inside the parentheses");
+    };
+    assert_eq!("t1", table.to_string());
+    assert_eq!(1, joins.len());
+}
+
+#[test]
+fn select_statement_with_lone_table_in_parentheses() {
+    let input = r"SELECT a.id FROM (a) WHERE a.id = 1";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let _ = parser.parse();
+
+    assert!(matches!(
+        parser.errors().first().map(|e| &e.error),
+        Some(ParseErrorType::UnexpectedToken {
+            token: TokenKind::RightParen,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn select_statement_with_ordered_top_subqueries() {
+    let input = r"SELECT a, (SELECT TOP 1 x FROM u ORDER BY y) FROM (SELECT TOP 5 a FROM t
+    ORDER BY b DESC) d WHERE a IN (SELECT x FROM u ORDER BY y OFFSET 2 ROWS)";
+    let mut expected_query = String::from("select a, (select top 1 x from u order by y)");
+    expected_query += " from (select top 5 a from t order by b desc) d";
+    expected_query += " where a in (select x from u order by y offset 2 rows)";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert!(parser.warnings().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn select_statement_with_ordered_subquery_without_top() {
+    let input = r"SELECT a FROM (SELECT x FROM u ORDER BY y) d ORDER BY a";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let _ = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(
+        vec![ParseWarning {
+            warning: ParseWarningType::OrderByInSubqueryWithoutTop,
+            span: Span::new(31, 38),
+        }],
+        parser.warnings()
+    );
+}
+
+#[test]
+fn select_statement_with_derived_table_missing_alias() {
+    let input = r"SELECT Symbol from (select Symbol from MarketData) where Symbol = 'MSFT'";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let _ = parser.parse();
+
+    assert_eq!(
+        Some(&ParseErrorType::MissingDerivedTableAlias),
+        parser.errors().first().map(|e| &e.error)
+    );
+}
+
+#[test]
+fn select_statement_with_cross_and_outer_apply() {
+    let input = r"SELECT m.Symbol, q.LastPrice from Market m cross apply
+    dbo.LatestQuotes(m.Symbol) q outer apply (select top 1 Exchange from MarketIndices mi
+    where mi.Symbol = m.Symbol) as e";
+    let mut expected_query = String::from("select m.Symbol, q.LastPrice from Market m");
+    expected_query += " cross apply dbo.LatestQuotes(m.Symbol) q outer apply (select top 1";
+    expected_query += " Exchange from MarketIndices mi where mi.Symbol = m.Symbol) as e";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn select_statement_with_where_and_in_subquery() {
+    let input = r"SELECT Symbol, LastPrice, PercentChange, (select Top 1 Exchange from
+    MarketIndices mi where mi.Symbol = m.Symbol) 'TopExchange', OpenPrice from Market m
+    where Symbol in (select Symbol from MarketData where QuoteDate = cast('1-3-24' as date))";
+    let mut expected_query = String::from("select Symbol, LastPrice, PercentChange, (select ");
+    expected_query += "top 1 Exchange from MarketIndices mi where mi.Symbol = m.Symbol) ";
+    expected_query += "'TopExchange', OpenPrice from Market m where Symbol in (select Symbol ";
+    expected_query += "from MarketData where QuoteDate = cast('1-3-24' as date))";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn select_statement_with_between() {
+    let input = r"SELECT name from users where age BETWEEN 18 AND 65";
+    let expected_query = "select name from users where age between 18 and 65";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn select_statement_with_not_between() {
+    let input = r"SELECT x from t where x NOT BETWEEN 1 AND 10";
+    let expected_query = "select x from t where x not between 1 and 10";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn select_statement_with_between_followed_by_and() {
+    let input = r"SELECT a from t where a BETWEEN 1 AND 2 AND b = 3";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    let Some(ast::Statement::Select(select)) = query.statements.first() else {
+        panic!("expected a select statement");
+    };
+    let Some(ast::Expression::And { left, right, .. }) =
+        select.where_clause.as_ref().map(|w| &w.expression)
+    else {
+        panic!("expected a logical and");
+    };
+    assert!(matches!(**left, ast::Expression::Between { .. }));
+    assert!(matches!(**right, ast::Expression::Comparison { .. }));
+}
+
+#[test]
+fn select_statement_with_and_followed_by_between() {
+    let input = r"SELECT a from t where b = 3 and a between 1 and 2 + 3";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    let Some(ast::Statement::Select(select)) = query.statements.first() else {
+        panic!("expected a select statement");
+    };
+    let Some(ast::Expression::And { left, right, .. }) =
+        select.where_clause.as_ref().map(|w| &w.expression)
+    else {
+        panic!("expected a logical and");
+    };
+    assert!(matches!(**left, ast::Expression::Comparison { .. }));
+    let ast::Expression::Between { end, .. } = &**right else {
+        panic!("expected a between expression");
+    };
+    assert!(matches!(**end, ast::Expression::Arithmetic { .. }));
+}
+
+#[test]
+fn select_statement_with_bracketed_multi_part_names() {
+    let input = r"SELECT [dbo].[Market].[Symbol], [Prices].[dbo].[Market].[LastPrice]
+    from [Prices].[dbo].[Market]";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    let mut expected_query =
+        String::from("select [dbo].[Market].[Symbol], [Prices].[dbo].[Market].[LastPrice] ");
+    expected_query += "from [Prices].[dbo].[Market]";
+    assert_eq!(expected_query, query.to_string());
+    let Some(ast::Statement::Select(select)) = query.statements.first() else {
+        panic!("expected a select statement");
+    };
+    let part_counts: Vec<usize> = select
+        .columns
+        .iter()
+        .map(|column| match column {
+            ast::SelectItem::Unnamed(ast::Expression::Compound(parts))
+                if parts
+                    .iter()
+                    .all(|part| matches!(part, ast::Expression::QuotedIdentifier(_))) =>
+            {
+                parts.len()
+            }
+            _ => panic!("expected a compound of quoted identifiers"),
+        })
+        .collect();
+    assert_eq!(vec![3, 4], part_counts);
+}
+
+#[test]
+fn select_statement_with_skipped_schema() {
+    let input = r"SELECT [Prices]..[Market].Symbol from Prices..Market";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(
+        "select [Prices]..[Market].Symbol from Prices..Market",
+        query.to_string()
+    );
+    let Some(ast::Statement::Select(select)) = query.statements.first() else {
+        panic!("expected a select statement");
+    };
+    let Some(ast::SelectItem::Unnamed(ast::Expression::Compound(parts))) = select.columns.first()
+    else {
+        panic!("expected a compound identifier");
+    };
+    assert_eq!(4, parts.len());
+    assert_eq!(
+        ast::Expression::Identifier(ast::Literal {
+            location: Span::new(16, 16),
+            content: String::new(),
+        }),
+        parts[1]
+    );
+}
+
+#[test]
+fn select_statement_with_modulus() {
+    let input = r"SELECT a % b from t";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!("select a % b from t", query.to_string());
+    let Some(ast::Statement::Select(select)) = query.statements.first() else {
+        panic!("expected a select statement");
+    };
+    let Some(ast::SelectItem::Unnamed(ast::Expression::Arithmetic { operator, .. })) =
+        select.columns.first()
+    else {
+        panic!("expected an arithmetic expression");
+    };
+    assert_eq!(ast::ArithmeticOperatorKind::Modulus, operator.kind);
+    assert_eq!(Span::new(9, 9), operator.location);
+}
+
+#[test]
+fn select_statement_with_where_and_between() {
+    let input = r"SELECT Symbol, LastPrice, PercentChange, (select Top 1 Exchange from
+    MarketIndices mi where mi.Symbol = m.Symbol) 'TopExchange', OpenPrice from Market m
+    where Symbol in (select Symbol from MarketData where QuoteDate = cast('1-3-24' as date))
+    and [PercentChange] between 0.56 and 2.4";
+    let mut expected_query = String::from("select Symbol, LastPrice, PercentChange, (select ");
+    expected_query += "top 1 Exchange from MarketIndices mi where mi.Symbol = m.Symbol) ";
+    expected_query += "'TopExchange', OpenPrice from Market m where Symbol in (select Symbol ";
+    expected_query += "from MarketData where QuoteDate = cast('1-3-24' as date)) and ";
+    expected_query += "[PercentChange] between 0.56 and 2.4";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn select_statement_with_where_and_not_expression() {
+    let input = r"SELECT Symbol, LastPrice, PercentChange from Market m
+    where nOT [PercentChange] > 0.56";
+    let mut expected_query = String::from("select Symbol, LastPrice, PercentChange ");
+    expected_query += "from Market m where not [PercentChange] > 0.56";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn select_statement_with_where_and_exists_expression() {
+    let input = r"SELECT Symbol, LastPrice, PercentChange from Market m
+    where nOT exists (select Symbol from MarketData)";
+    let mut expected_query = String::from("select Symbol, LastPrice, PercentChange ");
+    expected_query += "from Market m where not exists (select Symbol from MarketData)";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn select_statement_with_in_number_list() {
+    let input = r"SELECT name from users where id IN (1,2,3)";
+    let expected_query = "select name from users where id in (1, 2, 3)";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn select_statement_with_not_in_subquery() {
+    let input = r"SELECT name from users where id NOT IN (SELECT id FROM blocked)";
+    let expected_query = "select name from users where id not in (select id from blocked)";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+    let Some(ast::Statement::Select(select)) = query.statements.first() else {
+        panic!("expected a select statement");
+    };
+    assert!(matches!(
+        select.where_clause.as_ref().map(|w| &w.expression),
+        Some(ast::Expression::InSubquery {
+            not_kw: Some(_),
+            ..
+        })
+    ));
+}
+
+#[test]
+fn select_statement_with_empty_in_list() {
+    let input = r"SELECT name from users where id in ()";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let _ = parser.parse();
+
+    assert_eq!(
+        Some(&ParseErrorType::ExpectedSubqueryOrExpressionList),
+        parser.errors().first().map(|e| &e.error)
+    );
+}
+
+#[test]
+fn select_statement_with_correlated_exists_expression() {
+    let input = r"SELECT u.name from users u
+    where EXISTS (SELECT 1 FROM orders WHERE orders.uid = u.id)";
+    let mut expected_query = String::from("select u.name from users u where exists");
+    expected_query += " (select 1 from orders where orders.uid = u.id)";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn select_statement_with_exists_missing_subquery() {
+    let input = r"SELECT u.name from users u where exists (1, 2)";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let _ = parser.parse();
+
+    assert_eq!(
+        Some(&ParseErrorType::ExpectedSubqueryOrExpressionList),
+        parser.errors().first().map(|e| &e.error)
+    );
+}
+
+#[test]
+fn select_statement_with_where_and_all_expression() {
+    let input = r"SELECT Symbol, LastPrice, PercentChange from Market m
+    where ManufactureDays >= all (select ManufactureDays from MarketData)";
+    let mut expected_query = String::from("select Symbol, LastPrice, PercentChange ");
+    expected_query +=
+        "from Market m where ManufactureDays >= all (select ManufactureDays from MarketData)";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn select_statement_with_where_and_some_expression() {
+    let input = r"SELECT Symbol, LastPrice, PercentChange from Market m
+    where ManufactureDays >= some (select ManufactureDays from MarketData)";
+    let mut expected_query = String::from("select Symbol, LastPrice, PercentChange ");
+    expected_query +=
+        "from Market m where ManufactureDays >= some (select ManufactureDays from MarketData)";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn select_statement_with_where_and_any_expression() {
+    let input = r"SELECT Symbol, LastPrice, PercentChange from Market m
+    where ManufactureDays >= any (select ManufactureDays from MarketData)";
+    let mut expected_query = String::from("select Symbol, LastPrice, PercentChange ");
+    expected_query +=
+        "from Market m where ManufactureDays >= any (select ManufactureDays from MarketData)";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn select_statement_with_where_and_like_expression() {
+    let input = r"SELECT Symbol, LastPrice, PercentChange from Market m
+    where Symbol like 'AM%' and LastPrice > 32";
+    let mut expected_query = String::from("select Symbol, LastPrice, PercentChange ");
+    expected_query += "from Market m where Symbol like 'AM%' and LastPrice > 32";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn select_statement_with_where_and_like_expression_two() {
+    let input = r"SELECT Symbol, LastPrice, PercentChange from Market m
+    where Symbol not like @TestPattern and LastPrice > 32";
+    let mut expected_query = String::from("select Symbol, LastPrice, PercentChange ");
+    expected_query += "from Market m where Symbol not like @TestPattern and LastPrice > 32";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn select_statement_with_case_statement() {
+    let input = r"SELECT Symbol, LastPrice, PercentChange, case Exchange when 'nsdq' then 'Nasdaq'
+    when 'dji' then 'Dow Jones' else 'SP500' end from Market m where Symbol not like
+    @TestPattern and LastPrice > 32";
+    let mut expected_query = String::from("select Symbol, LastPrice, PercentChange, case Exchange");
+    expected_query += " when 'nsdq' then 'Nasdaq' when 'dji' then 'Dow Jones' else 'SP500' end";
+    expected_query += " from Market m where Symbol not like @TestPattern and LastPrice > 32";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn select_statement_with_searched_case_boolean_whens() {
+    let input = r"SELECT case when LastPrice > 0 and Volume between 1 and 10 then 'low'
+    when Symbol in ('a', 'b') or not PercentChange < 2 then 'odd' end from Market";
+    let mut expected_query = String::from("select case when LastPrice > 0 and Volume between 1");
+    expected_query += " and 10 then 'low' when Symbol in ('a', 'b') or not PercentChange < 2";
+    expected_query += " then 'odd' end from Market";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+    let Some(ast::Statement::Select(select)) = query.statements.first() else {
+        panic!("expected a select statement");
+    };
+    let ast::SelectItem::Unnamed(ast::Expression::SearchedCase { conditions, .. }) =
+        &select.columns[0]
+    else {
+        panic!("expected a searched case");
+    };
+    assert!(matches!(
+        &conditions[0],
+        ast::CaseCondition::WhenCondition {
+            when_expression: ast::Expression::And { .. },
+            ..
+        }
+    ));
+    assert!(matches!(
+        &conditions[1],
+        ast::CaseCondition::WhenCondition {
+            when_expression: ast::Expression::Or { .. },
+            ..
+        }
+    ));
+}
+
+#[test]
+fn select_statement_with_simple_case_value_whens() {
+    let input = r"SELECT case LastPrice + 1 when 2 * 3 then 'six' when -1 then 'zero'
+    else 'other' end from Market";
+    let mut expected_query = String::from("select case LastPrice + 1 when 2 * 3 then 'six'");
+    expected_query += " when -1 then 'zero' else 'other' end from Market";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+    let Some(ast::Statement::Select(select)) = query.statements.first() else {
+        panic!("expected a select statement");
+    };
+    let ast::SelectItem::Unnamed(ast::Expression::SimpleCase {
+        input_expression,
+        conditions,
+        ..
+    }) = &select.columns[0]
+    else {
+        panic!("expected a simple case");
+    };
+    assert!(matches!(
+        input_expression.as_ref(),
+        ast::Expression::Arithmetic { .. }
+    ));
+    assert!(matches!(
+        &conditions[0],
+        ast::CaseCondition::WhenCondition {
+            when_expression: ast::Expression::Arithmetic { .. },
+            ..
+        }
+    ));
+    assert!(matches!(
+        &conditions[2],
+        ast::CaseCondition::ElseCondition { .. }
+    ));
+}
+
+#[test]
+fn select_statement_with_simple_case_boolean_when() {
+    let input = r"SELECT case Symbol when Symbol = 'a' then 1 end from Market";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    parser.parse();
+
+    let error = parser.errors().first().expect("expected an error");
+    assert!(matches!(
+        error.error,
+        ParseErrorType::UnexpectedToken {
+            token: TokenKind::Equal,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn select_statement_with_cte() {
+    let input = r"with testcte as (select * from MarketLake) SELECT Symbol, LastPrice, 
+    PercentChange from Market m inner join testcte tc on tc.Symbol = m.Symbol where Symbol not like
+    @TestPattern and LastPrice > 32";
+    let mut expected_query = String::from("with testcte as (select * from MarketLake)");
+    expected_query += " select Symbol, LastPrice, PercentChange from Market m";
+    expected_query += " inner join testcte tc on tc.Symbol = m.Symbol";
+    expected_query += " where Symbol not like @TestPattern and LastPrice > 32";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn select_statement_with_cte_two() {
+    let input = r"with testcte (testcol, poolc)as (select * from MarketLake), potato as (select
+    Symbol,Title, Author from News) SELECT Symbol, LastPrice, PercentChange from Market m inner
+    join testcte tc on tc.Symbol = m.Symbol inner join potato p on m.Symbol = p.Symbol where 
+    Symbol not like @TestPattern and LastPrice > 32";
+    let mut expected_query = String::from("with testcte (testcol, poolc) as (select * from");
+    expected_query += " MarketLake), potato as (select Symbol, Title, Author from News)";
+    expected_query += " select Symbol, LastPrice, PercentChange from Market m";
+    expected_query += " inner join testcte tc on tc.Symbol = m.Symbol";
+    expected_query += " inner join potato p on m.Symbol = p.Symbol";
+    expected_query += " where Symbol not like @TestPattern and LastPrice > 32";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn select_statement_with_cte_column_list() {
+    let input = r"WITH c (a, b) AS (SELECT x, y AS z FROM t) SELECT a, b FROM c";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert!(parser.warnings().is_empty());
+    assert_eq!(
+        "with c (a, b) as (select x, y as z from t) select a, b from c",
+        query.to_string()
+    );
+
+    let Some(ast::Statement::CTE { ctes, .. }) = query.statements.first() else {
+        panic!("expected a cte statement");
+    };
+    let columns = ctes[0].columns.as_ref().expect("expected a column list");
+    assert_eq!(
+        vec!["a", "b"],
+        columns
+            .items
+            .iter()
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn select_statement_with_cte_column_count_mismatch() {
+    let input = r"WITH c (a, b) AS (SELECT x FROM t) SELECT a FROM c";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let _ = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(
+        vec![ParseWarning {
+            warning: ParseWarningType::CteColumnCountMismatch {
+                expected: 2,
+                found: 1,
+            },
+            span: Span::new(7, 12),
+        }],
+        parser.warnings()
+    );
+
+    // a wildcard could expand to any number of columns
+    for input in [
+        r"WITH c (a, b) AS (SELECT * FROM t) SELECT a FROM c",
+        r"WITH c (a, b) AS (SELECT t.* FROM t) SELECT a FROM c",
+        r"WITH c (a, b, d) AS (SELECT x, t.* FROM t) SELECT a FROM c",
+    ] {
+        let lexer = Lexer::new(input);
+        let mut parser = Parser::new(lexer);
+        let _ = parser.parse();
+
+        assert!(parser.errors().is_empty(), "{input}");
+        assert!(parser.warnings().is_empty(), "{input}");
+    }
+}
+
+#[test]
+fn select_statement_with_column_named_like_an_aggregate() {
+    let input = r"SELECT count, t.max FROM t WHERE count > 1 ORDER BY sum";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(
+        "select count, t.max from t where count > 1 order by sum",
+        query.to_string()
+    );
+    let Some(ast::Statement::Select(select)) = query.statements.first() else {
+        panic!("expected a select statement");
+    };
+    let Some(ast::SelectItem::Unnamed(ast::Expression::Identifier(count))) = select.columns.first()
+    else {
+        panic!("expected an identifier");
+    };
+    assert_eq!("count", count.content);
+    assert_eq!(Span::new(7, 11), count.location);
+    assert!(matches!(
+        select.columns.get(1),
+        Some(ast::SelectItem::Unnamed(ast::Expression::Compound(_)))
+    ));
+}
+
+#[test]
+fn select_statement_with_aggregate_call() {
+    let input = r"SELECT COUNT(*) FROM t";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!("select count(*) from t", query.to_string());
+    let Some(ast::Statement::Select(select)) = query.statements.first() else {
+        panic!("expected a select statement");
+    };
+    assert!(matches!(
+        select.columns.first(),
+        Some(ast::SelectItem::Unnamed(ast::Expression::Function { .. }))
+    ));
+}
+
+#[test]
+fn select_statement_with_count_distinct() {
+    let input = r"SELECT count(Symbol), COUNT(DISTINCT Symbol), count(all Symbol) from Market";
+    let expected_query =
+        "select count(Symbol), count(distinct Symbol), count(all Symbol) from Market";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+    let Some(ast::Statement::Select(select)) = query.statements.first() else {
+        panic!("expected a select statement");
+    };
+    let quantifiers: Vec<_> = select
+        .columns
+        .iter()
+        .map(|column| match column {
+            ast::SelectItem::Unnamed(ast::Expression::Function {
+                set_quantifier,
+                args: Some(args),
+                ..
+            }) if args.len() == 1 => set_quantifier.as_ref().map(|kw| kw.kind),
+            _ => panic!("expected a function with one argument"),
+        })
+        .collect();
+    assert_eq!(
+        vec![None, Some(KeywordKind::Distinct), Some(KeywordKind::All)],
+        quantifiers
+    );
+}
+
+#[test]
+fn select_statement_with_count_distinct_missing_argument() {
+    let input = r"SELECT count(distinct) from Market";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    parser.parse();
+
+    assert!(!parser.errors().is_empty());
+}
+
+#[test]
+fn select_statement_with_count_star() {
+    let input = r"SELECT COUNT(*), count(Symbol), count(distinct Symbol) from Market";
+    let expected_query = "select count(*), count(Symbol), count(distinct Symbol) from Market";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+    let Some(ast::Statement::Select(select)) = query.statements.first() else {
+        panic!("expected a select statement");
+    };
+    let ast::SelectItem::Unnamed(ast::Expression::Function {
+        name,
+        set_quantifier: None,
+        args: Some(args),
+        ..
+    }) = &select.columns[0]
+    else {
+        panic!("expected a function");
+    };
+    assert!(matches!(
+        name.as_ref(),
+        ast::FunctionName::Builtin(Keyword {
+            kind: KeywordKind::Count,
+            ..
+        })
+    ));
+    assert!(matches!(args.as_slice(), [ast::Expression::Asterisk(_)]));
+}
+
+#[test]
+fn select_statement_with_count_star_and_other_args() {
+    for input in [
+        r"SELECT COUNT(*, Symbol) from Market",
+        r"SELECT COUNT(Symbol, *) from Market",
+    ] {
+        let lexer = Lexer::new(input);
+        let mut parser = Parser::new(lexer);
+        parser.parse();
+
+        assert_eq!(
+            Some(&ParseErrorType::AsteriskWithOtherFunctionArgs),
+            parser.errors().first().map(|e| &e.error),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn select_statement_with_trailing_commas() {
+    let inputs = [
+        ("SELECT a, b, FROM t", Span::new(11, 11)),
+        (
+            "SELECT a FROM t GROUP BY a, b, ORDER BY a",
+            Span::new(29, 29),
+        ),
+        ("SELECT a FROM t ORDER BY a, b,", Span::new(29, 29)),
+        ("SELECT a FROM t WHERE a IN (1, 2,)", Span::new(32, 32)),
+        (
+            "SELECT sum(a) OVER (PARTITION BY b, c,) FROM t",
+            Span::new(37, 37),
+        ),
+        ("SELECT round(a, 2,) FROM t", Span::new(17, 17)),
+    ];
+
+    for (input, span) in inputs {
+        let lexer = Lexer::new(input);
+        let mut parser = Parser::new(lexer);
+        parser.parse();
+
+        let error = parser.errors().first().expect(input);
+        assert_eq!(ParseErrorType::TrailingComma, error.error, "{input}");
+        assert_eq!(span, error.span, "{input}");
+    }
+}
+
+#[test]
+fn select_statement_with_unicode_string_literal() {
+    let input = r"SELECT '🎉 party' AS msg, naïve from [fête] where 名前 = 'ünïcode'";
+    let expected_query = "select '🎉 party' as msg, naïve from [fête] where 名前 = 'ünïcode'";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn select_statement_with_alias_assignment() {
+    let input = r"SELECT IsActive = (status = 1) FROM t";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty(), "{:?}", parser.errors());
+    assert_eq!("select IsActive = (status = 1) from t", query.to_string());
+
+    let Some(ast::Statement::Select(select)) = query.statements.first() else {
+        panic!("expected a select statement");
+    };
+    let Some(ast::SelectItem::ReverseAliasAssign { alias, expression }) = select.columns.first()
+    else {
+        panic!("expected an alias assignment, got {:?}", select.columns);
+    };
+    assert_eq!(
+        &ast::Expression::Identifier(ast::Literal {
+            location: Span::new(7, 14),
+            content: "IsActive".to_string(),
+        }),
+        alias
+    );
+    let ast::Expression::Grouping { expression, .. } = expression else {
+        panic!("expected a grouping, got {:?}", expression);
+    };
+    assert!(
+        matches!(
+            expression.as_ref(),
+            ast::Expression::Comparison { operator, .. }
+                if operator.kind == ast::ComparisonOperatorKind::Equal
+        ),
+        "expected a comparison, got {:?}",
+        expression
+    );
+}
+
+#[test]
+fn select_statement_alias_assignment_needs_a_column_name() {
+    let tests = [
+        ("SELECT a = b FROM t", true),
+        ("SELECT [Is Active] = 1 FROM t", true),
+        ("SELECT 'Is Active' = 1 FROM t", true),
+        ("SELECT @total = sum(a) FROM t", true),
+        ("SELECT t.a = 1 FROM t", false),
+        ("SELECT 1 = a FROM t", false),
+        ("SELECT a + 1 = b FROM t", false),
+    ];
+
+    for (input, is_alias_assignment) in tests {
+        let lexer = Lexer::new(input);
+        let mut parser = Parser::new(lexer);
+        let query = parser.parse();
+
+        assert!(parser.errors().is_empty(), "{input}: {:?}", parser.errors());
+        let Some(ast::Statement::Select(select)) = query.statements.first() else {
+            panic!("expected a select statement");
+        };
+        assert_eq!(
+            is_alias_assignment,
+            matches!(
+                select.columns.first(),
+                Some(ast::SelectItem::ReverseAliasAssign { .. })
+            ),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn select_statement_paren_after_name_is_a_call() {
+    let tests = [
+        ("SELECT COUNT() FROM t", "function"),
+        ("SELECT dbo.f() FROM t", "function"),
+        ("SELECT (a + b) FROM t", "grouping"),
+        ("SELECT (SELECT 1) FROM t", "subquery"),
+    ];
+
+    for (input, expected) in tests {
+        let lexer = Lexer::new(input);
+        let mut parser = Parser::new(lexer);
+        let query = parser.parse();
+
+        assert!(parser.errors().is_empty(), "{input}: {:?}", parser.errors());
+        let Some(ast::Statement::Select(select)) = query.statements.first() else {
+            panic!("expected a select statement");
+        };
+        let Some(ast::SelectItem::Unnamed(expr)) = select.columns.first() else {
+            panic!("expected an unnamed select item");
+        };
+        let found = match expr {
+            ast::Expression::Function { args: None, .. } => "function",
+            ast::Expression::Grouping { .. } => "grouping",
+            ast::Expression::Subquery { .. } => "subquery",
+            _ => "other",
+        };
+        assert_eq!(expected, found, "{input}: {expr:?}");
+    }
+}
+
+#[test]
+fn select_statement_paren_after_literal_is_not_a_call() {
+    let tests = [
+        ("SELECT @a(1) FROM t", Span::new(9, 9)),
+        ("SELECT 'x'(1) FROM t", Span::new(10, 10)),
+        ("SELECT 1(1) FROM t", Span::new(8, 8)),
+    ];
+
+    for (input, span) in tests {
+        let lexer = Lexer::new(input);
+        let mut parser = Parser::new(lexer);
+        parser.parse();
+
+        let errors = parser.errors();
+        assert_eq!(1, errors.len(), "{input}: {errors:?}");
+        assert!(
+            matches!(
+                errors[0].error,
+                ParseErrorType::UnexpectedToken {
+                    token: TokenKind::LeftParen,
+                    ..
+                }
+            ),
+            "{input}: {errors:?}"
+        );
+        assert_eq!(span, errors[0].span, "{input}");
+    }
+}
+
+#[test]
+fn select_statement_with_null_and_boolean_literals() {
+    let input = "SELECT NULL FROM t WHERE flag = TRUE AND NOT done = FALSE";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty(), "{:?}", parser.errors());
+    let Some(ast::Statement::Select(select)) = query.statements.first() else {
+        panic!("expected a select statement");
+    };
+    assert_eq!(
+        vec![ast::SelectItem::Unnamed(ast::Expression::Keyword(
+            Keyword::new(Span::new(7, 10), KeywordKind::Null)
+        ))],
+        select.columns
+    );
+
+    let where_clause = select
+        .where_clause
+        .as_ref()
+        .expect("expected a where clause");
+    let ast::Expression::And { left, right, .. } = &where_clause.expression else {
+        panic!(
+            "expected an and expression, got {:?}",
+            where_clause.expression
+        );
+    };
+    assert_eq!(
+        ast::Expression::Comparison {
+            operator: ast::ComparisonOperator {
+                location: Span::new(30, 30),
+                kind: ast::ComparisonOperatorKind::Equal,
+            },
+            left: Box::new(ast::Expression::Identifier(ast::Literal {
+                location: Span::new(25, 28),
+                content: "flag".to_string(),
+            })),
+            right: Box::new(ast::Expression::Keyword(Keyword::new(
+                Span::new(32, 35),
+                KeywordKind::True
+            ))),
+        },
+        **left
+    );
+    let ast::Expression::Not { expression, .. } = right.as_ref() else {
+        panic!("expected a not expression, got {right:?}");
+    };
+    assert!(
+        matches!(
+            expression.as_ref(),
+            ast::Expression::Comparison { right, .. }
+                if matches!(right.as_ref(), ast::Expression::Keyword(k) if k.kind == KeywordKind::False)
+        ),
+        "{expression:?}"
+    );
+    assert_eq!(
+        "select null from t where flag = true and not done = false",
+        query.to_string()
+    );
+}
+
+#[test]
+fn select_statement_ending_mid_clause() {
+    let tests = [
+        ("SELECT", Span::new(6, 6)),
+        ("SELECT a FROM", Span::new(13, 13)),
+        ("SELECT a FROM t WHERE", Span::new(21, 21)),
+        ("SELECT a FROM t WHERE a =", Span::new(25, 25)),
+        ("SELECT a FROM t ORDER", Span::new(21, 21)),
+        ("SELECT a FROM t ORDER BY ", Span::new(25, 25)),
+        ("SELECT a FROM t GROUP BY", Span::new(24, 24)),
+    ];
+
+    for (input, span) in tests {
+        let lexer = Lexer::new(input);
+        let mut parser = Parser::new(lexer);
+        parser.parse();
+
+        let errors = parser.errors();
+        assert_eq!(1, errors.len(), "{input}: {errors:?}");
+        assert!(
+            matches!(errors[0].error, ParseErrorType::UnrecognizedEof { .. }),
+            "{input}: {errors:?}"
+        );
+        assert_eq!(span, errors[0].span, "{input}");
+    }
+
+    let lexer = Lexer::new("SELECT a FROM t ORDER");
+    let mut parser = Parser::new(lexer);
+    parser.parse();
+    assert_eq!(
+        "I expected the keyword BY but the input ended here",
+        parser.errors()[0].details()
+    );
+}
+
+#[test]
+fn select_statement_with_function_calls() {
+    let input = "SELECT GETDATE(), LEN(name), UPPER(LTRIM(x)) FROM t";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty(), "{:?}", parser.errors());
+    let Some(ast::Statement::Select(select)) = query.statements.first() else {
+        panic!("expected a select statement");
+    };
+    let calls = select
+        .columns
+        .iter()
+        .map(|item| match item {
+            ast::SelectItem::Unnamed(ast::Expression::Function { name, args, .. }) => {
+                (name.to_string(), args.clone())
+            }
+            _ => panic!("expected a function call, got {item:?}"),
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(("getdate".to_string(), None), calls[0]);
+    assert_eq!(
+        (
+            "LEN".to_string(),
+            Some(vec![ast::Expression::Identifier(ast::Literal {
+                location: Span::new(22, 25),
+                content: "name".to_string(),
+            })])
+        ),
+        calls[1]
+    );
+    assert_eq!("upper", calls[2].0);
+    assert!(
+        matches!(
+            calls[2].1.as_deref(),
+            Some([ast::Expression::Function { name, args: Some(args), .. }])
+                if name.to_string() == "LTRIM" && args.len() == 1
+        ),
+        "{:?}",
+        calls[2].1
+    );
+}
+
+#[test]
+fn select_statement_with_calls_as_function_args() {
+    let tests = [
+        "select upper(upper(x)) from t",
+        "select round(abs(x), 2) from t",
+        "select count(cast(x as int)) from t",
+        "select f(null, (1), case when a = 1 then 1 end) from t",
+    ];
+
+    for input in tests {
+        let lexer = Lexer::new(input);
+        let mut parser = Parser::new(lexer);
+        let query = parser.parse();
+
+        assert!(parser.errors().is_empty(), "{input}: {:?}", parser.errors());
+        assert_eq!(input, query.to_string());
+    }
+}
+
+#[test]
+fn select_statement_with_multiple_over_order_by_keys() {
+    let input = "SELECT ROW_NUMBER() OVER (ORDER BY a ASC, b DESC, c) FROM t";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty(), "{:?}", parser.errors());
+    let Some(ast::Statement::Select(select)) = query.statements.first() else {
+        panic!("expected a select statement");
+    };
+    let Some(ast::SelectItem::Unnamed(ast::Expression::Function {
+        over: Some(over), ..
+    })) = select.columns.first()
+    else {
+        panic!("expected a function with an over clause");
+    };
+
+    let keys = over
+        .order_by
+        .iter()
+        .map(|arg| (arg.column.to_string(), arg.order_kw))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            (
+                "a".to_string(),
+                Some(Keyword::new(Span::new(37, 39), KeywordKind::Asc))
+            ),
+            (
+                "b".to_string(),
+                Some(Keyword::new(Span::new(44, 47), KeywordKind::Desc))
+            ),
+            ("c".to_string(), None),
+        ],
+        keys
+    );
+    assert_eq!(
+        "select row_number() over(order by a asc, b desc, c) from t",
+        query.to_string()
+    );
+}
+
+#[test]
+fn select_statement_with_expression_partitions() {
+    let input = "SELECT SUM(amount) OVER (PARTITION BY YEAR(d), dept ORDER BY d) FROM t";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty(), "{:?}", parser.errors());
+    let Some(ast::Statement::Select(select)) = query.statements.first() else {
+        panic!("expected a select statement");
+    };
+    let Some(ast::SelectItem::Unnamed(ast::Expression::Function {
+        over: Some(over), ..
+    })) = select.columns.first()
+    else {
+        panic!("expected a function with an over clause");
+    };
+
+    assert_eq!(2, over.partition_by.len());
+    assert!(
+        matches!(
+            &over.partition_by[0],
+            ast::Expression::Function { name, args: Some(args), .. }
+                if name.to_string() == "year" && args.len() == 1
+        ),
+        "{:?}",
+        over.partition_by[0]
+    );
+    assert_eq!("dept", over.partition_by[1].to_string());
+    assert_eq!(
+        "select sum(amount) over(partition by year(d), dept order by d) from t",
+        query.to_string()
+    );
+}
+
+#[test]
+fn select_statement_with_empty_partition_by() {
+    let tests = [
+        (
+            "SELECT SUM(a) OVER (PARTITION BY) FROM t",
+            Span::new(32, 32),
+        ),
+        (
+            "SELECT SUM(a) OVER (PARTITION BY ORDER BY b) FROM t",
+            Span::new(33, 37),
+        ),
+    ];
+
+    for (input, span) in tests {
+        let lexer = Lexer::new(input);
+        let mut parser = Parser::new(lexer);
+        parser.parse();
+
+        let errors = parser.errors();
+        assert_eq!(1, errors.len(), "{input}: {errors:?}");
+        assert_eq!(ParseErrorType::EmptyPartitionByClause, errors[0].error);
+        assert_eq!(span, errors[0].span, "{input}");
+    }
+}
+
+#[test]
+fn select_statement_with_natural_as_name_tsql() {
+    let inputs = [
+        (r"SELECT natural FROM t", "select natural from t"),
+        (r"SELECT a FROM t natural", "select a from t natural"),
+        (r"SELECT a natural FROM t", "select a natural from t"),
+        (
+            r"SELECT t.natural FROM t WHERE natural > 0",
+            "select t.natural from t where natural > 0",
+        ),
+    ];
+    for (input, expected_query) in inputs {
+        let lexer = Lexer::new(input);
+        let mut parser = Parser::new(lexer);
+        let query = parser.parse();
+
+        assert!(parser.errors().is_empty());
+        assert_eq!(expected_query, query.to_string());
+    }
+}
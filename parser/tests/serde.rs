@@ -0,0 +1,32 @@
+#![cfg(feature = "serde")]
+
+use lexer::{Lexer, Span};
+use parser::ast::Query;
+use parser::Parser;
+
+#[test]
+fn query_round_trips_through_json() {
+    let input = r"SELECT TOP 10 Symbol, sum(Volume) OVER (PARTITION BY Symbol ORDER BY InsertDate) AS Total
+FROM dbo.MarketData md
+INNER JOIN Symbols s ON s.Id = md.SymbolId
+WHERE LastPrice BETWEEN 1 AND 10 AND Symbol IN ('MSFT', 'AAPL')
+ORDER BY Symbol DESC;
+UPDATE MarketData SET Volume = Volume * 2 WHERE Symbol = 'MSFT'";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+    assert!(parser.errors().is_empty(), "{:?}", parser.errors());
+
+    let json = serde_json::to_string(&query).expect("expected the query to serialize");
+    let deserialized: Query =
+        serde_json::from_str(&json).expect("expected the query to deserialize");
+
+    assert_eq!(query, deserialized);
+    assert_eq!(query.to_string(), deserialized.to_string());
+}
+
+#[test]
+fn span_serializes_as_offsets() {
+    let json = serde_json::to_string(&Span::new(7, 12)).expect("expected the span to serialize");
+    assert_eq!(r#"{"start":7,"end":12}"#, json);
+}
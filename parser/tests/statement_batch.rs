@@ -0,0 +1,192 @@
+use lexer::{Lexer, LexicalError, LexicalErrorType, Span, TokenKind};
+use parser::ast;
+use parser::error::ParseErrorType;
+use parser::Parser;
+
+#[test]
+fn statement_batch_separated_by_semicolons() {
+    let input = r"SELECT 1; SELECT 2;";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(2, query.statements.len());
+    assert!(query
+        .statements
+        .iter()
+        .all(|s| matches!(s, ast::Statement::Select(_))));
+}
+
+#[test]
+fn statement_batch_with_stray_semicolons() {
+    let input = r";SELECT 1;; set @MyCounter = 0; ;select 3;;";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(3, query.statements.len());
+    assert_eq!("select 1", query.statements[0].to_string());
+    assert_eq!("set @MyCounter = 0", query.statements[1].to_string());
+    assert_eq!("select 3", query.statements[2].to_string());
+}
+
+#[test]
+fn statement_batch_reports_an_error_per_statement() {
+    let input = r"SELECT FROM x; SELECT 1 FORM y; SELECT 3";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    let errors: Vec<_> = parser.errors().iter().map(|e| e.span).collect();
+    assert_eq!(vec![Span::new(7, 10), Span::new(29, 29)], errors);
+    assert_eq!(2, query.statements.len());
+    assert_eq!("select 1 FORM", query.statements[0].to_string());
+    assert_eq!("select 3", query.statements[1].to_string());
+}
+
+#[test]
+fn statement_batch_recovers_at_statement_keyword() {
+    let input = r"SELECT a FROM WHERE SELECT 2; UPDATE";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert_eq!(2, parser.errors().len());
+    assert!(matches!(
+        parser.errors().first().map(|e| &e.error),
+        Some(ParseErrorType::UnexpectedToken {
+            token: TokenKind::Where,
+            ..
+        })
+    ));
+    assert_eq!(1, query.statements.len());
+    assert_eq!("select 2", query.statements[0].to_string());
+}
+
+#[test]
+fn statement_batch_recovery_skips_select_of_insert() {
+    let input = r"INSERT INTO t (a,, b) SELECT x, y FROM u; SELECT 2";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert_eq!(1, parser.errors().len());
+    assert_eq!(1, query.statements.len());
+    assert_eq!("select 2", query.statements[0].to_string());
+}
+
+#[test]
+fn statement_batch_recovery_skips_subquery() {
+    let input = r"SELECT FROM x WHERE a IN (SELECT 1)";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert_eq!(1, parser.errors().len());
+    assert!(query.statements.is_empty());
+}
+
+#[test]
+fn statement_batch_recovery_skips_merge_actions() {
+    let input = r"MERGE t USING s ON WHEN MATCHED THEN DELETE
+        WHEN NOT MATCHED THEN INSERT VALUES (1); SELECT 1";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert_eq!(1, parser.errors().len());
+    assert_eq!(1, query.statements.len());
+    assert_eq!("select 1", query.statements[0].to_string());
+}
+
+#[test]
+fn statement_batch_separated_by_go() {
+    let input = "SELECT 1\nGO\nSELECT 2\nGO";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(4, query.statements.len());
+    let batches: Vec<Vec<String>> = query
+        .batches()
+        .iter()
+        .map(|batch| batch.iter().map(|s| s.to_string()).collect())
+        .collect();
+    assert_eq!(vec![vec!["select 1"], vec!["select 2"]], batches);
+}
+
+#[test]
+fn statement_batch_go_with_count() {
+    let input = "insert into t values (1)\ngo 3\nselect go from t";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(3, query.statements.len());
+    let ast::Statement::BatchSeparator { count, .. } = &query.statements[1] else {
+        panic!("expected a batch separator");
+    };
+    assert_eq!(Some("3".to_string()), count.as_ref().map(|c| c.to_string()));
+    assert_eq!("go 3", query.statements[1].to_string());
+    assert_eq!("select go from t", query.statements[2].to_string());
+    assert_eq!(2, query.batches().len());
+}
+
+#[test]
+fn statement_batch_error_location() {
+    let input = "SELECT 1;\nSELECT a\n  FROM WHERE";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let _ = parser.parse();
+
+    let error = parser.errors().first().expect("expected an error");
+    assert_eq!(Span::new(26, 30), error.span);
+    assert_eq!("line: 3 col: 8", error.location(input));
+}
+
+#[test]
+fn statement_batch_reports_lexical_errors() {
+    let input = r"SELECT 'unterminated FROM t";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    // the unterminated string is the only problem reported
+    assert_eq!(
+        vec![ParseErrorType::LexerError {
+            error: LexicalError {
+                error: LexicalErrorType::UnexpectedStringEnd,
+                span: Span::new(8, 26),
+            },
+        }],
+        parser
+            .errors()
+            .iter()
+            .map(|e| e.error.clone())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(Span::new(8, 26), parser.errors()[0].span);
+    assert!(query.statements.is_empty());
+}
+
+#[test]
+fn statement_batch_recovers_after_lexical_error() {
+    let input = r"SELECT a # b FROM t; SELECT 2";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    let errors: Vec<_> = parser.errors().iter().map(|e| e.span).collect();
+    assert_eq!(vec![Span::new(9, 9)], errors);
+    assert!(matches!(
+        parser.errors()[0].error,
+        ParseErrorType::LexerError { .. }
+    ));
+    // the statement the unreadable text was dropped from is not kept
+    assert_eq!(1, query.statements.len());
+    assert_eq!("select 2", query.statements[0].to_string());
+}
@@ -0,0 +1,68 @@
+use lexer::{Lexer, Span};
+use parser::ast::{self, PartitionRange};
+use parser::error::ParseErrorType;
+use parser::Parser;
+
+#[test]
+fn truncate_statement() {
+    let input = r"TRUNCATE TABLE dbo.MarketData";
+    let expected_query = "truncate table dbo.MarketData";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+
+    let Some(ast::Statement::Truncate { partitions, .. }) = query.statements.first() else {
+        panic!("expected a truncate statement");
+    };
+    assert!(partitions.is_none());
+}
+
+#[test]
+fn truncate_statement_with_partitions() {
+    let input = r"TRUNCATE TABLE MarketData WITH (PARTITIONS (1,2, 4 TO 6))";
+    let expected_query = "truncate table MarketData with (partitions (1, 2, 4 to 6))";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty(), "{:?}", parser.errors());
+    assert_eq!(expected_query, query.to_string());
+
+    let Some(ast::Statement::Truncate {
+        partitions: Some(partitions),
+        ..
+    }) = query.statements.first()
+    else {
+        panic!("expected a truncate statement with partitions");
+    };
+    assert_eq!(3, partitions.partitions.len());
+    assert!(matches!(
+        partitions.partitions[2],
+        PartitionRange::Range { .. }
+    ));
+}
+
+#[test]
+fn truncate_statement_errors() {
+    let inputs = [
+        ("TRUNCATE MarketData", Span::new(9, 18)),
+        ("TRUNCATE TABLE MarketData WITH (1)", Span::new(32, 32)),
+    ];
+
+    for (input, span) in inputs {
+        let lexer = Lexer::new(input);
+        let mut parser = Parser::new(lexer);
+        parser.parse();
+
+        let error = parser.errors().first().expect(input);
+        assert!(
+            matches!(error.error, ParseErrorType::UnexpectedToken { .. }),
+            "{input}: {:?}",
+            error
+        );
+        assert_eq!(span, error.span, "{input}");
+    }
+}
@@ -0,0 +1,42 @@
+use lexer::Lexer;
+use parser::error::ParseErrorType;
+use parser::Parser;
+
+#[test]
+fn try_parse_valid_query() {
+    let input = r"SELECT name from users where id = 1";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+
+    let query = parser.try_parse().expect("expected the query to parse");
+    assert_eq!("select name from users where id = 1", query.to_string());
+    assert!(parser.errors().is_empty());
+}
+
+#[test]
+fn try_parse_returns_error_variants() {
+    let input = r"SELECT a, FROM users";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+
+    let errors = parser
+        .try_parse()
+        .expect_err("expected a trailing comma error");
+    assert_eq!(
+        Some(&ParseErrorType::TrailingComma),
+        errors.first().map(|e| &e.error)
+    );
+    assert_eq!(parser.errors(), errors.as_slice());
+
+    let input = r"SELECT a FROM users WHERE";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+
+    let errors = parser
+        .try_parse()
+        .expect_err("expected a missing expression error");
+    assert!(matches!(
+        errors.first().map(|e| &e.error),
+        Some(ParseErrorType::UnrecognizedEof { .. })
+    ));
+}
@@ -0,0 +1,57 @@
+use lexer::Lexer;
+use parser::Parser;
+
+#[test]
+fn update_statement() {
+    let input =
+        r"UPDATE MarketData SET LastPrice = 10.5, Volume = Volume + 1 WHERE Symbol = 'MSFT'";
+    let mut expected_query = String::from("update MarketData set LastPrice = 10.5,");
+    expected_query += " Volume = Volume + 1 where Symbol = 'MSFT'";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn update_statement_from_join() {
+    let input = r"update t set t.LastPrice = s.Price from MarketData t inner join Quotes s
+    on t.Symbol = s.Symbol where s.QuoteDate = @today";
+    let mut expected_query = String::from("update t set t.LastPrice = s.Price from MarketData t");
+    expected_query += " inner join Quotes s on t.Symbol = s.Symbol where s.QuoteDate = @today";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn update_statement_with_top() {
+    let input = r"UPDATE TOP (100) MarketData SET Volume = 0 WHERE Symbol = 'MSFT'";
+    let expected_query = "update top (100) MarketData set Volume = 0 where Symbol = 'MSFT'";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
+
+#[test]
+fn update_statement_with_output() {
+    let input = r"UPDATE MarketData SET Volume = 0 OUTPUT deleted.Volume AS OldVolume, inserted.*
+    INTO @changes (OldVolume, Symbol) WHERE Symbol = 'MSFT'";
+    let mut expected_query = String::from("update MarketData set Volume = 0 output deleted.Volume");
+    expected_query += " as OldVolume, inserted.* into @changes (OldVolume, Symbol)";
+    expected_query += " where Symbol = 'MSFT'";
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let query = parser.parse();
+
+    assert!(parser.errors().is_empty());
+    assert_eq!(expected_query, query.to_string());
+}
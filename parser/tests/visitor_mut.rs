@@ -0,0 +1,52 @@
+use lexer::Lexer;
+use parser::ast::Expression;
+use parser::visitor_mut::{walk_expression_mut, VisitorMut};
+use parser::Parser;
+
+struct UppercaseIdentifiers;
+
+impl VisitorMut for UppercaseIdentifiers {
+    type Result = ();
+
+    fn visit_expression(&mut self, expr: &mut Expression) {
+        if let Expression::Identifier(literal) = expr {
+            literal.content = literal.content.to_uppercase();
+        }
+        walk_expression_mut(self, expr)
+    }
+}
+
+fn uppercase_identifiers(input: &str) -> String {
+    let mut parser = Parser::new(Lexer::new(input));
+    let mut query = parser.parse();
+    assert!(parser.errors().is_empty(), "{:?}", parser.errors());
+
+    UppercaseIdentifiers.visit_query(&mut query);
+    query.to_string()
+}
+
+#[test]
+fn uppercase_every_identifier() {
+    let tests = [
+        (
+            "select name, t.id from users t where id = 1",
+            "select NAME, T.ID from USERS T where ID = 1",
+        ),
+        (
+            "select name from users u inner join orders o on u.id = o.user_id",
+            "select NAME from USERS U inner join ORDERS O on U.ID = O.USER_ID",
+        ),
+        (
+            "select a, count(b) from t group by a having count(b) > 1 order by a",
+            "select A, count(B) from T group by A having count(B) > 1 order by A",
+        ),
+        (
+            "update t set a = 'x' where b in (select c from d)",
+            "update T set A = 'x' where B in (select C from D)",
+        ),
+    ];
+
+    for (input, expected) in tests {
+        assert_eq!(expected, uppercase_identifiers(input), "input: {}", input);
+    }
+}